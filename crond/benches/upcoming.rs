@@ -0,0 +1,34 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use criterion::*;
+use oni_comb_crond_rs::{CronEvaluator, CronExpr, CronParser, CronSchedule};
+
+const EXPRS: [&str; 3] = ["*/5 * * * *", "0 0 1 * *", "0 0 29 2 *"];
+
+/// The previous implementation: evaluates the expression minute by minute until it matches.
+fn scan(expr: &CronExpr, start: DateTime<Utc>) -> DateTime<Utc> {
+  let mut date_time = start;
+  while !CronEvaluator::new(&date_time).eval(expr) {
+    date_time += Duration::minutes(1);
+  }
+  date_time
+}
+
+fn upcoming_benchmark(criterion: &mut Criterion) {
+  let start = Utc.with_ymd_and_hms(2021, 3, 1, 0, 1, 0).unwrap();
+  let mut group = criterion.benchmark_group("upcoming");
+  group.sample_size(10);
+  for expr in EXPRS {
+    let schedule = CronSchedule::<Utc>::new(expr).unwrap();
+    group.bench_with_input(BenchmarkId::new("jump", expr), &schedule, |b, schedule| {
+      b.iter(|| schedule.upcoming(start).next())
+    });
+    let parsed = CronParser::parse(expr).unwrap();
+    group.bench_with_input(BenchmarkId::new("scan", expr), &parsed, |b, parsed| {
+      b.iter(|| scan(parsed, start))
+    });
+  }
+  group.finish();
+}
+
+criterion_group!(benches, upcoming_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,655 @@
+use crate::cron_error::CronField;
+use crate::cron_evaluator::is_restricted;
+use crate::cron_expr::CronExpr;
+use crate::cron_options::CronOptions;
+
+/// The wording of the description of a CROND expression, built from the parts of the expression.<br/>
+/// CROND式の説明の文言。式の部分から組み立てる。
+///
+/// The values of the day-of-week field are passed counted from Sunday as 0, whatever the numbering of the options.
+pub trait CronLocale {
+  /// The name of the value, e.g. `Monday` for 1 in the day-of-week field.
+  fn value(&self, field: CronField, value: u16) -> String;
+  /// A time of the day, e.g. `09:00`.
+  fn time(&self, hour: u16, minute: u16, second: Option<u16>) -> String;
+  /// The items of a list, e.g. `1, 2, and 3`.
+  fn list(&self, items: Vec<String>) -> String;
+  /// A field matching any value, e.g. `every minute`.
+  fn every(&self, field: CronField) -> String;
+  /// A field matching the named values, e.g. `minute 5` or `Monday and Friday`.
+  fn values(&self, field: CronField, values: Vec<String>) -> String;
+  /// A field with a step, e.g. `every 2nd hour`.
+  fn step(&self, field: CronField, step: u16) -> String;
+  /// A field with a range of the named values and an optional step, e.g. `every hour from 9 through 17`.
+  fn range(&self, field: CronField, from: String, to: String, step: Option<u16>) -> String;
+  /// `L` in the day-of-month field.
+  fn last_day(&self) -> String;
+  /// `L-n` in the day-of-month field.
+  fn last_day_offset(&self, offset: u16) -> String;
+  /// `nW` in the day-of-month field.
+  fn nearest_weekday(&self, day: u16) -> String;
+  /// `LW` in the day-of-month field.
+  fn last_weekday(&self) -> String;
+  /// `d#n` in the day-of-week field, with the named day of the week.
+  fn nth_day_of_week(&self, day_of_week: String, nth: u16) -> String;
+  /// `dL` in the day-of-week field, with the named day of the week.
+  fn last_day_of_week(&self, day_of_week: String) -> String;
+  /// A smaller field within a larger one, e.g. `minute 0 past every 2nd hour`.
+  fn past(&self, smaller: String, larger: String) -> String;
+  /// The time part of the description, e.g. `At 09:00`.
+  fn at(&self, time: String) -> String;
+  /// The day-of-month or day-of-week part, e.g. `on Monday`.
+  fn on(&self, days: String) -> String;
+  /// The month part, e.g. `in January`.
+  fn in_months(&self, months: String) -> String;
+  /// The year part, e.g. `in 2030`.
+  fn in_years(&self, years: String) -> String;
+  /// A day matching either the day-of-month or the day-of-week part.
+  fn either_day(&self, days: String, day_of_weeks: String) -> String;
+  /// A day matching both the day-of-month and the day-of-week parts.
+  fn both_days(&self, days: String, day_of_weeks: String) -> String;
+  /// The whole description from its parts.
+  fn sentence(&self, time: String, days: Option<String>, months: Option<String>, years: Option<String>) -> String;
+  /// `@every <duration>`.
+  fn fixed_rate(&self, seconds: u64) -> String;
+  /// `@reboot`.
+  fn reboot(&self) -> String;
+}
+
+/// The descriptions in English, similar to cronstrue and crontab.guru.<br/>
+/// 英語の説明。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EnglishLocale;
+
+const MONTH_NAMES: [&str; 12] = [
+  "January",
+  "February",
+  "March",
+  "April",
+  "May",
+  "June",
+  "July",
+  "August",
+  "September",
+  "October",
+  "November",
+  "December",
+];
+
+const DAY_OF_WEEK_NAMES: [&str; 7] = [
+  "Sunday",
+  "Monday",
+  "Tuesday",
+  "Wednesday",
+  "Thursday",
+  "Friday",
+  "Saturday",
+];
+
+fn ordinal(n: u16) -> String {
+  let suffix = match (n % 10, n % 100) {
+    (_, 11..=13) => "th",
+    (1, _) => "st",
+    (2, _) => "nd",
+    (3, _) => "rd",
+    _ => "th",
+  };
+  format!("{}{}", n, suffix)
+}
+
+/// Splits the seconds into the days, hours, minutes and seconds, leaving out the zeros.
+fn units(seconds: u64) -> Vec<(u64, usize)> {
+  [86400, 3600, 60, 1]
+    .iter()
+    .enumerate()
+    .scan(seconds, |rest, (unit, size)| {
+      let count = *rest / size;
+      *rest %= size;
+      Some((count, unit))
+    })
+    .filter(|(count, _)| *count > 0)
+    .collect()
+}
+
+impl CronLocale for EnglishLocale {
+  fn value(&self, field: CronField, value: u16) -> String {
+    let name = match field {
+      CronField::Month => MONTH_NAMES.get(value.wrapping_sub(1) as usize),
+      CronField::DayOfWeek => DAY_OF_WEEK_NAMES.get(value as usize),
+      _ => None,
+    };
+    name.map_or_else(|| value.to_string(), |name| name.to_string())
+  }
+
+  fn time(&self, hour: u16, minute: u16, second: Option<u16>) -> String {
+    match second {
+      Some(second) => format!("{:02}:{:02}:{:02}", hour, minute, second),
+      None => format!("{:02}:{:02}", hour, minute),
+    }
+  }
+
+  fn list(&self, mut items: Vec<String>) -> String {
+    match items.len() {
+      0 | 1 => items.concat(),
+      2 => items.join(" and "),
+      _ => {
+        let last = items.pop().unwrap_or_default();
+        format!("{}, and {}", items.join(", "), last)
+      }
+    }
+  }
+
+  fn every(&self, field: CronField) -> String {
+    format!("every {}", field)
+  }
+
+  fn values(&self, field: CronField, values: Vec<String>) -> String {
+    match field {
+      CronField::Month | CronField::DayOfWeek => self.list(values),
+      _ => format!("{} {}", field, self.list(values)),
+    }
+  }
+
+  fn step(&self, field: CronField, step: u16) -> String {
+    format!("every {} {}", ordinal(step), field)
+  }
+
+  fn range(&self, field: CronField, from: String, to: String, step: Option<u16>) -> String {
+    match (field, step) {
+      (CronField::DayOfWeek, None) => format!("{} through {}", from, to),
+      (_, None) => format!("every {} from {} through {}", field, from, to),
+      (_, Some(step)) => format!("{} from {} through {}", self.step(field, step), from, to),
+    }
+  }
+
+  fn last_day(&self) -> String {
+    "the last day of the month".to_string()
+  }
+
+  fn last_day_offset(&self, offset: u16) -> String {
+    format!("the {} day before the last day of the month", ordinal(offset))
+  }
+
+  fn nearest_weekday(&self, day: u16) -> String {
+    format!("the weekday nearest day {} of the month", day)
+  }
+
+  fn last_weekday(&self) -> String {
+    "the last weekday of the month".to_string()
+  }
+
+  fn nth_day_of_week(&self, day_of_week: String, nth: u16) -> String {
+    format!("the {} {} of the month", ordinal(nth), day_of_week)
+  }
+
+  fn last_day_of_week(&self, day_of_week: String) -> String {
+    format!("the last {} of the month", day_of_week)
+  }
+
+  fn past(&self, smaller: String, larger: String) -> String {
+    format!("{} past {}", smaller, larger)
+  }
+
+  fn at(&self, time: String) -> String {
+    format!("At {}", time)
+  }
+
+  fn on(&self, days: String) -> String {
+    format!("on {}", days)
+  }
+
+  fn in_months(&self, months: String) -> String {
+    format!("in {}", months)
+  }
+
+  fn in_years(&self, years: String) -> String {
+    format!("in {}", years)
+  }
+
+  fn either_day(&self, days: String, day_of_weeks: String) -> String {
+    format!("{} and {}", days, day_of_weeks)
+  }
+
+  fn both_days(&self, days: String, day_of_weeks: String) -> String {
+    format!("{}, only {}", days, day_of_weeks)
+  }
+
+  fn sentence(&self, time: String, days: Option<String>, months: Option<String>, years: Option<String>) -> String {
+    [Some(time), days, months, years]
+      .into_iter()
+      .flatten()
+      .collect::<Vec<_>>()
+      .join(" ")
+  }
+
+  fn fixed_rate(&self, seconds: u64) -> String {
+    let names = ["day", "hour", "minute", "second"];
+    let units = units(seconds)
+      .into_iter()
+      .map(|(count, unit)| format!("{} {}{}", count, names[unit], if count == 1 { "" } else { "s" }))
+      .collect();
+    format!("Every {}", self.list(units))
+  }
+
+  fn reboot(&self) -> String {
+    "At startup".to_string()
+  }
+}
+
+/// The descriptions in Japanese.<br/>
+/// 日本語の説明。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JapaneseLocale;
+
+const JAPANESE_DAY_OF_WEEK_NAMES: [&str; 7] = ["日曜日", "月曜日", "火曜日", "水曜日", "木曜日", "金曜日", "土曜日"];
+
+impl JapaneseLocale {
+  fn single(&self, field: CronField, value: String) -> String {
+    match field {
+      CronField::Second => format!("{}秒", value),
+      CronField::Minute => format!("{}分", value),
+      CronField::Hour => format!("{}時", value),
+      CronField::DayOfMonth => format!("{}日", value),
+      CronField::Month | CronField::DayOfWeek => value,
+      CronField::Year => format!("{}年", value),
+    }
+  }
+}
+
+impl CronLocale for JapaneseLocale {
+  fn value(&self, field: CronField, value: u16) -> String {
+    match field {
+      CronField::Month => format!("{}月", value),
+      CronField::DayOfWeek => JAPANESE_DAY_OF_WEEK_NAMES
+        .get(value as usize)
+        .map_or_else(|| value.to_string(), |name| name.to_string()),
+      _ => value.to_string(),
+    }
+  }
+
+  fn time(&self, hour: u16, minute: u16, second: Option<u16>) -> String {
+    match second {
+      Some(second) => format!("{}時{:02}分{:02}秒", hour, minute, second),
+      None => format!("{}時{:02}分", hour, minute),
+    }
+  }
+
+  fn list(&self, items: Vec<String>) -> String {
+    items.join("、")
+  }
+
+  fn every(&self, field: CronField) -> String {
+    match field {
+      CronField::Second => "毎秒",
+      CronField::Minute => "毎分",
+      CronField::Hour => "毎時",
+      CronField::DayOfMonth | CronField::DayOfWeek => "毎日",
+      CronField::Month => "毎月",
+      CronField::Year => "毎年",
+    }
+    .to_string()
+  }
+
+  fn values(&self, field: CronField, values: Vec<String>) -> String {
+    self.list(values.into_iter().map(|value| self.single(field, value)).collect())
+  }
+
+  fn step(&self, field: CronField, step: u16) -> String {
+    match field {
+      CronField::Second => format!("{}秒ごと", step),
+      CronField::Minute => format!("{}分ごと", step),
+      CronField::Hour => format!("{}時間ごと", step),
+      CronField::DayOfMonth => format!("{}日ごと", step),
+      CronField::Month => format!("{}か月ごと", step),
+      CronField::DayOfWeek => format!("{}曜日ごと", step),
+      CronField::Year => format!("{}年ごと", step),
+    }
+  }
+
+  fn range(&self, field: CronField, from: String, to: String, step: Option<u16>) -> String {
+    let range = format!("{}から{}まで", self.single(field, from), self.single(field, to));
+    match step {
+      Some(step) => format!("{}の{}", range, self.step(field, step)),
+      None => range,
+    }
+  }
+
+  fn last_day(&self) -> String {
+    "月末".to_string()
+  }
+
+  fn last_day_offset(&self, offset: u16) -> String {
+    format!("月末の{}日前", offset)
+  }
+
+  fn nearest_weekday(&self, day: u16) -> String {
+    format!("{}日に最も近い平日", day)
+  }
+
+  fn last_weekday(&self) -> String {
+    "月末の平日".to_string()
+  }
+
+  fn nth_day_of_week(&self, day_of_week: String, nth: u16) -> String {
+    format!("第{}{}", nth, day_of_week)
+  }
+
+  fn last_day_of_week(&self, day_of_week: String) -> String {
+    format!("最終{}", day_of_week)
+  }
+
+  fn past(&self, smaller: String, larger: String) -> String {
+    format!("{}の{}", larger, smaller)
+  }
+
+  fn at(&self, time: String) -> String {
+    time
+  }
+
+  fn on(&self, days: String) -> String {
+    days
+  }
+
+  fn in_months(&self, months: String) -> String {
+    months
+  }
+
+  fn in_years(&self, years: String) -> String {
+    years
+  }
+
+  fn either_day(&self, days: String, day_of_weeks: String) -> String {
+    format!("{}または{}", days, day_of_weeks)
+  }
+
+  fn both_days(&self, days: String, day_of_weeks: String) -> String {
+    format!("{}かつ{}", days, day_of_weeks)
+  }
+
+  fn sentence(&self, time: String, days: Option<String>, months: Option<String>, years: Option<String>) -> String {
+    let date = [years, months, days].into_iter().flatten().collect::<Vec<_>>();
+    if date.is_empty() {
+      time
+    } else {
+      format!("{}の{}", date.join("、"), time)
+    }
+  }
+
+  fn fixed_rate(&self, seconds: u64) -> String {
+    let names = ["日", "時間", "分", "秒"];
+    let units = units(seconds)
+      .into_iter()
+      .map(|(count, unit)| format!("{}{}", count, names[unit]))
+      .collect::<String>();
+    format!("{}ごと", units)
+  }
+
+  fn reboot(&self) -> String {
+    "起動時".to_string()
+  }
+}
+
+/// Describes the expression with the locale, or returns an empty string if it is not a whole expression.
+pub(crate) fn describe(expr: &CronExpr, options: CronOptions, locale: &dyn CronLocale) -> String {
+  match expr {
+    CronExpr::EveryExpr(seconds) => locale.fixed_rate(*seconds),
+    CronExpr::RebootExpr => locale.reboot(),
+    CronExpr::CronExpr {
+      secs,
+      box mins,
+      box hours,
+      box days,
+      box months,
+      box day_of_weeks,
+      years,
+    } => {
+      let describer = Describer { options, locale };
+      let time = describer.time(secs.as_deref(), mins, hours);
+      let days = describer.days(days, day_of_weeks);
+      let months = is_any(months).then_some(()).map_or_else(
+        || Some(locale.in_months(describer.field(CronField::Month, months))),
+        |_| None,
+      );
+      let years = years
+        .as_deref()
+        .filter(|years| !is_any(years))
+        .map(|years| locale.in_years(describer.field(CronField::Year, years)));
+      locale.sentence(time, days, months, years)
+    }
+    _ => String::new(),
+  }
+}
+
+fn is_any(expr: &CronExpr) -> bool {
+  matches!(expr, CronExpr::AnyValueExpr | CronExpr::NoSpecificValueExpr)
+}
+
+fn value_of(expr: &CronExpr) -> Option<u16> {
+  match expr {
+    CronExpr::ValueExpr(value) => Some(*value),
+    _ => None,
+  }
+}
+
+struct Describer<'a> {
+  options: CronOptions,
+  locale: &'a dyn CronLocale,
+}
+
+impl Describer<'_> {
+  fn time(&self, secs: Option<&CronExpr>, mins: &CronExpr, hours: &CronExpr) -> String {
+    let second = secs.map(value_of);
+    if let (Some(minute), Some(hour), None | Some(Some(_))) = (value_of(mins), value_of(hours), second) {
+      return self.locale.at(self.locale.time(hour, minute, second.flatten()));
+    }
+    // The seconds at 0 are left out, e.g. `0 */5 * * * *` is described as every 5th minute.
+    let secs = secs.filter(|secs| **secs != CronExpr::ValueExpr(0));
+    let mut parts = Vec::new();
+    if let Some(secs) = secs {
+      parts.push(self.field(CronField::Second, secs));
+    }
+    if parts.is_empty() || !is_any(mins) {
+      parts.push(self.field(CronField::Minute, mins));
+    }
+    if !is_any(hours) {
+      parts.push(self.field(CronField::Hour, hours));
+    }
+    let time = parts
+      .into_iter()
+      .reduce(|smaller, larger| self.locale.past(smaller, larger))
+      .unwrap_or_default();
+    self.locale.at(time)
+  }
+
+  fn days(&self, days: &CronExpr, day_of_weeks: &CronExpr) -> Option<String> {
+    let on = |field: CronField, expr: &CronExpr| (!is_any(expr)).then(|| self.locale.on(self.field(field, expr)));
+    match (on(CronField::DayOfMonth, days), on(CronField::DayOfWeek, day_of_weeks)) {
+      (Some(on_days), Some(on_day_of_weeks)) => {
+        let matching = self.options.day_matching();
+        Some(
+          if is_restricted(days, matching) && is_restricted(day_of_weeks, matching) {
+            self.locale.either_day(on_days, on_day_of_weeks)
+          } else {
+            self.locale.both_days(on_days, on_day_of_weeks)
+          },
+        )
+      }
+      (on_days, on_day_of_weeks) => on_days.or(on_day_of_weeks),
+    }
+  }
+
+  /// Returns the name of the value, with the days of the week counted from Sunday as 0.
+  fn value(&self, field: CronField, value: u16) -> String {
+    let value = match field {
+      CronField::DayOfWeek => (value + 7 - self.options.day_of_week_numbering().sunday()) % 7,
+      _ => value,
+    };
+    self.locale.value(field, value)
+  }
+
+  fn field(&self, field: CronField, expr: &CronExpr) -> String {
+    let locale = self.locale;
+    match expr {
+      CronExpr::AnyValueExpr | CronExpr::NoSpecificValueExpr => locale.every(field),
+      CronExpr::ValueExpr(value) => locale.values(field, vec![self.value(field, *value)]),
+      CronExpr::LastValueExpr if field == CronField::DayOfWeek => {
+        // `L` is Saturday in the day-of-week field.
+        let saturday = self.options.day_of_week_numbering().sunday() + 6;
+        locale.values(field, vec![self.value(field, saturday)])
+      }
+      CronExpr::LastValueExpr => locale.last_day(),
+      CronExpr::LastOffsetExpr(0) => locale.last_day(),
+      CronExpr::LastOffsetExpr(offset) => locale.last_day_offset(*offset),
+      CronExpr::NearestWeekdayExpr(box CronExpr::ValueExpr(day)) => locale.nearest_weekday(*day),
+      CronExpr::NearestWeekdayExpr(_) => locale.last_weekday(),
+      CronExpr::NthDayOfWeekExpr { day_of_week, nth } => {
+        locale.nth_day_of_week(self.value(CronField::DayOfWeek, *day_of_week), *nth)
+      }
+      CronExpr::LastDayOfWeekExpr(day_of_week) => {
+        locale.last_day_of_week(self.value(CronField::DayOfWeek, *day_of_week))
+      }
+      CronExpr::PerExpr { box option, .. } => locale.step(field, value_of(option).unwrap_or(1)),
+      CronExpr::RangeExpr {
+        box from,
+        box to,
+        box per_option,
+      } => locale.range(
+        field,
+        self.value(field, value_of(from).unwrap_or_default()),
+        self.value(field, value_of(to).unwrap_or_default()),
+        value_of(per_option),
+      ),
+      CronExpr::ListExpr(list) => match list.iter().map(value_of).collect::<Option<Vec<_>>>() {
+        Some(values) => locale.values(
+          field,
+          values.into_iter().map(|value| self.value(field, value)).collect(),
+        ),
+        None => locale.list(list.iter().map(|expr| self.field(field, expr)).collect()),
+      },
+      _ => String::new(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::cron_options::{CronDialect, DayMatching};
+  use crate::cron_parser::CronParser;
+
+  fn english(input: &str, options: CronOptions) -> String {
+    CronParser::parse_with(input, options)
+      .unwrap()
+      .describe_with(options, &EnglishLocale)
+  }
+
+  fn japanese(input: &str, options: CronOptions) -> String {
+    CronParser::parse_with(input, options)
+      .unwrap()
+      .describe_with(options, &JapaneseLocale)
+  }
+
+  #[test]
+  fn test_english() {
+    let options = CronOptions::default();
+    let cases = [
+      ("* * * * *", "At every minute"),
+      ("*/15 * * * *", "At every 15th minute"),
+      ("5 4 * * *", "At 04:05"),
+      ("0 * * * *", "At minute 0"),
+      (
+        "0 */2 * * MON-FRI",
+        "At minute 0 past every 2nd hour on Monday through Friday",
+      ),
+      ("0 9-17 * * *", "At minute 0 past every hour from 9 through 17"),
+      (
+        "0-30/10 9,17 * * *",
+        "At every 10th minute from 0 through 30 past hour 9 and 17",
+      ),
+      ("1,2,3 * * * *", "At minute 1, 2, and 3"),
+      ("0 0 1 * *", "At 00:00 on day-of-month 1"),
+      ("0 0 13 * FRI", "At 00:00 on day-of-month 13 and on Friday"),
+      ("0 0 */2 * MON", "At 00:00 on every 2nd day-of-month, only on Monday"),
+      ("0 0 1 1,7 *", "At 00:00 on day-of-month 1 in January and July"),
+      (
+        "0 0 1 JAN-MAR/2 *",
+        "At 00:00 on day-of-month 1 in every 2nd month from January through March",
+      ),
+      ("0 0 * * 0,7", "At 00:00 on Sunday and Sunday"),
+      ("0 22-2 * * *", "At minute 0 past every hour from 22 through 2"),
+      ("0 9 L * *", "At 09:00 on the last day of the month"),
+      (
+        "0 9 L-3 * *",
+        "At 09:00 on the 3rd day before the last day of the month",
+      ),
+      ("0 9 15W * *", "At 09:00 on the weekday nearest day 15 of the month"),
+      ("0 9 LW * *", "At 09:00 on the last weekday of the month"),
+      ("0 9 ? * 5#3", "At 09:00 on the 3rd Friday of the month"),
+      ("0 9 ? * 1L", "At 09:00 on the last Monday of the month"),
+      ("@daily", "At 00:00"),
+      ("@hourly", "At minute 0"),
+      ("@every 5m", "Every 5 minutes"),
+      ("@every 1h30m", "Every 1 hour and 30 minutes"),
+      ("@every 1d1h1m1s", "Every 1 day, 1 hour, 1 minute, and 1 second"),
+      ("@reboot", "At startup"),
+    ];
+    for (input, expected) in cases {
+      assert_eq!(english(input, options), expected, "{}", input);
+    }
+  }
+
+  #[test]
+  fn test_english_with_options() {
+    let quartz = CronOptions::quartz();
+    assert_eq!(
+      english("0 0 12 ? * 2-6", quartz),
+      "At 12:00:00 on Monday through Friday"
+    );
+    assert_eq!(english("0 0 12 ? * L", quartz), "At 12:00:00 on Saturday");
+    assert_eq!(
+      english("0 0 12 13 * 6", quartz),
+      "At 12:00:00 on day-of-month 13, only on Friday"
+    );
+    assert_eq!(
+      english("0 0 12 1 1 ? 2030-2035", quartz),
+      "At 12:00:00 on day-of-month 1 in January in every year from 2030 through 2035"
+    );
+    let spring = CronOptions::new().with_dialect(CronDialect::Spring);
+    assert_eq!(english("*/10 * 9 * * *", spring), "At every 10th second past hour 9");
+    assert_eq!(english("0 */5 * * * *", spring), "At every 5th minute");
+    let posix = CronOptions::new().with_day_matching(DayMatching::Posix);
+    assert_eq!(
+      english("0 0 */2 * MON", posix),
+      "At 00:00 on every 2nd day-of-month and on Monday"
+    );
+  }
+
+  #[test]
+  fn test_japanese() {
+    let options = CronOptions::default();
+    let cases = [
+      ("* * * * *", "毎分"),
+      ("5 4 * * *", "4時05分"),
+      ("0 */2 * * MON-FRI", "月曜日から金曜日までの2時間ごとの0分"),
+      ("0 9-17 * * *", "9時から17時までの0分"),
+      ("0 0 13 * FRI", "13日または金曜日の0時00分"),
+      ("0 0 1 JAN-MAR/2 *", "1月から3月までの2か月ごと、1日の0時00分"),
+      ("0 9 ? * 5#3", "第3金曜日の9時00分"),
+      ("0 9 LW * *", "月末の平日の9時00分"),
+      ("@every 1h30m", "1時間30分ごと"),
+      ("@reboot", "起動時"),
+    ];
+    for (input, expected) in cases {
+      assert_eq!(japanese(input, options), expected, "{}", input);
+    }
+  }
+
+  #[test]
+  fn test_ordinal() {
+    let ordinals = [1, 2, 3, 4, 11, 12, 13, 21, 22, 23, 101, 111].map(ordinal);
+    assert_eq!(
+      ordinals,
+      ["1st", "2nd", "3rd", "4th", "11th", "12th", "13th", "21st", "22nd", "23rd", "101st", "111th"]
+    );
+  }
+}
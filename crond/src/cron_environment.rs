@@ -1,10 +1,25 @@
+use chrono::NaiveDate;
+
 pub struct CronEnvironment {
-  pub(crate) now: u8,
-  pub(crate) max: u8,
+  pub(crate) now: u16,
+  pub(crate) min: u16,
+  pub(crate) max: u16,
+  /// The date being evaluated, required by the fields that depend on the calendar such as `15W` and `5#3`.
+  pub(crate) date: Option<NaiveDate>,
 }
 
 impl CronEnvironment {
-  pub fn new(now: u8, max: u8) -> Self {
-    Self { now, max }
+  pub fn new(now: u16, min: u16, max: u16) -> Self {
+    Self {
+      now,
+      min,
+      max,
+      date: None,
+    }
+  }
+
+  pub fn with_date(mut self, date: NaiveDate) -> Self {
+    self.date = Some(date);
+    self
   }
 }
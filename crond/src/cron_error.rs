@@ -0,0 +1,129 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+
+/// A field of a CROND expression.<br/>
+/// CROND式のフィールド。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CronField {
+  Second,
+  Minute,
+  Hour,
+  DayOfMonth,
+  Month,
+  DayOfWeek,
+  Year,
+}
+
+impl Display for CronField {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      CronField::Second => write!(f, "second"),
+      CronField::Minute => write!(f, "minute"),
+      CronField::Hour => write!(f, "hour"),
+      CronField::DayOfMonth => write!(f, "day-of-month"),
+      CronField::Month => write!(f, "month"),
+      CronField::DayOfWeek => write!(f, "day-of-week"),
+      CronField::Year => write!(f, "year"),
+    }
+  }
+}
+
+/// The error of parsing and validating a CROND expression.<br/>
+/// CROND式の解析と検証のエラー。
+///
+/// The position is the offset in characters of the field in the expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CronError {
+  /// The expression does not follow the syntax.<br/>
+  /// 構文に従っていない。
+  Syntax(String),
+  /// A step is 0, e.g. `*/0`.<br/>
+  /// 間隔が0である。
+  InvalidStep {
+    field: CronField,
+    position: usize,
+    step: u16,
+  },
+  /// A range starts after its end in a field that does not wrap around, e.g. `30-10` in the minute field.<br/>
+  /// 循環しないフィールドで範囲の開始が終了より後にある。
+  ReversedRange {
+    field: CronField,
+    position: usize,
+    from: u16,
+    to: u16,
+  },
+  /// A value is out of the range of the field, e.g. `60` in the minute field.<br/>
+  /// 値がフィールドの範囲外である。
+  OutOfRange {
+    field: CronField,
+    position: usize,
+    value: u16,
+    min: u16,
+    max: u16,
+  },
+  /// The day of the month never falls on a date, e.g. `30` in February.<br/>
+  /// 日が存在する日付に一致しない。
+  ImpossibleDate { field: CronField, position: usize },
+}
+
+impl CronError {
+  /// Returns the field of the error, or `None` for a syntax error.
+  pub fn field(&self) -> Option<CronField> {
+    match self {
+      CronError::Syntax(_) => None,
+      CronError::InvalidStep { field, .. }
+      | CronError::ReversedRange { field, .. }
+      | CronError::OutOfRange { field, .. }
+      | CronError::ImpossibleDate { field, .. } => Some(*field),
+    }
+  }
+
+  /// Returns the position of the field of the error, or `None` for a syntax error.
+  pub fn position(&self) -> Option<usize> {
+    match self {
+      CronError::Syntax(_) => None,
+      CronError::InvalidStep { position, .. }
+      | CronError::ReversedRange { position, .. }
+      | CronError::OutOfRange { position, .. }
+      | CronError::ImpossibleDate { position, .. } => Some(*position),
+    }
+  }
+}
+
+impl Display for CronError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      CronError::Syntax(message) => write!(f, "{}", message),
+      CronError::InvalidStep { field, position, step } => {
+        write!(f, "invalid step {} in the {} field at {}", step, field, position)
+      }
+      CronError::ReversedRange {
+        field,
+        position,
+        from,
+        to,
+      } => write!(
+        f,
+        "reversed range {}-{} in the {} field at {}",
+        from, to, field, position
+      ),
+      CronError::OutOfRange {
+        field,
+        position,
+        value,
+        min,
+        max,
+      } => write!(
+        f,
+        "value {} out of the range {}-{} in the {} field at {}",
+        value, min, max, field, position
+      ),
+      CronError::ImpossibleDate { field, position } => {
+        write!(f, "the {} field at {} never falls on a date", field, position)
+      }
+    }
+  }
+}
+
+impl Error for CronError {}
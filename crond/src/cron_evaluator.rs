@@ -1,70 +1,65 @@
 use crate::cron_environment::CronEnvironment;
 use crate::cron_expr::CronExpr;
+use crate::cron_options::{CronOptions, DayMatching, DayOfWeekNumbering};
 use crate::get_days_from_month;
-use chrono::{DateTime, Datelike, TimeZone, Timelike};
+use chrono::{DateTime, Datelike, NaiveDate, TimeZone, Timelike, Weekday};
 
 pub struct CronEvaluator<'a, Tz: TimeZone> {
   instant: &'a DateTime<Tz>,
+  options: CronOptions,
 }
 
 impl<'a, Tz: TimeZone> CronEvaluator<'a, Tz> {
   pub fn new(instant: &'a DateTime<Tz>) -> Self {
-    Self { instant }
+    Self::with_options(instant, CronOptions::default())
+  }
+
+  pub fn with_options(instant: &'a DateTime<Tz>, options: CronOptions) -> Self {
+    Self { instant, options }
   }
 
   pub fn eval(&self, ast: &CronExpr) -> bool {
     match ast {
       CronExpr::CronExpr {
+        secs,
         box mins,
         box hours,
         box months,
         box days,
         box day_of_weeks,
+        years,
       } => {
-        let last_day = get_days_from_month(self.instant.date().year(), self.instant.date().month());
-        let fmins = self.visit(&CronEnvironment::new(self.instant.time().minute() as u8, 59), mins);
-        let fhours = self.visit(&CronEnvironment::new(self.instant.time().hour() as u8, 23), hours);
-        let fdays = self.visit(
-          &CronEnvironment::new(self.instant.date().day() as u8, last_day as u8),
-          days,
-        );
-        let fmonths = self.visit(&CronEnvironment::new(self.instant.date().month() as u8, 12), months);
-        let fday_of_weeks = self.visit(
-          &CronEnvironment::new(self.instant.time().minute() as u8, 7),
-          day_of_weeks,
-        );
-        fmins && fhours && fdays && fmonths && fday_of_weeks
+        let date = self.instant.date_naive();
+        let time = self.instant.time();
+        let last_day = get_days_from_month(date.year(), date.month());
+        let fsecs = secs
+          .as_deref()
+          .is_none_or(|secs| visit(&CronEnvironment::new(time.second() as u16, 0, 59), secs));
+        let fyears = years
+          .as_deref()
+          .is_none_or(|years| visit(&CronEnvironment::new(date.year() as u16, 1970, 2099), years));
+        let fmins = visit(&CronEnvironment::new(time.minute() as u16, 0, 59), mins);
+        let fhours = visit(&CronEnvironment::new(time.hour() as u16, 0, 23), hours);
+        let fmonths = visit(&CronEnvironment::new(date.month() as u16, 1, 12), months);
+        fsecs && fmins && fhours && fmonths && fyears && self.eval_days(days, day_of_weeks, last_day as u16)
       }
+      CronExpr::EveryExpr(interval) => is_every(self.instant, *interval),
       _ => false,
     }
   }
 
-  fn visit(&self, env: &CronEnvironment, ast: &CronExpr) -> bool {
-    match ast {
-      CronExpr::AnyValueExpr => true,
-      CronExpr::LastValueExpr if env.now == env.max => true,
-      CronExpr::ValueExpr(n) if env.now == *n => true,
-      CronExpr::ListExpr(list) => list.iter().any(|e| self.visit(env, e)),
-      CronExpr::RangeExpr {
-        from: box CronExpr::ValueExpr(start),
-        to: box CronExpr::ValueExpr(end),
-        per_option,
-      } => match per_option {
-        box CronExpr::NoOp if *start <= env.now && env.now <= *end => true,
-        box CronExpr::ValueExpr(per) => (*start as usize..=*end as usize)
-          .step_by(*per as usize)
-          .into_iter()
-          .any(|e| e == env.now as usize),
-        _ => false,
-      },
-      CronExpr::PerExpr {
-        digit: box CronExpr::AnyValueExpr,
-        option: box CronExpr::ValueExpr(per),
-      } => (0usize..=(env.max as usize))
-        .step_by(*per as usize)
-        .into_iter()
-        .any(|e| e == env.now as usize),
-      _ => false,
+  fn eval_days(&self, days: &CronExpr, day_of_weeks: &CronExpr, last_day: u16) -> bool {
+    let date = self.instant.date_naive();
+    let fdays = visit(
+      &CronEnvironment::new(date.day() as u16, 1, last_day).with_date(date),
+      days,
+    );
+    let fday_of_weeks = matches_day_of_week(date, day_of_weeks, self.options.day_of_week_numbering());
+    let matching = self.options.day_matching();
+    if is_restricted(days, matching) && is_restricted(day_of_weeks, matching) {
+      fdays || fday_of_weeks
+    } else {
+      fdays && fday_of_weeks
     }
   }
 
@@ -73,6 +68,128 @@ impl<'a, Tz: TimeZone> CronEvaluator<'a, Tz> {
   //}
 }
 
+/// Returns whether the day-of-month or day-of-week field restricts the days, so that the fields are ORed.
+pub(crate) fn is_restricted(expr: &CronExpr, matching: DayMatching) -> bool {
+  match matching {
+    DayMatching::Vixie => !matches!(
+      expr,
+      CronExpr::AnyValueExpr
+        | CronExpr::NoSpecificValueExpr
+        | CronExpr::PerExpr {
+          digit: box CronExpr::AnyValueExpr,
+          ..
+        }
+    ),
+    DayMatching::Posix => !matches!(expr, CronExpr::AnyValueExpr | CronExpr::NoSpecificValueExpr),
+    DayMatching::Intersection => false,
+  }
+}
+
+/// Returns whether the instant is on a multiple of the interval in seconds, counted from the Unix epoch.
+pub(crate) fn is_every<Tz: TimeZone>(instant: &DateTime<Tz>, interval: u64) -> bool {
+  instant.timestamp_subsec_nanos() == 0
+    && i64::try_from(interval).is_ok_and(|interval| instant.timestamp().rem_euclid(interval) == 0)
+}
+
+/// Returns whether the day-of-week field matches the date.
+pub(crate) fn matches_day_of_week(date: NaiveDate, day_of_weeks: &CronExpr, numbering: DayOfWeekNumbering) -> bool {
+  let weekday = date.weekday().num_days_from_sunday() as u16;
+  match numbering {
+    DayOfWeekNumbering::SundayZero => {
+      // Sunday is either 0 or 7, and `L` is Saturday.
+      visit(&CronEnvironment::new(weekday, 0, 6).with_date(date), day_of_weeks)
+        || (weekday == 0 && visit(&CronEnvironment::new(7, 0, 6).with_date(date), day_of_weeks))
+    }
+    DayOfWeekNumbering::SundayOne => visit(&CronEnvironment::new(weekday + 1, 1, 7).with_date(date), day_of_weeks),
+  }
+}
+
+/// Returns whether the field depends on the calendar, i.e. not only on the value and the length of the month.
+pub(crate) fn depends_on_date(expr: &CronExpr) -> bool {
+  match expr {
+    CronExpr::NearestWeekdayExpr(_) | CronExpr::NthDayOfWeekExpr { .. } | CronExpr::LastDayOfWeekExpr(_) => true,
+    CronExpr::ListExpr(list) => list.iter().any(depends_on_date),
+    _ => false,
+  }
+}
+
+/// Returns the weekday nearest to the day `target` of the month of `date`, without leaving the month.
+fn nearest_weekday(date: NaiveDate, target: u16) -> Option<u16> {
+  let last_day = get_days_from_month(date.year(), date.month()) as u16;
+  let weekday = date.with_day(target as u32)?.weekday();
+  Some(match weekday {
+    Weekday::Sat if target == 1 => 3,
+    Weekday::Sat => target - 1,
+    Weekday::Sun if target == last_day => target - 2,
+    Weekday::Sun => target + 1,
+    _ => target,
+  })
+}
+
+/// Returns how far `env.now` is from `start` within the range `start-end`, or `None` if it is out of the range.
+/// A range whose start is after its end wraps around the end of the field, e.g. `22-2` or `FRI-MON`.
+fn range_offset(env: &CronEnvironment, start: u16, end: u16) -> Option<u16> {
+  if start <= end {
+    (start <= env.now && env.now <= end).then(|| env.now - start)
+  } else if start <= env.now {
+    Some(env.now - start)
+  } else if env.now <= end && env.min <= env.now {
+    Some((env.max + 1).saturating_sub(start) + env.now - env.min)
+  } else {
+    None
+  }
+}
+
+/// Returns whether the field matches the value `env.now`.
+pub(crate) fn visit(env: &CronEnvironment, ast: &CronExpr) -> bool {
+  match ast {
+    CronExpr::AnyValueExpr | CronExpr::NoSpecificValueExpr => true,
+    CronExpr::LastValueExpr if env.now == env.max => true,
+    CronExpr::LastOffsetExpr(offset) => env.max.checked_sub(*offset) == Some(env.now),
+    CronExpr::NearestWeekdayExpr(box target) => {
+      let target = match target {
+        CronExpr::ValueExpr(day) => Some(*day),
+        CronExpr::LastValueExpr => Some(env.max),
+        CronExpr::LastOffsetExpr(offset) => env.max.checked_sub(*offset),
+        _ => None,
+      };
+      env
+        .date
+        .zip(target)
+        .and_then(|(date, target)| nearest_weekday(date, target))
+        == Some(env.now)
+    }
+    CronExpr::NthDayOfWeekExpr { day_of_week, nth } => {
+      env.now == *day_of_week && env.date.is_some_and(|date| date.day().div_ceil(7) == *nth as u32)
+    }
+    CronExpr::LastDayOfWeekExpr(day_of_week) => {
+      env.now == *day_of_week
+        && env
+          .date
+          .is_some_and(|date| date.day() as i64 + 7 > get_days_from_month(date.year(), date.month()))
+    }
+    CronExpr::ValueExpr(n) if env.now == *n => true,
+    CronExpr::ListExpr(list) => list.iter().any(|e| visit(env, e)),
+    CronExpr::RangeExpr {
+      from: box CronExpr::ValueExpr(start),
+      to: box CronExpr::ValueExpr(end),
+      per_option,
+    } => match (range_offset(env, *start, *end), per_option) {
+      (Some(_), box CronExpr::NoOp) => true,
+      (Some(offset), box CronExpr::ValueExpr(per)) => *per != 0 && offset % per == 0,
+      _ => false,
+    },
+    CronExpr::PerExpr {
+      digit: box CronExpr::AnyValueExpr,
+      option: box CronExpr::ValueExpr(per),
+    } if *per > 0 => ((env.min as usize)..=(env.max as usize))
+      .step_by(*per as usize)
+      .into_iter()
+      .any(|e| e == env.now as usize),
+    _ => false,
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -85,11 +202,13 @@ mod tests {
     let date_time = Utc.with_ymd_and_hms(2021, 1, 1, 1, 1, 1).unwrap();
     let evaluator = CronEvaluator::new(&date_time);
     let expr = CronExpr::CronExpr {
+      secs: None,
       mins: Box::from(CronExpr::AnyValueExpr),
       hours: Box::from(CronExpr::AnyValueExpr),
       days: Box::from(CronExpr::AnyValueExpr),
       months: Box::from(CronExpr::AnyValueExpr),
       day_of_weeks: Box::from(CronExpr::AnyValueExpr),
+      years: None,
     };
     let result = evaluator.eval(&expr);
     assert!(result)
@@ -100,20 +219,60 @@ mod tests {
     let date_time = Utc.with_ymd_and_hms(2021, 1, 1, 1, 1, 1).unwrap();
     let evaluator = CronEvaluator::new(&date_time);
     let expr = CronExpr::CronExpr {
+      secs: None,
       mins: Box::from(CronExpr::ValueExpr(1)),
       hours: Box::from(CronExpr::ValueExpr(1)),
       days: Box::from(CronExpr::ValueExpr(1)),
       months: Box::from(CronExpr::ValueExpr(1)),
       day_of_weeks: Box::from(CronExpr::AnyValueExpr),
+      years: None,
     };
     let result = evaluator.eval(&expr);
     assert!(result)
   }
 
+  #[test]
+  fn test_wrap_around_range() {
+    let range = |from: u16, to: u16, per_option: CronExpr| CronExpr::RangeExpr {
+      from: Box::from(CronExpr::ValueExpr(from)),
+      to: Box::from(CronExpr::ValueExpr(to)),
+      per_option: Box::from(per_option),
+    };
+    let values = |expr: &CronExpr, min: u16, max: u16| {
+      (min..=max)
+        .filter(|now| visit(&CronEnvironment::new(*now, min, max), expr))
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(values(&range(22, 2, CronExpr::NoOp), 0, 23), vec![0, 1, 2, 22, 23]);
+    assert_eq!(values(&range(21, 3, CronExpr::ValueExpr(2)), 0, 23), vec![1, 3, 21, 23]);
+    assert_eq!(values(&range(11, 2, CronExpr::NoOp), 1, 12), vec![1, 2, 11, 12]);
+    assert_eq!(values(&range(30, 2, CronExpr::NoOp), 1, 30), vec![1, 2, 30]);
+    assert_eq!(values(&range(2, 5, CronExpr::ValueExpr(2)), 0, 59), vec![2, 4]);
+  }
+
+  #[test]
+  fn test_wrap_around_day_of_week() {
+    // FRI-MON
+    let expr = CronExpr::RangeExpr {
+      from: Box::from(CronExpr::ValueExpr(5)),
+      to: Box::from(CronExpr::ValueExpr(1)),
+      per_option: Box::from(CronExpr::NoOp),
+    };
+    let weekdays = (3..10)
+      .map(|day| NaiveDate::from_ymd_opt(2023, 1, day).unwrap())
+      .filter(|date| matches_day_of_week(*date, &expr, DayOfWeekNumbering::SundayZero))
+      .map(|date| date.weekday())
+      .collect::<Vec<_>>();
+    assert_eq!(weekdays, vec![Weekday::Fri, Weekday::Sat, Weekday::Sun, Weekday::Mon]);
+  }
+
   #[test]
   fn test_example() {
     let input = "* * * * *".chars().collect::<Vec<_>>();
-    let expr = (instruction() - end()).parse(&input).to_result().unwrap();
+    let expr = (instruction(CronOptions::default()) - end())
+      .parse(&input)
+      .to_result()
+      .unwrap();
     let date_time = Utc.with_ymd_and_hms(2021, 1, 1, 1, 1, 1).unwrap();
     let evaluator = CronEvaluator::new(&date_time);
     let result = evaluator.eval(&expr);
@@ -1,9 +1,25 @@
+use crate::cron_description::{describe, CronLocale, EnglishLocale};
+use crate::cron_options::CronOptions;
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum CronExpr {
   NoOp,
-  ValueExpr(u8),
+  ValueExpr(u16),
   LastValueExpr,
   AnyValueExpr,
+  /// `?`, no specific value of the day of the month or the day of the week.
+  NoSpecificValueExpr,
+  /// `L-n`, the n-th day before the last day of the month.
+  LastOffsetExpr(u16),
+  /// `nW` or `LW`, the weekday nearest to the day, within the same month.
+  NearestWeekdayExpr(Box<CronExpr>),
+  /// `d#n`, the n-th given day of the week of the month.
+  NthDayOfWeekExpr {
+    day_of_week: u16,
+    nth: u16,
+  },
+  /// `dL`, the last given day of the week of the month.
+  LastDayOfWeekExpr(u16),
   PerExpr {
     digit: Box<CronExpr>,
     option: Box<CronExpr>,
@@ -14,11 +30,34 @@ pub enum CronExpr {
     per_option: Box<CronExpr>,
   },
   ListExpr(Vec<CronExpr>),
+  /// `@every <duration>`, every given number of seconds counted from the Unix epoch.
+  EveryExpr(u64),
+  /// `@reboot`, once at startup instead of at a date and time.
+  RebootExpr,
   CronExpr {
+    /// The seconds field, `None` if the expression fires at the start of the minute.
+    secs: Option<Box<CronExpr>>,
     mins: Box<CronExpr>,
     hours: Box<CronExpr>,
     days: Box<CronExpr>,
     months: Box<CronExpr>,
     day_of_weeks: Box<CronExpr>,
+    /// The year field, `None` if the expression fires every year.
+    years: Option<Box<CronExpr>>,
   },
 }
+
+impl CronExpr {
+  /// Returns the description of the expression in English, e.g. `At minute 0 past every 2nd hour on Monday through
+  /// Friday` for `0 */2 * * MON-FRI`.<br/>
+  /// 式の英語の説明を返す。
+  pub fn describe(&self) -> String {
+    self.describe_with(CronOptions::default(), &EnglishLocale)
+  }
+
+  /// Returns the description of the expression parsed with the options, in the locale.<br/>
+  /// オプションで解析した式の、ロケールに従った説明を返す。
+  pub fn describe_with(&self, options: CronOptions, locale: &dyn CronLocale) -> String {
+    describe(self, options, locale)
+  }
+}
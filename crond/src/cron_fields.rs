@@ -0,0 +1,487 @@
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Timelike};
+
+use crate::cron_environment::CronEnvironment;
+use crate::cron_evaluator::{depends_on_date, is_restricted, matches_day_of_week, visit};
+use crate::cron_expr::CronExpr;
+use crate::cron_options::{CronOptions, DayOfWeekNumbering};
+use crate::get_days_from_month;
+
+/// A Sunday, the first day of the week the day-of-week field is compiled with.
+const SUNDAY: NaiveDate = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+
+/// The Gregorian calendar repeats every 400 years, so a schedule that does not fire in 400 years never fires.
+const SEARCH_YEARS: i32 = 400;
+
+/// A CronExpr compiled into a bitset of the matching values per field.<br/>
+/// フィールドごとに一致する値のビットセットへコンパイルしたCronExpr。
+///
+/// The next fire time is found by jumping to the next matching month, day, hour and minute in turn, instead of
+/// evaluating the expression minute by minute.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CronFields {
+  /// The seconds, `None` if the expression fires at the start of the minute.
+  seconds: Option<u64>,
+  minutes: u64,
+  hours: u64,
+  /// The days of the month, per the length of the month from 28 to 31 days, since `L` and `*/n` depend on it.
+  days: [u64; 4],
+  months: u64,
+  /// The days of the week, counted from Sunday as 0.
+  day_of_weeks: u64,
+  /// Whether a day matches either the day of the month or the day of the week.
+  either_day: bool,
+  /// The day-of-month field if it depends on the calendar, e.g. `15W`, so that `days` cannot be used.
+  calendar_days: Option<CronExpr>,
+  /// The day-of-week field if it depends on the calendar, e.g. `5#3`, so that `day_of_weeks` cannot be used.
+  calendar_day_of_weeks: Option<CronExpr>,
+  numbering: DayOfWeekNumbering,
+  /// The year field, `None` if the expression fires every year.
+  years: Option<CronExpr>,
+}
+
+impl CronFields {
+  pub(crate) fn compile(expr: &CronExpr, options: CronOptions) -> Option<Self> {
+    match expr {
+      CronExpr::CronExpr {
+        secs,
+        box mins,
+        box hours,
+        box days,
+        box day_of_weeks,
+        box months,
+        years,
+      } => {
+        let matching = options.day_matching();
+        let numbering = options.day_of_week_numbering();
+        Some(Self {
+          seconds: secs.as_deref().map(|secs| bits(secs, 0, 59)),
+          minutes: bits(mins, 0, 59),
+          hours: bits(hours, 0, 23),
+          days: [28, 29, 30, 31].map(|last_day| bits(days, 1, last_day)),
+          months: bits(months, 1, 12),
+          day_of_weeks: (0..7)
+            .filter(|weekday| matches_day_of_week(SUNDAY + Duration::days(*weekday), day_of_weeks, numbering))
+            .fold(0, |acc, weekday| acc | 1 << weekday),
+          either_day: is_restricted(days, matching) && is_restricted(day_of_weeks, matching),
+          calendar_days: depends_on_date(days).then(|| days.clone()),
+          calendar_day_of_weeks: depends_on_date(day_of_weeks).then(|| day_of_weeks.clone()),
+          numbering,
+          years: years.as_deref().cloned(),
+        })
+      }
+      _ => None,
+    }
+  }
+
+  /// Returns whether the date and time matches, ignoring the seconds if the expression has no seconds field.
+  pub(crate) fn matches(&self, date_time: &NaiveDateTime) -> bool {
+    let date = date_time.date();
+    self.seconds.is_none_or(|seconds| has_bit(seconds, date_time.second()))
+      && self.matches_year(date.year())
+      && has_bit(self.minutes, date_time.minute())
+      && has_bit(self.hours, date_time.hour())
+      && has_bit(self.months, date.month())
+      && has_bit(self.day_mask(date.year(), date.month()), date.day())
+  }
+
+  /// Returns the first matching date and time at or after `from`, on a whole second, or on a whole minute if the
+  /// expression has no seconds field.
+  pub(crate) fn next_from(&self, from: &NaiveDateTime) -> Option<NaiveDateTime> {
+    let (mut date_time, step) = match self.seconds {
+      Some(_) => (from.with_nanosecond(0)?, Duration::seconds(1)),
+      None => (from.with_second(0)?.with_nanosecond(0)?, Duration::minutes(1)),
+    };
+    if date_time < *from {
+      date_time += step;
+    }
+    let limit = date_time.year().checked_add(SEARCH_YEARS)?;
+    let (mut date, mut hour, mut minute, mut second) = (
+      date_time.date(),
+      date_time.hour(),
+      date_time.minute(),
+      date_time.second(),
+    );
+    while date.year() <= limit {
+      if !self.matches_year(date.year()) {
+        let year = (date.year() + 1..=limit).find(|year| self.matches_year(*year))?;
+        (date, hour, minute, second) = (NaiveDate::from_ymd_opt(year, 1, 1)?, 0, 0, 0);
+        continue;
+      }
+      match next_bit(self.months, date.month()) {
+        Some(month) if month == date.month() => {}
+        next => {
+          date = match next {
+            Some(month) => NaiveDate::from_ymd_opt(date.year(), month, 1)?,
+            None => NaiveDate::from_ymd_opt(date.year() + 1, self.months.trailing_zeros(), 1)?,
+          };
+          (hour, minute, second) = (0, 0, 0);
+          continue;
+        }
+      }
+      match next_bit(self.day_mask(date.year(), date.month()), date.day()) {
+        Some(day) if day == date.day() => {}
+        next => {
+          date = match next {
+            Some(day) => date.with_day(day)?,
+            None => first_day_of_next_month(date)?,
+          };
+          (hour, minute, second) = (0, 0, 0);
+          continue;
+        }
+      }
+      match next_bit(self.hours, hour) {
+        Some(next) if next == hour => {}
+        Some(next) => (hour, minute, second) = (next, 0, 0),
+        None => {
+          (date, hour, minute, second) = (date.succ_opt()?, 0, 0, 0);
+          continue;
+        }
+      }
+      match next_bit(self.minutes, minute) {
+        Some(next) if next == minute => {}
+        Some(next) => (minute, second) = (next, 0),
+        None => {
+          (hour, minute, second) = (hour + 1, 0, 0);
+          continue;
+        }
+      }
+      match self.seconds.map(|seconds| next_bit(seconds, second)) {
+        None => return date.and_hms_opt(hour, minute, 0),
+        Some(Some(second)) => return date.and_hms_opt(hour, minute, second),
+        Some(None) => (minute, second) = (minute + 1, 0),
+      }
+    }
+    None
+  }
+
+  /// Returns the last matching date and time at or before `from`, on a whole second, or on a whole minute if the
+  /// expression has no seconds field.
+  pub(crate) fn prev_from(&self, from: &NaiveDateTime) -> Option<NaiveDateTime> {
+    let date_time = match self.seconds {
+      Some(_) => from.with_nanosecond(0)?,
+      None => from.with_second(0)?.with_nanosecond(0)?,
+    };
+    let limit = date_time.year().checked_sub(SEARCH_YEARS)?;
+    // Moves to the last second before the date and time.
+    let before = |date_time: Option<NaiveDateTime>| {
+      let date_time = date_time? - Duration::seconds(1);
+      Some((
+        date_time.date(),
+        date_time.hour(),
+        date_time.minute(),
+        date_time.second(),
+      ))
+    };
+    let (mut date, mut hour, mut minute, mut second) = (
+      date_time.date(),
+      date_time.hour(),
+      date_time.minute(),
+      date_time.second(),
+    );
+    while date.year() >= limit {
+      if !self.matches_year(date.year()) {
+        let year = (limit..date.year()).rev().find(|year| self.matches_year(*year))?;
+        (date, hour, minute, second) = before(NaiveDate::from_ymd_opt(year + 1, 1, 1)?.and_hms_opt(0, 0, 0))?;
+        continue;
+      }
+      match prev_bit(self.months, date.month()) {
+        Some(month) if month == date.month() => {}
+        prev => {
+          let first_day = match prev {
+            Some(month) => first_day_of_next_month(NaiveDate::from_ymd_opt(date.year(), month, 1)?)?,
+            None => NaiveDate::from_ymd_opt(date.year(), 1, 1)?,
+          };
+          (date, hour, minute, second) = before(first_day.and_hms_opt(0, 0, 0))?;
+          continue;
+        }
+      }
+      match prev_bit(self.day_mask(date.year(), date.month()), date.day()) {
+        Some(day) if day == date.day() => {}
+        prev => {
+          let next_day = match prev {
+            Some(day) => date.with_day(day)?.succ_opt()?,
+            None => date.with_day(1)?,
+          };
+          (date, hour, minute, second) = before(next_day.and_hms_opt(0, 0, 0))?;
+          continue;
+        }
+      }
+      match prev_bit(self.hours, hour) {
+        Some(prev) if prev == hour => {}
+        Some(prev) => (hour, minute, second) = (prev, 59, 59),
+        None => {
+          (date, hour, minute, second) = before(date.and_hms_opt(0, 0, 0))?;
+          continue;
+        }
+      }
+      match prev_bit(self.minutes, minute) {
+        Some(prev) if prev == minute => {}
+        Some(prev) => (minute, second) = (prev, 59),
+        None => {
+          (date, hour, minute, second) = before(date.and_hms_opt(hour, 0, 0))?;
+          continue;
+        }
+      }
+      match self.seconds.map(|seconds| prev_bit(seconds, second)) {
+        None => return date.and_hms_opt(hour, minute, 0),
+        Some(Some(second)) => return date.and_hms_opt(hour, minute, second),
+        Some(None) => (date, hour, minute, second) = before(date.and_hms_opt(hour, minute, 0))?,
+      }
+    }
+    None
+  }
+
+  fn matches_year(&self, year: i32) -> bool {
+    self
+      .years
+      .as_ref()
+      .is_none_or(|years| u16::try_from(year).is_ok_and(|year| visit(&CronEnvironment::new(year, 1970, 2099), years)))
+  }
+
+  /// Returns the bitset of the matching days of the month, combining the day-of-month and day-of-week fields.
+  fn day_mask(&self, year: i32, month: u32) -> u64 {
+    let last_day = get_days_from_month(year, month) as u32;
+    let Some(first_day) = NaiveDate::from_ymd_opt(year, month, 1) else {
+      return 0;
+    };
+    let dates = first_day.iter_days().take(last_day as usize);
+    let days = match &self.calendar_days {
+      Some(days) => dates
+        .clone()
+        .filter(|date| {
+          visit(
+            &CronEnvironment::new(date.day() as u16, 1, last_day as u16).with_date(*date),
+            days,
+          )
+        })
+        .fold(0, |acc, date| acc | 1 << date.day()),
+      None => self.days[(last_day - 28) as usize],
+    };
+    let day_of_weeks = dates
+      .filter(|date| match &self.calendar_day_of_weeks {
+        Some(day_of_weeks) => matches_day_of_week(*date, day_of_weeks, self.numbering),
+        None => has_bit(self.day_of_weeks, date.weekday().num_days_from_sunday()),
+      })
+      .fold(0, |acc, date| acc | 1 << date.day());
+    if self.either_day {
+      days | day_of_weeks
+    } else {
+      days & day_of_weeks
+    }
+  }
+}
+
+fn bits(expr: &CronExpr, min: u16, max: u16) -> u64 {
+  (min..=max)
+    .filter(|now| visit(&CronEnvironment::new(*now, min, max), expr))
+    .fold(0, |acc, now| acc | 1 << now)
+}
+
+fn has_bit(bits: u64, index: u32) -> bool {
+  index < 64 && bits & (1 << index) != 0
+}
+
+/// Returns the index of the first set bit at or after `index`.
+fn next_bit(bits: u64, index: u32) -> Option<u32> {
+  let rest = bits.checked_shr(index)?.checked_shl(index)?;
+  (rest != 0).then(|| rest.trailing_zeros())
+}
+
+/// Returns the index of the last set bit at or before `index`.
+fn prev_bit(bits: u64, index: u32) -> Option<u32> {
+  let rest = bits & u64::MAX >> 63u32.saturating_sub(index);
+  (rest != 0).then(|| 63 - rest.leading_zeros())
+}
+
+fn first_day_of_next_month(date: NaiveDate) -> Option<NaiveDate> {
+  match date.month() {
+    12 => NaiveDate::from_ymd_opt(date.year() + 1, 1, 1),
+    month => NaiveDate::from_ymd_opt(date.year(), month + 1, 1),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::cron_evaluator::CronEvaluator;
+  use crate::cron_options::CronDialect;
+  use crate::cron_parser::{instruction, CronParser};
+  use chrono::{TimeZone, Utc};
+  use oni_comb_parser_rs::prelude::*;
+
+  /// Compiles the expression without the validation, which rejects the expressions that never fire.
+  fn fields(expr: &str) -> CronFields {
+    let input = expr.chars().collect::<Vec<_>>();
+    let expr = instruction(CronOptions::default()).parse_all(&input).unwrap();
+    CronFields::compile(&expr, CronOptions::default()).unwrap()
+  }
+
+  fn date_time(s: &str) -> NaiveDateTime {
+    NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+  }
+
+  #[test]
+  fn test_next_from() {
+    let cases = [
+      ("* * * * *", "2021-01-01 00:00:00", "2021-01-01 00:00:00"),
+      ("* * * * *", "2021-01-01 00:00:01", "2021-01-01 00:01:00"),
+      ("0-59/30 0-23/2 * * *", "2021-01-01 01:01:00", "2021-01-01 02:00:00"),
+      ("59 23 31 12 *", "2021-01-01 00:00:00", "2021-12-31 23:59:00"),
+      ("0 0 29 2 *", "2021-03-01 00:00:00", "2024-02-29 00:00:00"),
+      ("0 0 29 2 *", "2096-03-01 00:00:00", "2104-02-29 00:00:00"),
+      ("0 0 31 * *", "2021-04-01 00:00:00", "2021-05-31 00:00:00"),
+      ("0 0 30 * *", "2021-02-01 00:00:00", "2021-03-30 00:00:00"),
+      ("0 0 13 * FRI", "2021-01-09 00:00:00", "2021-01-13 00:00:00"),
+      ("0 12 * * SUN", "2021-01-03 12:00:00", "2021-01-03 12:00:00"),
+      ("0 12 * * SUN", "2021-01-03 12:00:01", "2021-01-10 12:00:00"),
+    ];
+    for (expr, from, expected) in cases {
+      assert_eq!(
+        fields(expr).next_from(&date_time(from)),
+        Some(date_time(expected)),
+        "{} from {}",
+        expr,
+        from
+      );
+    }
+  }
+
+  #[test]
+  fn test_next_from_agrees_with_evaluator() {
+    let exprs = [
+      "*/7 1-5 * * *",
+      "0 0 1,15 * 3",
+      "0 0 */2 * 1",
+      "30 12 * 2 SAT-SUN",
+      "0 0 31 * *",
+      "15 */6 29 2 *",
+      "0 9 LW * *",
+      "0 9 L-2 * *",
+      "0 9 15W * *",
+      "0 9 ? * 1#2",
+      "0 9 ? * 5L",
+      "0 9 1 * FRI#1",
+    ];
+    let start = Utc.with_ymd_and_hms(2023, 12, 1, 0, 0, 0).unwrap();
+    for expr in exprs {
+      let parsed = CronParser::parse(expr).unwrap();
+      let fields = CronFields::compile(&parsed, CronOptions::default()).unwrap();
+      let scanned = (0..60 * 24 * 120)
+        .map(|minutes| start + Duration::minutes(minutes))
+        .filter(|date_time| CronEvaluator::new(date_time).eval(&parsed))
+        .map(|date_time| date_time.naive_utc())
+        .collect::<Vec<_>>();
+      let end = (start + Duration::days(120)).naive_utc();
+      let jumped = std::iter::successors(fields.next_from(&start.naive_utc()), |date_time| {
+        fields.next_from(&(*date_time + Duration::minutes(1)))
+      })
+      .take_while(|date_time| *date_time < end)
+      .collect::<Vec<_>>();
+      assert_eq!(jumped, scanned, "{}", expr);
+    }
+  }
+
+  #[test]
+  fn test_next_from_with_seconds() {
+    let options = CronOptions::quartz();
+    let fields = |expr: &str| CronFields::compile(&CronParser::parse_with(expr, options).unwrap(), options).unwrap();
+    let cases = [
+      ("*/15 * * * * 1", "2021-01-01 00:00:01", "2021-01-03 00:00:00"),
+      ("*/15 * * * * *", "2021-01-01 00:00:01", "2021-01-01 00:00:15"),
+      ("*/15 * * * * *", "2021-01-01 00:00:45.5", "2021-01-01 00:01:00"),
+      ("5 59 23 31 12 * 2030", "2021-01-01 00:00:00", "2030-12-31 23:59:05"),
+    ];
+    for (expr, from, expected) in cases {
+      let from = NaiveDateTime::parse_from_str(from, "%Y-%m-%d %H:%M:%S%.f").unwrap();
+      assert_eq!(fields(expr).next_from(&from), Some(date_time(expected)), "{}", expr);
+    }
+  }
+
+  #[test]
+  fn test_next_from_never() {
+    assert_eq!(fields("0 0 30 2 *").next_from(&date_time("2021-01-01 00:00:00")), None);
+  }
+
+  #[test]
+  fn test_prev_from() {
+    let cases = [
+      ("* * * * *", "2021-01-01 00:00:00", "2021-01-01 00:00:00"),
+      ("* * * * *", "2021-01-01 00:00:59", "2021-01-01 00:00:00"),
+      ("0-59/30 0-23/2 * * *", "2021-01-01 01:01:00", "2021-01-01 00:30:00"),
+      ("59 23 31 12 *", "2021-12-31 23:58:00", "2020-12-31 23:59:00"),
+      ("0 0 29 2 *", "2024-02-28 00:00:00", "2020-02-29 00:00:00"),
+      ("0 0 29 2 *", "2104-02-28 00:00:00", "2096-02-29 00:00:00"),
+      ("0 0 31 * *", "2021-05-30 00:00:00", "2021-03-31 00:00:00"),
+      ("0 0 13 * FRI", "2021-01-12 00:00:00", "2021-01-08 00:00:00"),
+      ("0 12 * * SUN", "2021-01-10 11:59:00", "2021-01-03 12:00:00"),
+      ("0 9 LW * *", "2021-05-31 08:00:00", "2021-04-30 09:00:00"),
+    ];
+    for (expr, from, expected) in cases {
+      assert_eq!(
+        fields(expr).prev_from(&date_time(from)),
+        Some(date_time(expected)),
+        "{} from {}",
+        expr,
+        from
+      );
+    }
+    assert_eq!(fields("0 0 30 2 *").prev_from(&date_time("2021-01-01 00:00:00")), None);
+  }
+
+  #[test]
+  fn test_prev_from_agrees_with_next_from() {
+    let exprs = [
+      "*/7 1-5 * * *",
+      "0 0 1,15 * 3",
+      "15 */6 29 2 *",
+      "0 9 L-2 * *",
+      "0 9 ? * 5L",
+    ];
+    let options = CronOptions::new().with_dialect(CronDialect::Spring);
+    let exprs = exprs.iter().map(|expr| (fields(expr), Duration::minutes(1))).chain([(
+      CronFields::compile(&CronParser::parse_with("*/20 0 0 * * *", options).unwrap(), options).unwrap(),
+      Duration::seconds(1),
+    )]);
+    let end = date_time("2025-01-01 00:00:00");
+    for (fields, step) in exprs {
+      let forward = std::iter::successors(fields.next_from(&date_time("2023-12-01 00:00:00")), |date_time| {
+        fields.next_from(&(*date_time + step))
+      })
+      .take_while(|date_time| *date_time < end)
+      .collect::<Vec<_>>();
+      let mut backward = std::iter::successors(fields.prev_from(&(end - step)), |date_time| {
+        fields.prev_from(&(*date_time - step))
+      })
+      .take(forward.len())
+      .collect::<Vec<_>>();
+      backward.reverse();
+      assert_eq!(backward, forward);
+    }
+  }
+
+  #[test]
+  fn test_matches() {
+    let fields = fields("0 0 13 * FRI");
+    assert!(fields.matches(&date_time("2021-01-13 00:00:00")));
+    assert!(fields.matches(&date_time("2021-01-15 00:00:00")));
+    assert!(!fields.matches(&date_time("2021-01-14 00:00:00")));
+  }
+
+  #[test]
+  fn test_prev_bit() {
+    assert_eq!(prev_bit(0b1010, 0), None);
+    assert_eq!(prev_bit(0b1010, 1), Some(1));
+    assert_eq!(prev_bit(0b1010, 2), Some(1));
+    assert_eq!(prev_bit(0b1010, 63), Some(3));
+    assert_eq!(prev_bit(u64::MAX, 100), Some(63));
+  }
+
+  #[test]
+  fn test_next_bit() {
+    assert_eq!(next_bit(0b1010, 0), Some(1));
+    assert_eq!(next_bit(0b1010, 1), Some(1));
+    assert_eq!(next_bit(0b1010, 2), Some(3));
+    assert_eq!(next_bit(0b1010, 4), None);
+    assert_eq!(next_bit(u64::MAX, 64), None);
+  }
+}
@@ -5,6 +5,7 @@ use chrono::{DateTime, TimeZone};
 use intervals_rs::{Interval, LimitValue};
 
 use crate::cron_interval_iterator::CronIntervalIterator;
+use crate::cron_interval_reverse_iterator::CronIntervalReverseIterator;
 use crate::cron_specification::Specification;
 
 /// A structure representing an interval of CROND date and time.<br/>
@@ -49,4 +50,12 @@ impl<Tz: TimeZone, S: Specification<DateTime<Tz>>> CronInterval<Tz, S> {
     let date_time = timezone.timestamp_millis_opt(*timestamp).unwrap();
     CronIntervalIterator::new(timezone, date_time.clone(), date_time, Rc::new(self.clone()))
   }
+
+  /// Returns a CronIntervalReverseIterator, going backward from the end.<br/>
+  /// 終了日時から逆順に辿るCronIntervalReverseIteratorを返す。
+  pub fn iter_rev(&self, timezone: Tz) -> CronIntervalReverseIterator<Tz, S> {
+    let timestamp = self.underlying.as_upper_limit().as_value().unwrap();
+    let date_time = timezone.timestamp_millis_opt(*timestamp).unwrap();
+    CronIntervalReverseIterator::new(timezone, date_time.clone(), date_time, Rc::new(self.clone()))
+  }
 }
@@ -32,24 +32,13 @@ impl<Tz: TimeZone, S: Specification<DateTime<Tz>>> Iterator for CronIntervalIter
   type Item = DateTime<Tz>;
 
   fn next(&mut self) -> Option<Self::Item> {
-    self.curr = self.next.clone();
-    self.next = self.next.clone() + Duration::minutes(1);
-    match self.end_value() {
-      None => {
-        self.proceed_next();
-        let curr: DateTime<Tz> = self.curr.clone();
-        Some(curr)
-      }
-      Some(end) => {
-        if end >= self.curr {
-          self.proceed_next();
-          let curr: DateTime<Tz> = self.curr.clone();
-          Some(curr)
-        } else {
-          None
-        }
-      }
+    let curr = self.cron_interval.cron_specification.next_satisfied_from(&self.next)?;
+    if self.end_value().is_some_and(|end| curr > end) {
+      return None;
     }
+    self.curr = curr.clone();
+    self.next = curr.clone() + Duration::seconds(1);
+    Some(curr)
   }
 }
 
@@ -75,18 +64,11 @@ impl<Tz: TimeZone, S: Specification<DateTime<Tz>>> CronIntervalIterator<Tz, S> {
       None
     }
   }
-
-  fn proceed_next(&mut self) {
-    while !self.cron_interval.cron_specification.is_satisfied_by(&self.curr) {
-      self.curr = self.next.clone();
-      self.next = self.next.clone() + Duration::minutes(1);
-    }
-  }
 }
 
 #[cfg(test)]
 mod tests {
-  use chrono::{TimeZone, Utc};
+  use chrono::{TimeZone, Timelike, Utc};
   use intervals_rs::LimitValue;
 
   use crate::cron_expr::CronExpr;
@@ -108,4 +90,25 @@ mod tests {
 
     itr.take(5).for_each(|e| println!("{:?}", e));
   }
+
+  #[derive(Clone)]
+  struct QuarterHours;
+
+  impl Specification<DateTime<Utc>> for QuarterHours {
+    fn is_satisfied_by(&self, datetime: &DateTime<Utc>) -> bool {
+      datetime.minute().is_multiple_of(15)
+    }
+  }
+
+  #[test]
+  fn test_iterator_with_default_scan() {
+    let dt: DateTime<Utc> = Utc.with_ymd_and_hms(2021, 1, 1, 1, 1, 0).unwrap();
+    let interval = CronInterval::new(LimitValue::Limit(dt), LimitValue::Limitless, QuarterHours);
+    let minutes = interval.iter(Utc).take(3).map(|e| e.minute()).collect::<Vec<_>>();
+    assert_eq!(minutes, vec![15, 30, 45]);
+    assert_eq!(
+      QuarterHours.previous_satisfied_from(&dt),
+      Some(Utc.with_ymd_and_hms(2021, 1, 1, 1, 0, 0).unwrap())
+    );
+  }
 }
@@ -0,0 +1,105 @@
+use std::rc::Rc;
+
+use chrono::{DateTime, Duration, TimeZone};
+
+use crate::cron_interval::CronInterval;
+use crate::cron_specification::Specification;
+
+/// Iterator for The CronInterval, going backward from the end.<br/>
+/// CronIntervalを終了日時から逆順に辿るイテレータ。
+#[derive(Clone)]
+pub struct CronIntervalReverseIterator<Tz: TimeZone, S: Specification<DateTime<Tz>>> {
+  timezone: Tz,
+  curr: DateTime<Tz>,
+  next: DateTime<Tz>,
+  cron_interval: Rc<CronInterval<Tz, S>>,
+}
+
+impl<Tz: TimeZone, S: Specification<DateTime<Tz>>> CronIntervalReverseIterator<Tz, S> {
+  /// The factory method.
+  /// ファクトリメソッド。
+  pub fn new(timezone: Tz, curr: DateTime<Tz>, next: DateTime<Tz>, cron_interval: Rc<CronInterval<Tz, S>>) -> Self {
+    Self {
+      timezone,
+      curr,
+      next,
+      cron_interval,
+    }
+  }
+}
+
+impl<Tz: TimeZone, S: Specification<DateTime<Tz>>> Iterator for CronIntervalReverseIterator<Tz, S> {
+  type Item = DateTime<Tz>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let curr = self
+      .cron_interval
+      .cron_specification
+      .previous_satisfied_from(&self.next)?;
+    if self.start_value().is_some_and(|start| curr < start) {
+      return None;
+    }
+    self.curr = curr.clone();
+    self.next = curr.clone() - Duration::seconds(1);
+    Some(curr)
+  }
+}
+
+impl<Tz: TimeZone, S: Specification<DateTime<Tz>>> CronIntervalReverseIterator<Tz, S> {
+  /// Returns the timezone of CronIntervalReverseIterator.<br/>
+  /// CronIntervalReverseIteratorのタイムゾーンを返す。
+  pub fn timezone(&self) -> &Tz {
+    &self.timezone
+  }
+
+  /// Returns the CronInterval.<br/>
+  /// CronIntervalを返す。
+  pub fn cron_interval(&self) -> Rc<CronInterval<Tz, S>> {
+    self.cron_interval.clone()
+  }
+
+  fn start_value(&self) -> Option<DateTime<Tz>> {
+    if self.cron_interval.underlying.has_lower_limit() {
+      let timestamp = self.cron_interval.underlying.as_lower_limit().as_value().unwrap();
+      let date_time = self.timezone.timestamp_millis_opt(*timestamp).unwrap();
+      Some(date_time)
+    } else {
+      None
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use chrono::{TimeZone, Utc};
+  use intervals_rs::LimitValue;
+
+  use crate::cron_expr::CronExpr;
+  use crate::cron_parser::CronParser;
+  use crate::cron_specification::CronSpecification;
+
+  use super::*;
+
+  #[test]
+  fn test_iterator() {
+    let start: DateTime<Utc> = Utc.with_ymd_and_hms(2021, 1, 1, 1, 1, 0).unwrap();
+    let end: DateTime<Utc> = Utc.with_ymd_and_hms(2021, 1, 1, 6, 0, 0).unwrap();
+    let expr: CronExpr = CronParser::parse("0-59/30 0-23/2 * * *").unwrap();
+    let interval: CronInterval<Utc, CronSpecification> = CronInterval::new(
+      LimitValue::Limit(start),
+      LimitValue::Limit(end),
+      CronSpecification::new(expr),
+    );
+    let dt_vec = interval.iter_rev(Utc).collect::<Vec<_>>();
+    assert_eq!(
+      dt_vec,
+      vec![
+        Utc.with_ymd_and_hms(2021, 1, 1, 6, 0, 0).unwrap(),
+        Utc.with_ymd_and_hms(2021, 1, 1, 4, 30, 0).unwrap(),
+        Utc.with_ymd_and_hms(2021, 1, 1, 4, 0, 0).unwrap(),
+        Utc.with_ymd_and_hms(2021, 1, 1, 2, 30, 0).unwrap(),
+        Utc.with_ymd_and_hms(2021, 1, 1, 2, 0, 0).unwrap(),
+      ]
+    );
+  }
+}
@@ -0,0 +1,160 @@
+/// The layout of the fields of a CROND expression.<br/>
+/// CROND式のフィールドの構成。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum CronDialect {
+  /// `min hour day month day-of-week`, as in Unix cron.<br/>
+  /// 分 時 日 月 曜日(Unix cron)。
+  #[default]
+  Unix,
+  /// `sec min hour day month day-of-week`, as in Spring.<br/>
+  /// 秒 分 時 日 月 曜日(Spring)。
+  Spring,
+  /// `sec min hour day month day-of-week [year]`, as in Quartz.<br/>
+  /// 秒 分 時 日 月 曜日 [年](Quartz)。
+  Quartz,
+  /// `min hour day month day-of-week year`, as in AWS EventBridge.<br/>
+  /// 分 時 日 月 曜日 年(AWS EventBridge)。
+  EventBridge,
+}
+
+impl CronDialect {
+  /// Returns whether the expressions start with the seconds field.
+  pub fn has_seconds(self) -> bool {
+    matches!(self, CronDialect::Spring | CronDialect::Quartz)
+  }
+}
+
+/// How the days of the week are numbered in the day-of-week field.<br/>
+/// 曜日フィールドにおける曜日の番号付け。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DayOfWeekNumbering {
+  /// Sunday is 0 or 7 and Saturday is 6, as in POSIX and Vixie cron.<br/>
+  /// 日曜日が0または7、土曜日が6(POSIX, Vixie cron)。
+  #[default]
+  SundayZero,
+  /// Sunday is 1 and Saturday is 7, as in Quartz.<br/>
+  /// 日曜日が1、土曜日が7(Quartz)。
+  SundayOne,
+}
+
+impl DayOfWeekNumbering {
+  /// Returns the number of Sunday, the first day of the week.
+  pub fn sunday(self) -> u16 {
+    match self {
+      DayOfWeekNumbering::SundayZero => 0,
+      DayOfWeekNumbering::SundayOne => 1,
+    }
+  }
+
+  /// Returns the smallest and the largest numbers accepted in the day-of-week field.
+  pub fn bounds(self) -> (u16, u16) {
+    match self {
+      DayOfWeekNumbering::SundayZero => (0, 7),
+      DayOfWeekNumbering::SundayOne => (1, 7),
+    }
+  }
+}
+
+/// How the day-of-month and the day-of-week fields are combined.<br/>
+/// 日フィールドと曜日フィールドの組み合わせ方。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DayMatching {
+  /// A day matches either field if both are restricted, i.e. neither starts with `*`, as in Vixie cron.
+  /// So `0 0 1 * MON` fires on the 1st and on Mondays, while `0 0 */2 * MON` fires on the Mondays that are odd days.<br/>
+  /// どちらのフィールドも`*`で始まらない場合は、いずれかに一致する日に実行する(Vixie cron)。
+  #[default]
+  Vixie,
+  /// A day matches either field if both are restricted, i.e. neither is exactly `*`, as in POSIX.<br/>
+  /// どちらのフィールドも`*`でない場合は、いずれかに一致する日に実行する(POSIX)。
+  Posix,
+  /// A day must match both fields.<br/>
+  /// 両方のフィールドに一致する日に実行する。
+  Intersection,
+}
+
+/// What happens to the fire times whose local time is skipped or repeated by a daylight saving time transition.<br/>
+/// 夏時間の切り替えで現地時刻が飛ばされる、または繰り返される場合の実行時刻の扱い。
+///
+/// A fire time in a repeated hour fires once, at the earlier one, except for the jobs that follow the wall clock with
+/// [DstPolicy::Vixie].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DstPolicy {
+  /// As documented for Vixie cron: a job at a fixed time fires at the transition if its time is skipped, and once if
+  /// it is repeated. A job with `*` at the start of the minute or hour field, e.g. `*/15 * * * *` or `@hourly`,
+  /// follows the wall clock, so it skips the skipped times and fires again in the repeated hour.<br/>
+  /// Vixie cronの仕様に従う。時刻を指定したジョブは、飛ばされた時刻を切り替え時に実行し、繰り返される時刻には一度だけ実行する。
+  /// 分または時が`*`で始まるジョブは壁時計に従う。
+  #[default]
+  Vixie,
+  /// A fire time that is skipped fires at the transition.<br/>
+  /// 飛ばされた実行時刻は切り替え時に実行する。
+  FireAtTransition,
+  /// A fire time that is skipped does not fire.<br/>
+  /// 飛ばされた実行時刻は実行しない。
+  Skip,
+}
+
+/// The dialect options of the evaluation of a CROND expression.<br/>
+/// CROND式の評価における方言のオプション。
+///
+/// The default follows Vixie cron: the expressions have five fields, Sunday is 0 or 7, the days are matched with
+/// [DayMatching::Vixie], and the daylight saving time transitions are handled with [DstPolicy::Vixie].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CronOptions {
+  dialect: CronDialect,
+  day_of_week_numbering: DayOfWeekNumbering,
+  day_matching: DayMatching,
+  dst_policy: DstPolicy,
+}
+
+impl CronOptions {
+  /// Creates the options of Vixie cron.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Creates the options of Quartz, where the expressions have the seconds and year fields, Sunday is 1 and a day
+  /// must match both fields.
+  pub fn quartz() -> Self {
+    Self::new()
+      .with_dialect(CronDialect::Quartz)
+      .with_day_of_week_numbering(DayOfWeekNumbering::SundayOne)
+      .with_day_matching(DayMatching::Intersection)
+  }
+
+  pub fn with_dialect(mut self, dialect: CronDialect) -> Self {
+    self.dialect = dialect;
+    self
+  }
+
+  pub fn with_day_of_week_numbering(mut self, day_of_week_numbering: DayOfWeekNumbering) -> Self {
+    self.day_of_week_numbering = day_of_week_numbering;
+    self
+  }
+
+  pub fn with_day_matching(mut self, day_matching: DayMatching) -> Self {
+    self.day_matching = day_matching;
+    self
+  }
+
+  pub fn with_dst_policy(mut self, dst_policy: DstPolicy) -> Self {
+    self.dst_policy = dst_policy;
+    self
+  }
+
+  pub fn dialect(&self) -> CronDialect {
+    self.dialect
+  }
+
+  pub fn day_of_week_numbering(&self) -> DayOfWeekNumbering {
+    self.day_of_week_numbering
+  }
+
+  pub fn day_matching(&self) -> DayMatching {
+    self.day_matching
+  }
+
+  pub fn dst_policy(&self) -> DstPolicy {
+    self.dst_policy
+  }
+}
@@ -1,63 +1,60 @@
+use crate::cron_error::CronError;
 use crate::cron_expr::CronExpr;
 use crate::cron_expr::CronExpr::*;
+use crate::cron_options::{CronDialect, CronOptions, DayOfWeekNumbering};
+use crate::cron_validator::validate;
 use oni_comb_parser_rs::prelude::*;
 
-fn min_digit<'a>() -> Parser<'a, char, CronExpr> {
-  ((elm_in('1', '5') + elm_digit())
-    .map(|(e1, e2)| ValueExpr((e1 as u8 - 48) * 10 + e2 as u8 - 48))
-    .attempt()
-    | (elm('0') * elm_digit()).map(|e| ValueExpr(e as u8 - 48)).attempt()
-    | (elm_digit()).map(|e| ValueExpr(e as u8 - 48)))
-  .cache()
+/// Parses a number, leaving the check of the range of the field to the validation.
+fn number<'a>() -> Parser<'a, char, CronExpr> {
+  elm_digit()
+    .of_many1()
+    .map(|digits| {
+      digits
+        .iter()
+        .try_fold(0u16, |n, e| n.checked_mul(10)?.checked_add(*e as u16 - 48))
+    })
+    .with_filter(Option::is_some)
+    .map(|n| ValueExpr(n.unwrap()))
+    .cache()
 }
 
-fn hour_digit<'a>() -> Parser<'a, char, CronExpr> {
-  ((elm('2') + elm_in('0', '3'))
-    .map(|(e1, e2)| ValueExpr((e1 as u8 - 48) * 10 + e2 as u8 - 48))
-    .attempt()
-    | (elm('1') + elm_digit())
-      .map(|(e1, e2)| ValueExpr((e1 as u8 - 48) * 10 + e2 as u8 - 48))
-      .attempt()
-    | (elm('0') * elm_digit()).map(|e| ValueExpr(e as u8 - 48)).attempt()
-    | elm_digit().map(|e| ValueExpr(e as u8 - 48)).debug("hour_digit_4"))
-  .cache()
+/// The names of the months, from January.
+const MONTH_NAMES: [&str; 12] = [
+  "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+/// The names of the days of the week, from Sunday.
+const DAY_OF_WEEK_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// Parses one of the `names` case-insensitively, numbered from `first`.
+fn name<'a>(names: &'static [&'static str], first: u16) -> Parser<'a, char, CronExpr> {
+  names
+    .iter()
+    .zip(first..)
+    .map(|(name, n)| tag_no_case(name).map(move |_| ValueExpr(n)).attempt())
+    .reduce(|p1, p2| p1 | p2)
+    .unwrap()
+    .cache()
 }
 
-fn day_digit<'a>() -> Parser<'a, char, CronExpr> {
-  ((elm('3') + elm_of("01"))
-    .map(|(e1, e2)| ValueExpr((e1 as u8 - 48) * 10 + e2 as u8 - 48))
-    .attempt()
-    | (elm_of("12") + elm_digit())
-      .map(|(e1, e2)| ValueExpr((e1 as u8 - 48) * 10 + e2 as u8 - 48))
-      .attempt()
-    | (elm('0') * elm_digit_1_9()).map(|e| ValueExpr(e as u8 - 48)).attempt()
-    | elm_digit_1_9().map(|e| ValueExpr(e as u8 - 48)))
-  .cache()
+fn month_name<'a>() -> Parser<'a, char, CronExpr> {
+  name(&MONTH_NAMES, 1)
 }
 
-fn month_digit<'a>() -> Parser<'a, char, CronExpr> {
-  ((elm('1') + elm_of("012"))
-    .map(|(e1, e2)| ValueExpr((e1 as u8 - 48) * 10 + e2 as u8 - 48))
-    .attempt()
-    | (elm('0') * elm_digit_1_9()).map(|e| ValueExpr(e as u8 - 48)).attempt()
-    | elm_digit_1_9().map(|e| ValueExpr(e as u8 - 48)))
-  .cache()
+fn day_of_week_digit<'a>(numbering: DayOfWeekNumbering) -> Parser<'a, char, CronExpr> {
+  name(&DAY_OF_WEEK_NAMES, numbering.sunday())
 }
 
-fn day_of_week_digit<'a>() -> Parser<'a, char, CronExpr> {
-  (tag("SUN").map(|_| ValueExpr(1)).attempt()
-    | tag("MON").map(|_| ValueExpr(2)).attempt()
-    | tag("TUE").map(|_| ValueExpr(3)).attempt()
-    | tag("WED").map(|_| ValueExpr(4)).attempt()
-    | tag("THU").map(|_| ValueExpr(5)).attempt()
-    | tag("FRI").map(|_| ValueExpr(6)).attempt()
-    | tag("SAT").map(|_| ValueExpr(7)).attempt()
-    | elm('L').map(|_| LastValueExpr))
-  .cache()
+fn question<'a>() -> Parser<'a, char, CronExpr> {
+  elm('?').map(|_| NoSpecificValueExpr).cache()
 }
 
-fn day_of_week_text<'a>() -> Parser<'a, char, CronExpr> {
-  elm_in('1', '7').map(|e| ValueExpr(e as u8 - 48)).cache()
+fn value_of(expr: CronExpr) -> u16 {
+  match expr {
+    ValueExpr(n) => n,
+    _ => unreachable!("the digit parsers return ValueExpr"),
+  }
 }
 
 fn asterisk<'a>() -> Parser<'a, char, CronExpr> {
@@ -94,9 +91,10 @@ fn list(p: Parser<char, CronExpr>) -> Parser<char, CronExpr> {
     .attempt()
 }
 
+// A step is always a number, even in the fields that accept names.
 macro_rules! range {
   ( $x:expr ) => {
-    ($x - elm('-') + $x + range_per($x))
+    ($x - elm('-') + $x + range_per(number()))
       .map(|((e1, e2), e3)| RangeExpr {
         from: Box::from(e1),
         to: Box::from(e2),
@@ -108,33 +106,137 @@ macro_rules! range {
 
 macro_rules! digit_instruction {
   ( $x:expr ) => {
-    list(range!($x) | $x) | asterisk_per($x) | asterisk()
+    list(range!($x) | $x) | asterisk_per(number()) | asterisk()
+  };
+}
+
+fn day_instruction<'a>() -> Parser<'a, char, CronExpr> {
+  question()
+    | tag("LW")
+      .map(|_| NearestWeekdayExpr(Box::from(LastValueExpr)))
+      .attempt()
+    | (tag("L-") * number()).map(|e| LastOffsetExpr(value_of(e))).attempt()
+    | elm('L').map(|_| LastValueExpr)
+    | (number() - elm('W'))
+      .map(|e| NearestWeekdayExpr(Box::from(e)))
+      .attempt()
+    | digit_instruction!(number())
+}
+
+fn day_of_week_instruction<'a>(numbering: DayOfWeekNumbering) -> Parser<'a, char, CronExpr> {
+  let value = || number() | day_of_week_digit(numbering);
+  question()
+    | (value() - elm('#') + elm_in('1', '5'))
+      .map(|(e, nth)| NthDayOfWeekExpr {
+        day_of_week: value_of(e),
+        nth: nth as u16 - 48,
+      })
+      .attempt()
+    | (value() - elm('L')).map(|e| LastDayOfWeekExpr(value_of(e))).attempt()
+    | digit_instruction!(value() | elm('L').map(|_| LastValueExpr))
+}
+
+/// Parses a duration such as `90s` or `1h30m`, into seconds.
+fn duration<'a>() -> Parser<'a, char, u64> {
+  let unit = elm('d').map(|_| 86400) | elm('h').map(|_| 3600) | elm('m').map(|_| 60) | elm('s').map(|_| 1);
+  (elm_digit().of_many1() + unit)
+    .map(|(digits, unit): (Vec<char>, u64)| {
+      digits
+        .iter()
+        .try_fold(0u64, |n, e| n.checked_mul(10)?.checked_add(*e as u64 - 48))?
+        .checked_mul(unit)
+    })
+    .of_many1()
+    .map(|parts| parts.into_iter().try_fold(0u64, |total, part| total.checked_add(part?)))
+    .with_filter(|total| total.is_some_and(|total| total > 0))
+    .map(|total| total.unwrap())
+}
+
+/// Parses the nicknames of Vixie cron such as `@daily`, and `@every <duration>`.
+fn nickname<'a>(options: CronOptions) -> Parser<'a, char, CronExpr> {
+  // The nicknames fire at minute 0, and at second 0 in the dialects with seconds.
+  let at = move |hours: Option<u16>, days: Option<u16>, months: Option<u16>, day_of_weeks: Option<u16>| {
+    let value = |value: Option<u16>| Box::from(value.map_or(AnyValueExpr, ValueExpr));
+    CronExpr::CronExpr {
+      secs: options.dialect().has_seconds().then(|| value(Some(0))),
+      mins: value(Some(0)),
+      hours: value(hours),
+      days: value(days),
+      months: value(months),
+      day_of_weeks: value(day_of_weeks),
+      years: (options.dialect() == CronDialect::EventBridge).then(|| value(None)),
+    }
   };
+  let sunday = options.day_of_week_numbering().sunday();
+  elm('@')
+    * ((tag("yearly") | tag("annually"))
+      .map(move |_| at(Some(0), Some(1), Some(1), None))
+      .attempt()
+      | tag("monthly").map(move |_| at(Some(0), Some(1), None, None)).attempt()
+      | tag("weekly")
+        .map(move |_| at(Some(0), None, None, Some(sunday)))
+        .attempt()
+      | (tag("daily") | tag("midnight"))
+        .map(move |_| at(Some(0), None, None, None))
+        .attempt()
+      | tag("hourly").map(move |_| at(None, None, None, None)).attempt()
+      | tag("reboot").map(|_| RebootExpr).attempt()
+      | (tag("every") * elm(' ').of_many1() * duration()).map(EveryExpr))
 }
 
-pub(crate) fn instruction<'a>() -> Parser<'a, char, CronExpr> {
-  (digit_instruction!(min_digit()) - elm(' ') + digit_instruction!(hour_digit()) - elm(' ')
-    + digit_instruction!(day_digit())
+pub(crate) fn instruction<'a>(options: CronOptions) -> Parser<'a, char, CronExpr> {
+  let numbering = options.day_of_week_numbering();
+  let secs = match options.dialect() {
+    CronDialect::Spring | CronDialect::Quartz => (digit_instruction!(number()) - elm(' ')).map(Some),
+    CronDialect::Unix | CronDialect::EventBridge => successful(None),
+  };
+  let years = match options.dialect() {
+    CronDialect::Quartz => (elm(' ') * digit_instruction!(number())).opt(),
+    CronDialect::EventBridge => (elm(' ') * digit_instruction!(number())).map(Some),
+    CronDialect::Unix | CronDialect::Spring => successful(None),
+  };
+  let fields = (secs + digit_instruction!(number()) - elm(' ') + digit_instruction!(number()) - elm(' ')
+    + day_instruction()
     - elm(' ')
-    + digit_instruction!(month_digit())
+    + digit_instruction!(number() | month_name())
     - elm(' ')
-    + digit_instruction!(day_of_week_text() | day_of_week_digit()))
-  .map(|((((mins, hours), days), months), day_of_weeks)| CronExpr::CronExpr {
-    mins: Box::from(mins),
-    hours: Box::from(hours),
-    days: Box::from(days),
-    months: Box::from(months),
-    day_of_weeks: Box::from(day_of_weeks),
-  })
+    + day_of_week_instruction(numbering)
+    + years)
+    .map(
+      |((((((secs, mins), hours), days), months), day_of_weeks), years)| CronExpr::CronExpr {
+        secs: secs.map(Box::from),
+        mins: Box::from(mins),
+        hours: Box::from(hours),
+        days: Box::from(days),
+        months: Box::from(months),
+        day_of_weeks: Box::from(day_of_weeks),
+        years: years.map(Box::from),
+      },
+    );
+  nickname(options) | fields
 }
 
 pub struct CronParser;
 
 impl CronParser {
-  pub fn parse<'a>(input: &str) -> Result<CronExpr, String> {
-    let input = input.chars().collect::<Vec<_>>();
-    let x = (instruction() - end()).parse(&input).to_result();
-    x.map_err(|e| e.to_string())
+  pub fn parse<'a>(input: &str) -> Result<CronExpr, CronError> {
+    Self::parse_with(input, CronOptions::default())
+  }
+
+  /// Parses a CROND string with the fields of the dialect of `options`, and the days of the week numbered as in
+  /// `options`, e.g. `SUN` is parsed as 1 with [crate::DayOfWeekNumbering::SundayOne].<br/>
+  /// `options`の方言のフィールド構成と曜日の番号付けでCROND文字列を解析する。
+  ///
+  /// The values are validated after parsing, so that e.g. `*/0`, `30-10` in the minute field and `0 0 30 2 *` are
+  /// rejected with the field and the position.<br/>
+  /// 解析後に値を検証し、エラーにはフィールドと位置を含める。
+  pub fn parse_with(input: &str, options: CronOptions) -> Result<CronExpr, CronError> {
+    let chars = input.chars().collect::<Vec<_>>();
+    let expr = instruction(options)
+      .parse_all(&chars)
+      .map_err(|e| CronError::Syntax(e.to_string()))?;
+    validate(input, &expr, options)?;
+    Ok(expr)
   }
 }
 
@@ -160,11 +262,13 @@ mod tests {
     assert_eq!(
       result,
       CronExpr::CronExpr {
+        secs: None,
         mins: Box::from(AnyValueExpr),
         hours: Box::from(AnyValueExpr),
         days: Box::from(AnyValueExpr),
         months: Box::from(AnyValueExpr),
-        day_of_weeks: Box::from(AnyValueExpr)
+        day_of_weeks: Box::from(AnyValueExpr),
+        years: None,
       }
     );
   }
@@ -175,19 +279,184 @@ mod tests {
     assert_eq!(
       result,
       CronExpr::CronExpr {
+        secs: None,
         mins: Box::from(ValueExpr(1)),
         hours: Box::from(ValueExpr(1)),
         days: Box::from(ValueExpr(1)),
         months: Box::from(ValueExpr(1)),
-        day_of_weeks: Box::from(ValueExpr(1))
+        day_of_weeks: Box::from(ValueExpr(1)),
+        years: None,
       }
     );
   }
 
+  #[test]
+  fn test_instruction_dialects() {
+    let parse =
+      |input: &str, dialect: CronDialect| CronParser::parse_with(input, CronOptions::new().with_dialect(dialect));
+    let fields = |expr: CronExpr| match expr {
+      CronExpr::CronExpr { secs, years, .. } => (secs.map(|e| *e), years.map(|e| *e)),
+      _ => unreachable!(),
+    };
+
+    assert!(parse("*/10 * * * * *", CronDialect::Unix).is_err());
+    assert_eq!(
+      fields(parse("*/10 * * * * *", CronDialect::Spring).unwrap()),
+      (
+        Some(PerExpr {
+          digit: Box::from(AnyValueExpr),
+          option: Box::from(ValueExpr(10))
+        }),
+        None
+      )
+    );
+    assert!(parse("0 * * * * * 2030", CronDialect::Spring).is_err());
+    assert_eq!(
+      fields(parse("30 0 12 * * 1", CronDialect::Quartz).unwrap()),
+      (Some(ValueExpr(30)), None)
+    );
+    assert_eq!(
+      fields(parse("30 0 12 * * 1 2030-2035", CronDialect::Quartz).unwrap()),
+      (
+        Some(ValueExpr(30)),
+        Some(RangeExpr {
+          from: Box::from(ValueExpr(2030)),
+          to: Box::from(ValueExpr(2035)),
+          per_option: Box::from(NoOp)
+        })
+      )
+    );
+    assert_eq!(
+      fields(parse("0 12 * * 1 2030,2040", CronDialect::EventBridge).unwrap()),
+      (None, Some(ListExpr(vec![ValueExpr(2030), ValueExpr(2040)])))
+    );
+    assert!(parse("0 12 * * 1", CronDialect::EventBridge).is_err());
+    assert!(parse("0 12 * * 1 1969", CronDialect::EventBridge).is_err());
+    assert!(parse("0 12 * * 1 2100", CronDialect::EventBridge).is_err());
+  }
+
+  #[test]
+  fn test_day_instructions() {
+    let days = |input: &str| {
+      let input = input.chars().collect::<Vec<_>>();
+      let result = (day_instruction() - end())
+        .parse_as_result(&input)
+        .map_err(|e| e.to_string());
+      result
+    };
+    assert_eq!(days("?").unwrap(), NoSpecificValueExpr);
+    assert_eq!(days("L").unwrap(), LastValueExpr);
+    assert_eq!(days("L-3").unwrap(), LastOffsetExpr(3));
+    assert_eq!(days("LW").unwrap(), NearestWeekdayExpr(Box::from(LastValueExpr)));
+    assert_eq!(days("15W").unwrap(), NearestWeekdayExpr(Box::from(ValueExpr(15))));
+    assert_eq!(days("1,15").unwrap(), ListExpr(vec![ValueExpr(1), ValueExpr(15)]));
+    assert!(days("W").is_err());
+    assert!(CronParser::parse("0 0 32W * *").is_err());
+
+    let numbering = DayOfWeekNumbering::SundayOne;
+    let day_of_weeks = |input: &str| {
+      let input = input.chars().collect::<Vec<_>>();
+      let result = (day_of_week_instruction(numbering) - end())
+        .parse_as_result(&input)
+        .map_err(|e| e.to_string());
+      result
+    };
+    assert_eq!(day_of_weeks("?").unwrap(), NoSpecificValueExpr);
+    assert_eq!(day_of_weeks("L").unwrap(), LastValueExpr);
+    assert_eq!(
+      day_of_weeks("5#3").unwrap(),
+      NthDayOfWeekExpr { day_of_week: 5, nth: 3 }
+    );
+    assert_eq!(
+      day_of_weeks("FRI#1").unwrap(),
+      NthDayOfWeekExpr { day_of_week: 6, nth: 1 }
+    );
+    assert_eq!(day_of_weeks("6L").unwrap(), LastDayOfWeekExpr(6));
+    assert_eq!(
+      day_of_weeks("MON-FRI").unwrap(),
+      RangeExpr {
+        from: Box::from(ValueExpr(2)),
+        to: Box::from(ValueExpr(6)),
+        per_option: Box::from(NoOp)
+      }
+    );
+    assert!(day_of_weeks("5#6").is_err());
+    assert!(CronParser::parse_with("0 0 0 ? * 0#1", CronOptions::quartz()).is_err());
+  }
+
+  #[test]
+  fn test_nicknames() {
+    let parse = |input: &str, options: CronOptions| CronParser::parse_with(input, options).unwrap();
+    let options = CronOptions::default();
+    assert_eq!(parse("@yearly", options), CronParser::parse("0 0 1 1 *").unwrap());
+    assert_eq!(parse("@annually", options), CronParser::parse("0 0 1 1 *").unwrap());
+    assert_eq!(parse("@monthly", options), CronParser::parse("0 0 1 * *").unwrap());
+    assert_eq!(parse("@weekly", options), CronParser::parse("0 0 * * 0").unwrap());
+    assert_eq!(parse("@daily", options), CronParser::parse("0 0 * * *").unwrap());
+    assert_eq!(parse("@midnight", options), CronParser::parse("0 0 * * *").unwrap());
+    assert_eq!(parse("@hourly", options), CronParser::parse("0 * * * *").unwrap());
+    assert_eq!(
+      parse("@weekly", CronOptions::quartz()),
+      CronParser::parse_with("0 0 0 * * 1", CronOptions::quartz()).unwrap()
+    );
+    let event_bridge = CronOptions::new().with_dialect(CronDialect::EventBridge);
+    assert_eq!(
+      parse("@daily", event_bridge),
+      CronParser::parse_with("0 0 * * * *", event_bridge).unwrap()
+    );
+    assert_eq!(parse("@reboot", options), RebootExpr);
+    assert_eq!(parse("@every 5m", options), EveryExpr(300));
+    assert_eq!(parse("@every 1h30m", options), EveryExpr(5400));
+    assert_eq!(parse("@every 1d12h30s", options), EveryExpr(129630));
+    assert!(CronParser::parse("@every 0s").is_err());
+    assert!(CronParser::parse("@every 5").is_err());
+    assert!(CronParser::parse("@every 99999999999999999999s").is_err());
+    assert!(CronParser::parse("@fortnightly").is_err());
+    assert!(CronParser::parse("@daily *").is_err());
+  }
+
+  #[test]
+  fn test_names() {
+    let fields = |input: &str| match CronParser::parse(input).unwrap() {
+      CronExpr::CronExpr {
+        box months,
+        box day_of_weeks,
+        ..
+      } => (months, day_of_weeks),
+      _ => unreachable!(),
+    };
+    let range = |from: u16, to: u16, per_option: CronExpr| RangeExpr {
+      from: Box::from(ValueExpr(from)),
+      to: Box::from(ValueExpr(to)),
+      per_option: Box::from(per_option),
+    };
+    assert_eq!(fields("0 0 * jan mon"), (ValueExpr(1), ValueExpr(1)));
+    assert_eq!(fields("0 0 * Dec Sat"), (ValueExpr(12), ValueExpr(6)));
+    assert_eq!(
+      fields("0 0 * JAN-JUN/2 MON-FRI"),
+      (range(1, 6, ValueExpr(2)), range(1, 5, NoOp))
+    );
+    assert_eq!(
+      fields("0 0 * mar,Sep,12 sun,3,fri"),
+      (
+        ListExpr(vec![ValueExpr(3), ValueExpr(9), ValueExpr(12)]),
+        ListExpr(vec![ValueExpr(0), ValueExpr(3), ValueExpr(5)])
+      )
+    );
+    assert_eq!(fields("0 0 * NOV-FEB FRI-MON"), (range(11, 2, NoOp), range(5, 1, NoOp)));
+    assert!(CronParser::parse("0 0 * JANUARY *").is_err());
+    assert!(CronParser::parse("0 0 * * MONDAY").is_err());
+    // A step is a number, not a name.
+    assert!(CronParser::parse("0 0 * JAN-DEC/JAN *").is_err());
+    assert!(CronParser::parse("0 0 * */FEB *").is_err());
+    assert!(CronParser::parse("0 0 * * MON-FRI/MON").is_err());
+    assert!(CronParser::parse("0 0 * * */MON").is_err());
+  }
+
   #[test]
   fn test_digit_instruction_1() {
     let input = "*".chars().collect::<Vec<_>>();
-    let result = (digit_instruction!(min_digit()) - end())
+    let result = (digit_instruction!(number()) - end())
       .parse(&input)
       .to_result()
       .unwrap();
@@ -197,7 +466,7 @@ mod tests {
   #[test]
   fn test_digit_instruction_2() {
     let input = "*/2".chars().collect::<Vec<_>>();
-    let result = (digit_instruction!(min_digit()) - end())
+    let result = (digit_instruction!(number()) - end())
       .parse(&input)
       .to_result()
       .unwrap();
@@ -213,7 +482,7 @@ mod tests {
   #[test]
   fn test_digit_instruction_3() {
     let input = "1-10/2".chars().collect::<Vec<_>>();
-    let result = (digit_instruction!(min_digit()) - end())
+    let result = (digit_instruction!(number()) - end())
       .parse(&input)
       .to_result()
       .unwrap();
@@ -230,7 +499,7 @@ mod tests {
   #[test]
   fn test_digit_instruction_4() {
     let input = "1,2,3".chars().collect::<Vec<_>>();
-    let result = (digit_instruction!(min_digit()) - end())
+    let result = (digit_instruction!(number()) - end())
       .parse(&input)
       .to_result()
       .unwrap();
@@ -240,7 +509,7 @@ mod tests {
   #[test]
   fn test_digit_instruction_5() {
     let input = "1".chars().collect::<Vec<_>>();
-    let result = (digit_instruction!(min_digit()) - end())
+    let result = (digit_instruction!(number()) - end())
       .parse(&input)
       .to_result()
       .unwrap();
@@ -251,7 +520,7 @@ mod tests {
   fn test_list() {
     let s = (0..=59).map(|v| v.to_string()).collect::<Vec<_>>().join(",");
     let input = s.chars().collect::<Vec<_>>();
-    let result = (list(min_digit()) - end()).parse(&input).to_result().unwrap();
+    let result = (list(number()) - end()).parse(&input).to_result().unwrap();
     let values = (0..=59).map(|v| ValueExpr(v)).collect::<Vec<_>>();
     assert_eq!(result, ListExpr(values));
   }
@@ -264,7 +533,7 @@ mod tests {
       let s: &str = &format!("{:<02}-{:<02}/{:<02}", n1, n2, option);
       let input = s.chars().collect::<Vec<_>>();
       println!("{}", s);
-      let result = (range!(min_digit()) - end()).parse(&input).to_result().unwrap();
+      let result = (range!(number()) - end()).parse(&input).to_result().unwrap();
       assert_eq!(
         result,
         RangeExpr {
@@ -281,7 +550,7 @@ mod tests {
     for n in 0..59 {
       let s: &str = &format!("*/{:<02}", n);
       let input = s.chars().collect::<Vec<_>>();
-      let result = (asterisk_per(min_digit()) - end()).parse(&input).to_result().unwrap();
+      let result = (asterisk_per(number()) - end()).parse(&input).to_result().unwrap();
       assert_eq!(
         result,
         PerExpr {
@@ -295,78 +564,24 @@ mod tests {
   #[test]
   fn test_per() {
     let input = "/2".chars().collect::<Vec<_>>();
-    let _result = asterisk_per(min_digit()) - end();
-    let result = (per(min_digit()) - end()).parse(&input).to_result().unwrap();
+    let _result = asterisk_per(number()) - end();
+    let result = (per(number()) - end()).parse(&input).to_result().unwrap();
     assert_eq!(result, ValueExpr(2));
   }
 
   #[test]
-  fn test_min_digit() {
-    for n in 0..59 {
-      let s: &str = &format!("{:<02}", n);
-      let input = s.chars().collect::<Vec<_>>();
-      let result = (min_digit() - end()).parse(&input).to_result().unwrap();
-      assert_eq!(result, ValueExpr(n));
-    }
-    let input = "60".chars().collect::<Vec<_>>();
-    let result = (min_digit() - end()).parse(&input).to_result();
-    assert!(result.is_err());
-  }
-
-  #[test]
-  fn test_hour_digit() {
-    for n in 0..=23 {
-      if n < 10 {
-        let s = &n.to_string();
-        let input = s.chars().collect::<Vec<_>>();
-        let result: CronExpr = (hour_digit() - end()).debug("test").parse(&input).to_result().unwrap();
-        assert_eq!(result, ValueExpr(n));
-      }
-      let s: &str = &format!("{:<02}", n);
-      let input = s.chars().collect::<Vec<_>>();
-      let result: CronExpr = (hour_digit() - end()).parse(&input).to_result().unwrap();
-      assert_eq!(result, ValueExpr(n));
-    }
-    let input = "24".chars().collect::<Vec<_>>();
-    let result = (hour_digit() - end()).parse(&input).to_result();
-    assert!(result.is_err());
-  }
-
-  #[test]
-  fn test_day_digit() {
-    for n in 1..=31 {
-      if n < 10 {
-        let s: &str = &n.to_string();
+  fn test_number() {
+    for n in [0, 1, 9, 10, 59, 60, 2099, 65535] {
+      for s in [n.to_string(), format!("{:<02}", n)] {
         let input = s.chars().collect::<Vec<_>>();
-        let result: CronExpr = (day_digit() - end()).parse(&input).to_result().unwrap();
+        let result = (number() - end()).parse(&input).to_result().unwrap();
         assert_eq!(result, ValueExpr(n));
       }
-      let s: &str = &format!("{:<02}", n);
-      let input = s.chars().collect::<Vec<_>>();
-      let result: CronExpr = (day_digit() - end()).parse(&input).to_result().unwrap();
-      assert_eq!(result, ValueExpr(n));
     }
-    let input = "32".chars().collect::<Vec<_>>();
-    let result = (day_digit() - end()).parse(&input).to_result();
-    assert!(result.is_err());
-  }
-
-  #[test]
-  fn test_month_digit() {
-    for n in 1..=12 {
-      if n < 10 {
-        let s: &str = &n.to_string();
-        let input = s.chars().collect::<Vec<_>>();
-        let result: CronExpr = (month_digit() - end()).parse(&input).to_result().unwrap();
-        assert_eq!(result, ValueExpr(n));
-      }
-      let s: &str = &format!("{:<02}", n);
+    for s in ["", "-1", "65536", "1a"] {
       let input = s.chars().collect::<Vec<_>>();
-      let result: CronExpr = (month_digit() - end()).parse(&input).to_result().unwrap();
-      assert_eq!(result, ValueExpr(n));
+      let result = (number() - end()).parse(&input).to_result();
+      assert!(result.is_err(), "{}", s);
     }
-    let input = "13".chars().collect::<Vec<_>>();
-    let result = (month_digit() - end()).parse(&input).to_result();
-    assert!(result.is_err());
   }
 }
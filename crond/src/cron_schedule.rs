@@ -1,20 +1,39 @@
 use std::marker::PhantomData;
 
-use chrono::{DateTime, TimeZone};
+use chrono::{DateTime, Duration, TimeZone};
 use intervals_rs::LimitValue;
 
+use crate::cron_description::{CronLocale, EnglishLocale};
+use crate::cron_error::CronError;
 use crate::cron_expr::CronExpr;
 use crate::cron_interval::CronInterval;
 use crate::cron_interval_iterator::CronIntervalIterator;
+use crate::cron_interval_reverse_iterator::CronIntervalReverseIterator;
+use crate::cron_options::CronOptions;
 use crate::cron_parser::CronParser;
-use crate::cron_specification::CronSpecification;
+use crate::cron_specification::{CronSpecification, Specification};
+
+/// The kind of a CronSchedule.<br/>
+/// CronScheduleの種類。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CronScheduleKind {
+  /// Fires at the dates and times matching the fields, including the nicknames such as `@daily`.<br/>
+  /// フィールドに一致する日時に実行する(`@daily`などの別名を含む)。
+  Calendar,
+  /// `@every <duration>`, fires at a fixed rate.<br/>
+  /// 一定の間隔で実行する。
+  FixedRate,
+  /// `@reboot`, fires once at startup, so that `upcoming` returns no date and time.<br/>
+  /// 起動時に一度だけ実行する。`upcoming`は日時を返さない。
+  Reboot,
+}
 
 /// Facade that returns a CronInterval or CronIntervalIterator from a CROND string.<br/>
 /// CROND文字列からCronIntervalやCronIntervalIteratorを返すFacade。
 pub struct CronSchedule<Tz>
 where
   Tz: TimeZone, {
-  expr: CronExpr,
+  specification: CronSpecification,
   phantom: PhantomData<Tz>,
 }
 
@@ -29,37 +48,112 @@ impl<Tz: TimeZone> CronSchedule<Tz> {
   /// # Retun values(戻り値)
   ///
   /// * Err:
-  ///   - if CrondParser::parse fails
-  ///   - CrondParser::parseに失敗した場合
+  ///   - if CrondParser::parse fails, including the validation of the values
+  ///   - CrondParser::parseに失敗した場合(値の検証を含む)
   /// * Ok
   ///   - if CrondParser::parse succeeds
   ///   - CrondParser::parseに成功した場合
-  pub fn new(crond_string: &str) -> Result<Self, String> {
+  pub fn new(crond_string: &str) -> Result<Self, CronError> {
+    Self::with_options(crond_string, CronOptions::default())
+  }
+
+  /// The Factory method with the dialect options, e.g. [CronOptions::quartz].<br/>
+  /// 方言のオプションを指定するファクトリメソッド。
+  pub fn with_options(crond_string: &str, options: CronOptions) -> Result<Self, CronError> {
     Ok(Self {
-      expr: CronParser::parse(crond_string)?,
+      specification: CronSpecification::with_options(CronParser::parse_with(crond_string, options)?, options),
       phantom: PhantomData,
     })
   }
 
+  /// Returns the kind of the schedule.<br/>
+  /// スケジュールの種類を返す。
+  pub fn kind(&self) -> CronScheduleKind {
+    match self.specification.expr() {
+      CronExpr::EveryExpr(_) => CronScheduleKind::FixedRate,
+      CronExpr::RebootExpr => CronScheduleKind::Reboot,
+      _ => CronScheduleKind::Calendar,
+    }
+  }
+
+  /// Returns whether the schedule is `@reboot`.<br/>
+  /// スケジュールが`@reboot`かどうかを返す。
+  pub fn is_reboot(&self) -> bool {
+    self.kind() == CronScheduleKind::Reboot
+  }
+
+  /// Returns the description of the schedule in English.<br/>
+  /// スケジュールの英語の説明を返す。
+  pub fn describe(&self) -> String {
+    self.describe_with(&EnglishLocale)
+  }
+
+  /// Returns the description of the schedule in the locale.<br/>
+  /// ロケールに従ったスケジュールの説明を返す。
+  pub fn describe_with(&self, locale: &dyn CronLocale) -> String {
+    self
+      .specification
+      .expr()
+      .describe_with(self.specification.options(), locale)
+  }
+
   /// Returns a CronInterval with date and time candidates after the start date and time.<br/>
   /// 開始日時以降の日時候補を持つCronIntervalを返す。
   pub fn cron_interval(&self, start: DateTime<Tz>) -> CronInterval<Tz, CronSpecification> {
-    let spec = CronSpecification::new(self.expr.clone());
+    let spec = self.specification.clone();
     let start = LimitValue::Limit(start);
     let end = LimitValue::Limitless;
     CronInterval::new(start, end, spec)
   }
 
+  /// Returns a CronInterval with date and time candidates between the start and the end date and time, both
+  /// inclusive.<br/>
+  /// 開始日時から終了日時まで(両端を含む)の日時候補を持つCronIntervalを返す。
+  pub fn cron_interval_between(&self, start: DateTime<Tz>, end: DateTime<Tz>) -> CronInterval<Tz, CronSpecification> {
+    let spec = self.specification.clone();
+    CronInterval::new(LimitValue::Limit(start), LimitValue::Limit(end), spec)
+  }
+
   /// Returns a CronIntervalIterator with the date and time candidates after the start date and time.<br/>
   /// 開始日時以降の日時候補を持つCronIntervalIteratorを返す。
   pub fn upcoming(&self, start: DateTime<Tz>) -> CronIntervalIterator<Tz, CronSpecification> {
     self.cron_interval(start.clone()).iter(start.timezone())
   }
+
+  /// Returns a CronIntervalReverseIterator with the date and time candidates at or before the end date and time, from
+  /// the latest.<br/>
+  /// 終了日時以前の日時候補を新しい順に返すCronIntervalReverseIteratorを返す。
+  pub fn preceding(&self, end: DateTime<Tz>) -> CronIntervalReverseIterator<Tz, CronSpecification> {
+    let spec = self.specification.clone();
+    CronInterval::new(LimitValue::Limitless, LimitValue::Limit(end.clone()), spec).iter_rev(end.timezone())
+  }
+
+  /// Returns a CronIntervalIterator with the date and time candidates between the start and the end date and time,
+  /// both inclusive.<br/>
+  /// 開始日時から終了日時まで(両端を含む)の日時候補を持つCronIntervalIteratorを返す。
+  pub fn fire_times_between(
+    &self,
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+  ) -> CronIntervalIterator<Tz, CronSpecification> {
+    self.cron_interval_between(start.clone(), end).iter(start.timezone())
+  }
+
+  /// Returns the last date and time candidate strictly before the date and time, e.g. when a job last fired.<br/>
+  /// 指定日時より前の最後の日時候補を返す。
+  pub fn previous(&self, before: DateTime<Tz>) -> Option<DateTime<Tz>> {
+    self
+      .specification
+      .previous_satisfied_from(&(before - Duration::nanoseconds(1)))
+  }
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::cron_description::JapaneseLocale;
+  use crate::cron_error::CronField;
+  use crate::cron_options::CronDialect;
   use chrono::{TimeZone, Utc};
 
   #[test]
@@ -81,4 +175,173 @@ mod tests {
     // 2021-01-01T04:30:00Z
     // 2021-01-01T06:00:00Z
   }
+
+  #[test]
+  fn test_iterator_with_nickname() {
+    let dt: DateTime<Utc> = Utc.with_ymd_and_hms(2021, 1, 1, 1, 1, 0).unwrap();
+
+    let schedule = CronSchedule::new("@weekly").unwrap();
+    assert_eq!(schedule.kind(), CronScheduleKind::Calendar);
+    let dt_vec = schedule.upcoming(dt).take(2).collect::<Vec<_>>();
+    assert_eq!(dt_vec[0], Utc.with_ymd_and_hms(2021, 1, 3, 0, 0, 0).unwrap());
+    assert_eq!(dt_vec[1], Utc.with_ymd_and_hms(2021, 1, 10, 0, 0, 0).unwrap());
+  }
+
+  #[test]
+  fn test_iterator_with_every() {
+    let dt: DateTime<Utc> = Utc.with_ymd_and_hms(2021, 1, 1, 1, 1, 0).unwrap();
+
+    let schedule = CronSchedule::new("@every 25m").unwrap();
+    assert_eq!(schedule.kind(), CronScheduleKind::FixedRate);
+    let dt_vec = schedule.upcoming(dt).take(3).collect::<Vec<_>>();
+    assert_eq!(dt_vec[0], Utc.with_ymd_and_hms(2021, 1, 1, 1, 20, 0).unwrap());
+    assert_eq!(dt_vec[1], Utc.with_ymd_and_hms(2021, 1, 1, 1, 45, 0).unwrap());
+    assert_eq!(dt_vec[2], Utc.with_ymd_and_hms(2021, 1, 1, 2, 10, 0).unwrap());
+
+    let dt_vec = CronSchedule::new("@every 1h")
+      .unwrap()
+      .upcoming(Utc.with_ymd_and_hms(2021, 1, 1, 1, 0, 0).unwrap())
+      .take(2)
+      .collect::<Vec<_>>();
+    assert_eq!(dt_vec[0], Utc.with_ymd_and_hms(2021, 1, 1, 1, 0, 0).unwrap());
+    assert_eq!(dt_vec[1], Utc.with_ymd_and_hms(2021, 1, 1, 2, 0, 0).unwrap());
+  }
+
+  #[test]
+  fn test_reboot() {
+    let dt: DateTime<Utc> = Utc.with_ymd_and_hms(2021, 1, 1, 1, 1, 0).unwrap();
+
+    let schedule = CronSchedule::new("@reboot").unwrap();
+    assert!(schedule.is_reboot());
+    assert_eq!(schedule.kind(), CronScheduleKind::Reboot);
+    assert_eq!(schedule.upcoming(dt).next(), None);
+    assert!(!CronSchedule::<Utc>::new("@daily").unwrap().is_reboot());
+  }
+
+  #[test]
+  fn test_describe() {
+    let schedule = CronSchedule::<Utc>::new("0 */2 * * MON-FRI").unwrap();
+    assert_eq!(
+      schedule.describe(),
+      "At minute 0 past every 2nd hour on Monday through Friday"
+    );
+    assert_eq!(
+      schedule.describe_with(&JapaneseLocale),
+      "月曜日から金曜日までの2時間ごとの0分"
+    );
+    let schedule = CronSchedule::<Utc>::with_options("0 30 9 ? * 6#3", CronOptions::quartz()).unwrap();
+    assert_eq!(schedule.describe(), "At 09:30:00 on the 3rd Friday of the month");
+  }
+
+  #[test]
+  fn test_previous() {
+    let schedule = CronSchedule::<Utc>::new("0-59/30 0-23/2 * * *").unwrap();
+    let dt: DateTime<Utc> = Utc.with_ymd_and_hms(2021, 1, 1, 4, 30, 0).unwrap();
+
+    assert_eq!(
+      schedule.previous(dt),
+      Some(Utc.with_ymd_and_hms(2021, 1, 1, 4, 0, 0).unwrap())
+    );
+    assert_eq!(
+      schedule.previous(dt + Duration::milliseconds(1)),
+      Some(Utc.with_ymd_and_hms(2021, 1, 1, 4, 30, 0).unwrap())
+    );
+    assert_eq!(
+      schedule.previous(Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap()),
+      Some(Utc.with_ymd_and_hms(2020, 12, 31, 22, 30, 0).unwrap())
+    );
+    assert_eq!(CronSchedule::<Utc>::new("@reboot").unwrap().previous(dt), None);
+    assert_eq!(
+      CronSchedule::<Utc>::new("@every 25m").unwrap().previous(dt),
+      Some(Utc.with_ymd_and_hms(2021, 1, 1, 4, 15, 0).unwrap())
+    );
+  }
+
+  #[test]
+  fn test_preceding() {
+    let dt: DateTime<Utc> = Utc.with_ymd_and_hms(2021, 1, 1, 4, 30, 0).unwrap();
+
+    let dt_vec = CronSchedule::new("0-59/30 0-23/2 * * *")
+      .unwrap()
+      .preceding(dt)
+      .take(4)
+      .collect::<Vec<_>>();
+    assert_eq!(dt_vec[0], Utc.with_ymd_and_hms(2021, 1, 1, 4, 30, 0).unwrap());
+    assert_eq!(dt_vec[1], Utc.with_ymd_and_hms(2021, 1, 1, 4, 0, 0).unwrap());
+    assert_eq!(dt_vec[2], Utc.with_ymd_and_hms(2021, 1, 1, 2, 30, 0).unwrap());
+    assert_eq!(dt_vec[3], Utc.with_ymd_and_hms(2021, 1, 1, 2, 0, 0).unwrap());
+  }
+
+  #[test]
+  fn test_fire_times_between() {
+    let schedule = CronSchedule::<Utc>::new("0 12 * * *").unwrap();
+    let start: DateTime<Utc> = Utc.with_ymd_and_hms(2021, 1, 1, 12, 0, 0).unwrap();
+    let end: DateTime<Utc> = Utc.with_ymd_and_hms(2021, 1, 4, 12, 0, 0).unwrap();
+
+    let dt_vec = schedule.fire_times_between(start, end).collect::<Vec<_>>();
+    assert_eq!(dt_vec.len(), 4);
+    assert_eq!(dt_vec[0], start);
+    assert_eq!(dt_vec[3], end);
+    assert_eq!(
+      schedule
+        .fire_times_between(start + Duration::seconds(1), end - Duration::seconds(1))
+        .count(),
+      2
+    );
+  }
+
+  #[test]
+  fn test_invalid_schedule() {
+    let error = CronSchedule::<Utc>::new("0 0 30 2 *").err().unwrap();
+    assert_eq!(
+      error,
+      CronError::ImpossibleDate {
+        field: CronField::DayOfMonth,
+        position: 4
+      }
+    );
+    assert_eq!(error.to_string(), "the day-of-month field at 4 never falls on a date");
+    assert!(CronSchedule::<Utc>::new("*/0 * * * *").is_err());
+  }
+
+  #[test]
+  fn test_iterator_with_seconds() {
+    let dt: DateTime<Utc> = Utc.with_ymd_and_hms(2021, 1, 1, 1, 1, 5).unwrap();
+    let options = CronOptions::new().with_dialect(CronDialect::Spring);
+
+    let dt_vec = CronSchedule::with_options("*/20 1 1 * * *", options)
+      .unwrap()
+      .upcoming(dt)
+      .take(4)
+      .collect::<Vec<_>>();
+    assert_eq!(dt_vec[0], Utc.with_ymd_and_hms(2021, 1, 1, 1, 1, 20).unwrap());
+    assert_eq!(dt_vec[1], Utc.with_ymd_and_hms(2021, 1, 1, 1, 1, 40).unwrap());
+    assert_eq!(dt_vec[2], Utc.with_ymd_and_hms(2021, 1, 2, 1, 1, 0).unwrap());
+    assert_eq!(dt_vec[3], Utc.with_ymd_and_hms(2021, 1, 2, 1, 1, 20).unwrap());
+  }
+
+  #[test]
+  fn test_iterator_with_years() {
+    let dt: DateTime<Utc> = Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap();
+
+    let dt_vec = CronSchedule::with_options(
+      "0 0 1 1 * 2030-2099/5",
+      CronOptions::new().with_dialect(CronDialect::EventBridge),
+    )
+    .unwrap()
+    .upcoming(dt)
+    .take(2)
+    .collect::<Vec<_>>();
+    assert_eq!(dt_vec[0], Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap());
+    assert_eq!(dt_vec[1], Utc.with_ymd_and_hms(2035, 1, 1, 0, 0, 0).unwrap());
+
+    let dt_vec = CronSchedule::with_options(
+      "0 0 1 1 * 2020",
+      CronOptions::new().with_dialect(CronDialect::EventBridge),
+    )
+    .unwrap()
+    .upcoming(dt)
+    .collect::<Vec<_>>();
+    assert!(dt_vec.is_empty());
+  }
 }
@@ -1,25 +1,256 @@
-use crate::cron_evaluator::CronEvaluator;
-use chrono::{DateTime, TimeZone};
+use chrono::{DateTime, Duration, LocalResult, NaiveDateTime, TimeZone, Timelike};
 
+use crate::cron_evaluator::is_every;
 use crate::cron_expr::CronExpr;
+use crate::cron_fields::CronFields;
+use crate::cron_options::{CronOptions, DstPolicy};
+
+/// The number of steps the default methods of [Specification] scan, four years of minutes including a leap day.
+const MAX_SCAN_STEPS: usize = 4 * 366 * 24 * 60;
 
 pub trait Specification<T>: Clone {
   fn is_satisfied_by(&self, arg: &T) -> bool;
+
+  /// Returns the first value at or after `from` that satisfies the specification, or `None` if there is none.<br/>
+  /// `from`以降で仕様を満たす最初の値を返す。存在しない場合は`None`を返す。
+  ///
+  /// By default, scans the values step by step with [Specification::is_satisfied_by], for up to four years.
+  fn next_satisfied_from(&self, from: &T) -> Option<T>
+  where
+    T: Steppable, {
+    let mut value = if from.is_step() {
+      from.clone()
+    } else {
+      from.next_step()?
+    };
+    for _ in 0..MAX_SCAN_STEPS {
+      if self.is_satisfied_by(&value) {
+        return Some(value);
+      }
+      value = value.next_step()?;
+    }
+    None
+  }
+
+  /// Returns the last value at or before `from` that satisfies the specification, or `None` if there is none.<br/>
+  /// `from`以前で仕様を満たす最後の値を返す。存在しない場合は`None`を返す。
+  ///
+  /// By default, scans the values step by step with [Specification::is_satisfied_by], for up to four years.
+  fn previous_satisfied_from(&self, from: &T) -> Option<T>
+  where
+    T: Steppable, {
+    let mut value = if from.is_step() {
+      from.clone()
+    } else {
+      from.previous_step()?
+    };
+    for _ in 0..MAX_SCAN_STEPS {
+      if self.is_satisfied_by(&value) {
+        return Some(value);
+      }
+      value = value.previous_step()?;
+    }
+    None
+  }
+}
+
+/// A value that a [Specification] can be scanned over, one step at a time.<br/>
+/// [Specification]を一段階ずつ走査できる値。
+pub trait Steppable: Clone {
+  /// Returns whether the value is a step itself.
+  fn is_step(&self) -> bool;
+
+  /// Returns the first step after the value.
+  fn next_step(&self) -> Option<Self>;
+
+  /// Returns the last step before the value.
+  fn previous_step(&self) -> Option<Self>;
+}
+
+/// Dates and times are scanned minute by minute, on whole minutes.
+impl<Tz: TimeZone> Steppable for DateTime<Tz> {
+  fn is_step(&self) -> bool {
+    self.second() == 0 && self.nanosecond() == 0
+  }
+
+  fn next_step(&self) -> Option<Self> {
+    let minute = self.with_second(0)?.with_nanosecond(0)?;
+    minute.checked_add_signed(Duration::minutes(1))
+  }
+
+  fn previous_step(&self) -> Option<Self> {
+    if self.is_step() {
+      self.clone().checked_sub_signed(Duration::minutes(1))
+    } else {
+      self.with_second(0)?.with_nanosecond(0)
+    }
+  }
 }
 
 #[derive(Clone)]
 pub struct CronSpecification {
   expr: CronExpr,
+  options: CronOptions,
+  fields: Option<CronFields>,
 }
 
 impl CronSpecification {
   pub fn new(expr: CronExpr) -> Self {
-    Self { expr }
+    Self::with_options(expr, CronOptions::default())
+  }
+
+  pub fn with_options(expr: CronExpr, options: CronOptions) -> Self {
+    let fields = CronFields::compile(&expr, options);
+    Self { expr, options, fields }
+  }
+
+  pub fn expr(&self) -> &CronExpr {
+    &self.expr
+  }
+
+  pub fn options(&self) -> CronOptions {
+    self.options
+  }
+
+  /// Returns whether the job follows the wall clock, i.e. fires again in a repeated hour and skips the skipped times.
+  fn follows_wall_clock(&self) -> bool {
+    match &self.expr {
+      CronExpr::CronExpr {
+        box mins, box hours, ..
+      } => self.options.dst_policy() == DstPolicy::Vixie && (starts_with_asterisk(mins) || starts_with_asterisk(hours)),
+      _ => false,
+    }
+  }
+
+  /// Returns whether a local time skipped by a transition fires at the transition.
+  fn fires_at_transition(&self) -> bool {
+    match self.options.dst_policy() {
+      DstPolicy::Vixie => !self.follows_wall_clock(),
+      DstPolicy::FireAtTransition => true,
+      DstPolicy::Skip => false,
+    }
+  }
+
+  /// Returns the dates and times the local time fires at, in order.
+  fn resolve<Tz: TimeZone>(&self, timezone: &Tz, local: &NaiveDateTime) -> Vec<DateTime<Tz>> {
+    match timezone.from_local_datetime(local) {
+      LocalResult::Single(date_time) => vec![date_time],
+      LocalResult::Ambiguous(earliest, latest) if self.follows_wall_clock() => vec![earliest, latest],
+      LocalResult::Ambiguous(earliest, _) => vec![earliest],
+      LocalResult::None if self.fires_at_transition() => transition(timezone, local).into_iter().collect(),
+      LocalResult::None => vec![],
+    }
+  }
+
+  fn next_at_local<Tz: TimeZone>(
+    &self,
+    fields: &CronFields,
+    from: &DateTime<Tz>,
+    mut local: NaiveDateTime,
+  ) -> Option<DateTime<Tz>> {
+    loop {
+      let next = fields.next_from(&local)?;
+      let resolved = self.resolve(&from.timezone(), &next);
+      if let Some(date_time) = resolved.into_iter().find(|date_time| date_time >= from) {
+        return Some(date_time);
+      }
+      local = next + Duration::seconds(1);
+    }
+  }
+
+  fn previous_at_local<Tz: TimeZone>(
+    &self,
+    fields: &CronFields,
+    from: &DateTime<Tz>,
+    mut local: NaiveDateTime,
+  ) -> Option<DateTime<Tz>> {
+    loop {
+      let previous = fields.prev_from(&local)?;
+      let resolved = self.resolve(&from.timezone(), &previous);
+      if let Some(date_time) = resolved.into_iter().rev().find(|date_time| date_time <= from) {
+        return Some(date_time);
+      }
+      local = previous - Duration::seconds(1);
+    }
   }
 }
 
 impl<Tz: TimeZone> Specification<DateTime<Tz>> for CronSpecification {
   fn is_satisfied_by(&self, datetime: &DateTime<Tz>) -> bool {
-    CronEvaluator::new(datetime).eval(&self.expr)
+    if let CronExpr::EveryExpr(interval) = self.expr {
+      return is_every(datetime, interval);
+    }
+    self
+      .fields
+      .as_ref()
+      .is_some_and(|fields| fields.matches(&datetime.naive_local()))
+  }
+
+  fn next_satisfied_from(&self, from: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+    if let CronExpr::EveryExpr(interval) = self.expr {
+      // A fixed rate does not depend on the calendar, so it is counted on the timestamps.
+      let interval = i64::try_from(interval).ok()?;
+      let seconds = from.timestamp() + i64::from(from.timestamp_subsec_nanos() > 0);
+      let next = seconds.checked_add((interval - seconds.rem_euclid(interval)) % interval)?;
+      return from.timezone().timestamp_opt(next, 0).single();
+    }
+    let fields = self.fields.as_ref()?;
+    let next = self.next_at_local(fields, from, from.naive_local());
+    // The wall clock goes back at the end of a repeated hour, so the local times before `from` come again.
+    match from.timezone().from_local_datetime(&from.naive_local()) {
+      LocalResult::Ambiguous(earliest, latest) if self.follows_wall_clock() && earliest == *from => {
+        let repeated = self.next_at_local(fields, from, from.naive_local() - (latest - earliest));
+        next.into_iter().chain(repeated).min()
+      }
+      _ => next,
+    }
+  }
+
+  fn previous_satisfied_from(&self, from: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+    if let CronExpr::EveryExpr(interval) = self.expr {
+      let interval = i64::try_from(interval).ok()?;
+      let seconds = from.timestamp();
+      return from
+        .timezone()
+        .timestamp_opt(seconds - seconds.rem_euclid(interval), 0)
+        .single();
+    }
+    let fields = self.fields.as_ref()?;
+    let previous = self.previous_at_local(fields, from, from.naive_local());
+    // The wall clock went back at the start of a repeated hour, so the local times after `from` came before.
+    match from.timezone().from_local_datetime(&from.naive_local()) {
+      LocalResult::Ambiguous(earliest, latest) if self.follows_wall_clock() && latest == *from => {
+        let repeated = self.previous_at_local(fields, from, from.naive_local() + (latest - earliest));
+        previous.into_iter().chain(repeated).max()
+      }
+      _ => previous,
+    }
+  }
+}
+
+/// Returns whether the field starts with `*`, as the `MIN_STAR` and `HR_STAR` flags of Vixie cron.
+fn starts_with_asterisk(expr: &CronExpr) -> bool {
+  matches!(
+    expr,
+    CronExpr::AnyValueExpr
+      | CronExpr::PerExpr {
+        digit: box CronExpr::AnyValueExpr,
+        ..
+      }
+  )
+}
+
+/// Returns the first date and time whose local time is at or after `local`, i.e. the end of the gap `local` is in.
+fn transition<Tz: TimeZone>(timezone: &Tz, local: &NaiveDateTime) -> Option<DateTime<Tz>> {
+  // The offsets are less than a day, so the transition is within a day of `local` taken as UTC.
+  const DAY: i64 = 24 * 60 * 60;
+  let (mut low, mut high) = (local.and_utc().timestamp() - DAY, local.and_utc().timestamp() + DAY);
+  while low < high {
+    let middle = low + (high - low) / 2;
+    match timezone.timestamp_opt(middle, 0) {
+      LocalResult::Single(date_time) if date_time.naive_local() < *local => low = middle + 1,
+      _ => high = middle,
+    }
   }
+  timezone.timestamp_opt(low, 0).single()
 }
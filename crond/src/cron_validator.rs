@@ -0,0 +1,224 @@
+use chrono::DateTime;
+
+use crate::cron_error::{CronError, CronField};
+use crate::cron_expr::CronExpr;
+use crate::cron_fields::CronFields;
+use crate::cron_options::CronOptions;
+
+/// Validates the values of a parsed expression, where `input` is the string the expression was parsed from.
+pub(crate) fn validate(input: &str, expr: &CronExpr, options: CronOptions) -> Result<(), CronError> {
+  let CronExpr::CronExpr {
+    secs,
+    box mins,
+    box hours,
+    box days,
+    box months,
+    box day_of_weeks,
+    years,
+  } = expr
+  else {
+    return Ok(());
+  };
+  let fields = [
+    (CronField::Second, secs.as_deref()),
+    (CronField::Minute, Some(mins)),
+    (CronField::Hour, Some(hours)),
+    (CronField::DayOfMonth, Some(days)),
+    (CronField::Month, Some(months)),
+    (CronField::DayOfWeek, Some(day_of_weeks)),
+    (CronField::Year, years.as_deref()),
+  ]
+  .into_iter()
+  .filter_map(|(field, expr)| expr.map(|expr| (field, expr)))
+  .zip(positions(input))
+  .collect::<Vec<_>>();
+  for (field, expr, position) in fields
+    .iter()
+    .map(|((field, expr), position)| (*field, *expr, *position))
+  {
+    validate_field(field, position, expr, options)?;
+  }
+  let never_fires = CronFields::compile(expr, options)
+    .is_some_and(|fields| fields.next_from(&DateTime::UNIX_EPOCH.naive_utc()).is_none());
+  match fields.iter().find(|((field, _), _)| *field == CronField::DayOfMonth) {
+    Some((_, position)) if never_fires => Err(CronError::ImpossibleDate {
+      field: CronField::DayOfMonth,
+      position: *position,
+    }),
+    _ => Ok(()),
+  }
+}
+
+/// Returns the offsets in characters of the fields, which are separated by a space.
+fn positions(input: &str) -> impl Iterator<Item = usize> + '_ {
+  input.split(' ').scan(0, |offset, field| {
+    let position = *offset;
+    *offset += field.chars().count() + 1;
+    Some(position)
+  })
+}
+
+/// Returns the smallest and the largest values of the field.
+fn bounds(field: CronField, options: CronOptions) -> (u16, u16) {
+  match field {
+    CronField::Second | CronField::Minute => (0, 59),
+    CronField::Hour => (0, 23),
+    CronField::DayOfMonth => (1, 31),
+    CronField::Month => (1, 12),
+    CronField::DayOfWeek => options.day_of_week_numbering().bounds(),
+    CronField::Year => (1970, 2099),
+  }
+}
+
+/// Returns whether a range whose start is after its end wraps around the end of the field, e.g. `22-2` or `FRI-MON`.
+fn wraps_around(field: CronField) -> bool {
+  matches!(field, CronField::Hour | CronField::Month | CronField::DayOfWeek)
+}
+
+fn validate_field(field: CronField, position: usize, expr: &CronExpr, options: CronOptions) -> Result<(), CronError> {
+  let (min, max) = bounds(field, options);
+  let in_range = |value: u16, min: u16, max: u16| {
+    if (min..=max).contains(&value) {
+      Ok(())
+    } else {
+      Err(CronError::OutOfRange {
+        field,
+        position,
+        value,
+        min,
+        max,
+      })
+    }
+  };
+  let step = |per: &CronExpr| match per {
+    CronExpr::ValueExpr(0) => Err(CronError::InvalidStep {
+      field,
+      position,
+      step: 0,
+    }),
+    _ => Ok(()),
+  };
+  match expr {
+    CronExpr::ValueExpr(value) => in_range(*value, min, max),
+    CronExpr::LastOffsetExpr(offset) => in_range(*offset, 0, max - min),
+    CronExpr::NearestWeekdayExpr(box target) => validate_field(field, position, target, options),
+    CronExpr::NthDayOfWeekExpr { day_of_week, .. } | CronExpr::LastDayOfWeekExpr(day_of_week) => {
+      in_range(*day_of_week, min, max)
+    }
+    CronExpr::PerExpr { box option, .. } => step(option),
+    CronExpr::RangeExpr {
+      from: box CronExpr::ValueExpr(from),
+      to: box CronExpr::ValueExpr(to),
+      box per_option,
+    } => {
+      in_range(*from, min, max)?;
+      in_range(*to, min, max)?;
+      if from > to && !wraps_around(field) {
+        return Err(CronError::ReversedRange {
+          field,
+          position,
+          from: *from,
+          to: *to,
+        });
+      }
+      step(per_option)
+    }
+    CronExpr::ListExpr(list) => list
+      .iter()
+      .try_for_each(|expr| validate_field(field, position, expr, options)),
+    _ => Ok(()),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::cron_options::CronDialect;
+  use crate::cron_parser::CronParser;
+
+  #[test]
+  fn test_out_of_range() {
+    let error = |input: &str| CronParser::parse(input).unwrap_err();
+    assert_eq!(
+      error("60 * * * *"),
+      CronError::OutOfRange {
+        field: CronField::Minute,
+        position: 0,
+        value: 60,
+        min: 0,
+        max: 59
+      }
+    );
+    assert_eq!(error("0 24 * * *").field(), Some(CronField::Hour));
+    assert_eq!(error("0 0 1,32 * *").field(), Some(CronField::DayOfMonth));
+    assert_eq!(error("0 0 * 13 *").position(), Some(6));
+    assert_eq!(error("0 0 * 0 *").position(), Some(6));
+    assert_eq!(error("0 0 * * 8").field(), Some(CronField::DayOfWeek));
+    assert_eq!(error("0 0 L-31 * *").field(), Some(CronField::DayOfMonth));
+    assert_eq!(error("0 0 32W * *").field(), Some(CronField::DayOfMonth));
+    assert_eq!(
+      CronParser::parse_with("0 0 0 * * 0", CronOptions::quartz()).unwrap_err(),
+      CronError::OutOfRange {
+        field: CronField::DayOfWeek,
+        position: 10,
+        value: 0,
+        min: 1,
+        max: 7
+      }
+    );
+    let error = CronParser::parse_with(
+      "0 0 * * ? 2100",
+      CronOptions::new().with_dialect(CronDialect::EventBridge),
+    )
+    .unwrap_err();
+    assert_eq!((error.field(), error.position()), (Some(CronField::Year), Some(10)));
+  }
+
+  #[test]
+  fn test_invalid_step() {
+    assert_eq!(
+      CronParser::parse("*/0 * * * *").unwrap_err(),
+      CronError::InvalidStep {
+        field: CronField::Minute,
+        position: 0,
+        step: 0
+      }
+    );
+    assert_eq!(
+      CronParser::parse("0 1-10/0 * * *").unwrap_err().field(),
+      Some(CronField::Hour)
+    );
+  }
+
+  #[test]
+  fn test_reversed_range() {
+    assert_eq!(
+      CronParser::parse("30-10 * * * *").unwrap_err(),
+      CronError::ReversedRange {
+        field: CronField::Minute,
+        position: 0,
+        from: 30,
+        to: 10
+      }
+    );
+    assert_eq!(CronParser::parse("0 0 20-10 * *").unwrap_err().position(), Some(4));
+    assert!(CronParser::parse("0 22-2 * NOV-FEB FRI-MON").is_ok());
+  }
+
+  #[test]
+  fn test_impossible_date() {
+    assert_eq!(
+      CronParser::parse("0 0 30 2 *").unwrap_err(),
+      CronError::ImpossibleDate {
+        field: CronField::DayOfMonth,
+        position: 4
+      }
+    );
+    assert!(CronParser::parse("0 0 31 4,6,9,11 *").is_err());
+    assert!(CronParser::parse("0 0 29 2 *").is_ok());
+    assert!(CronParser::parse("0 0 31 4,5 *").is_ok());
+    // Vixie cron fires on the Mondays in February.
+    assert!(CronParser::parse("0 0 30 2 MON").is_ok());
+    assert!(CronParser::parse_with("0 0 0 30 2 2", CronOptions::quartz()).is_err());
+  }
+}
@@ -2,23 +2,33 @@
 #![feature(box_patterns)]
 use chrono::NaiveDate;
 
+pub use cron_description::*;
 pub use cron_environment::*;
+pub use cron_error::*;
 pub use cron_evaluator::*;
 pub use cron_expr::*;
 pub use cron_interval::*;
 pub use cron_interval_iterator::*;
+pub use cron_interval_reverse_iterator::*;
+pub use cron_options::*;
 pub use cron_parser::*;
 pub use cron_schedule::*;
 pub use cron_specification::*;
 
+mod cron_description;
 mod cron_environment;
+mod cron_error;
 mod cron_evaluator;
 mod cron_expr;
+mod cron_fields;
 mod cron_interval;
 mod cron_interval_iterator;
+mod cron_interval_reverse_iterator;
+mod cron_options;
 mod cron_parser;
 mod cron_schedule;
 mod cron_specification;
+mod cron_validator;
 
 fn get_days_from_month(year: i32, month: u32) -> i64 {
   NaiveDate::from_ymd(
@@ -0,0 +1,215 @@
+//! Conformance cases of the day-of-month and day-of-week matching, following the rules of Vixie cron: a field that
+//! starts with `*` is unrestricted (`DOM_STAR`/`DOW_STAR` in `entry.c`), and a day matches either field if both are
+//! restricted (`cron.c`). The cases and their expected dates are written by hand from those rules; they are not
+//! ported from the test suites of cronie or Vixie cron.
+use chrono::{TimeZone, Utc};
+use oni_comb_crond_rs::{CronDialect, CronOptions, CronSchedule, DayMatching, DayOfWeekNumbering};
+
+fn upcoming(expr: &str, options: CronOptions, n: usize) -> Vec<String> {
+  let start = Utc.with_ymd_and_hms(2021, 1, 1, 0, 1, 0).unwrap();
+  CronSchedule::<Utc>::with_options(expr, options)
+    .unwrap()
+    .upcoming(start)
+    .take(n)
+    .map(|date_time| date_time.format("%Y-%m-%d %H:%M %a").to_string())
+    .collect()
+}
+
+fn assert_upcoming(expr: &str, options: CronOptions, expected: &[&str]) {
+  assert_eq!(upcoming(expr, options, expected.len()), expected, "{}", expr);
+}
+
+#[test]
+fn test_day_of_week_is_the_weekday() {
+  let options = CronOptions::default();
+  assert_upcoming(
+    "30 8 * * MON-FRI",
+    options,
+    &[
+      "2021-01-01 08:30 Fri",
+      "2021-01-04 08:30 Mon",
+      "2021-01-05 08:30 Tue",
+      "2021-01-06 08:30 Wed",
+      "2021-01-07 08:30 Thu",
+      "2021-01-08 08:30 Fri",
+    ],
+  );
+  assert_upcoming(
+    "0 12 * * SUN",
+    options,
+    &["2021-01-03 12:00 Sun", "2021-01-10 12:00 Sun"],
+  );
+}
+
+#[test]
+fn test_sunday_is_zero_or_seven() {
+  let options = CronOptions::default();
+  for expr in ["0 0 * * 0", "0 0 * * 7"] {
+    assert_upcoming(expr, options, &["2021-01-03 00:00 Sun", "2021-01-10 00:00 Sun"]);
+  }
+  assert_upcoming(
+    "0 0 * * 5-7",
+    options,
+    &[
+      "2021-01-02 00:00 Sat",
+      "2021-01-03 00:00 Sun",
+      "2021-01-08 00:00 Fri",
+      "2021-01-09 00:00 Sat",
+    ],
+  );
+  assert_upcoming(
+    "0 0 * * */2",
+    options,
+    &[
+      "2021-01-02 00:00 Sat",
+      "2021-01-03 00:00 Sun",
+      "2021-01-05 00:00 Tue",
+      "2021-01-07 00:00 Thu",
+      "2021-01-09 00:00 Sat",
+    ],
+  );
+}
+
+#[test]
+fn test_sunday_is_one() {
+  let options = CronOptions::new().with_day_of_week_numbering(DayOfWeekNumbering::SundayOne);
+  for expr in ["0 0 * * 1", "0 0 * * SUN"] {
+    assert_upcoming(expr, options, &["2021-01-03 00:00 Sun", "2021-01-10 00:00 Sun"]);
+  }
+  assert_upcoming("0 0 * * 7", options, &["2021-01-02 00:00 Sat", "2021-01-09 00:00 Sat"]);
+  assert!(CronSchedule::<Utc>::with_options("0 0 * * 0", options).is_err());
+}
+
+#[test]
+fn test_steps_start_at_the_first_day() {
+  assert_upcoming(
+    "0 0 */10 * *",
+    CronOptions::default(),
+    &[
+      "2021-01-11 00:00 Mon",
+      "2021-01-21 00:00 Thu",
+      "2021-01-31 00:00 Sun",
+      "2021-02-01 00:00 Mon",
+      "2021-02-11 00:00 Thu",
+    ],
+  );
+}
+
+#[test]
+fn test_vixie_days_match_either_field_if_both_are_restricted() {
+  let options = CronOptions::default();
+  assert_upcoming(
+    "0 0 1,15 * 3",
+    options,
+    &[
+      "2021-01-06 00:00 Wed",
+      "2021-01-13 00:00 Wed",
+      "2021-01-15 00:00 Fri",
+      "2021-01-20 00:00 Wed",
+      "2021-01-27 00:00 Wed",
+      "2021-02-01 00:00 Mon",
+    ],
+  );
+  assert_upcoming(
+    "0 0 13 * FRI",
+    options,
+    &[
+      "2021-01-08 00:00 Fri",
+      "2021-01-13 00:00 Wed",
+      "2021-01-15 00:00 Fri",
+      "2021-01-22 00:00 Fri",
+      "2021-01-29 00:00 Fri",
+    ],
+  );
+}
+
+#[test]
+fn test_vixie_step_of_asterisk_is_unrestricted() {
+  assert_upcoming(
+    "0 0 */2 * 1",
+    CronOptions::default(),
+    &[
+      "2021-01-11 00:00 Mon",
+      "2021-01-25 00:00 Mon",
+      "2021-02-01 00:00 Mon",
+      "2021-02-15 00:00 Mon",
+    ],
+  );
+}
+
+#[test]
+fn test_posix_step_of_asterisk_is_restricted() {
+  assert_upcoming(
+    "0 0 */2 * 1",
+    CronOptions::new().with_day_matching(DayMatching::Posix),
+    &[
+      "2021-01-03 00:00 Sun",
+      "2021-01-04 00:00 Mon",
+      "2021-01-05 00:00 Tue",
+      "2021-01-07 00:00 Thu",
+    ],
+  );
+}
+
+#[test]
+fn test_intersection_days_match_both_fields() {
+  assert_upcoming(
+    "0 0 13 * FRI",
+    CronOptions::new().with_day_matching(DayMatching::Intersection),
+    &["2021-08-13 00:00 Fri", "2022-05-13 00:00 Fri"],
+  );
+  assert_upcoming(
+    "0 0 0 13 * 6",
+    CronOptions::quartz(),
+    &["2021-08-13 00:00 Fri", "2022-05-13 00:00 Fri"],
+  );
+}
+
+#[test]
+fn test_unrestricted_day_of_week_does_not_widen_the_days() {
+  assert_upcoming(
+    "0 0 1 * *",
+    CronOptions::default(),
+    &["2021-02-01 00:00 Mon", "2021-03-01 00:00 Mon", "2021-04-01 00:00 Thu"],
+  );
+}
+
+#[test]
+fn test_ranges_wrap_around() {
+  let options = CronOptions::default();
+  assert_upcoming(
+    "0 0 * * fri-mon",
+    options,
+    &[
+      "2021-01-02 00:00 Sat",
+      "2021-01-03 00:00 Sun",
+      "2021-01-04 00:00 Mon",
+      "2021-01-08 00:00 Fri",
+    ],
+  );
+  assert_upcoming(
+    "0 23-1 * * *",
+    options,
+    &[
+      "2021-01-01 01:00 Fri",
+      "2021-01-01 23:00 Fri",
+      "2021-01-02 00:00 Sat",
+      "2021-01-02 01:00 Sat",
+    ],
+  );
+  assert_upcoming(
+    "0 0 1 Nov-Feb *",
+    options,
+    &[
+      "2021-02-01 00:00 Mon",
+      "2021-11-01 00:00 Mon",
+      "2021-12-01 00:00 Wed",
+      "2022-01-01 00:00 Sat",
+    ],
+  );
+  assert_upcoming(
+    "0 0 * * FRI-MON",
+    CronOptions::quartz().with_dialect(CronDialect::Unix),
+    &["2021-01-02 00:00 Sat", "2021-01-03 00:00 Sun", "2021-01-04 00:00 Mon"],
+  );
+}
@@ -0,0 +1,172 @@
+//! Cases of the daylight saving time transitions, following the documented behavior of Vixie cron (`cron(8)`): a job
+//! at a fixed time fires soon after the change if its time is skipped, and is not re-run if its time is repeated,
+//! while a job with `*` in the minute or hour field runs based on the new time immediately.
+//!
+//! The IANA time zones are tested with `chrono-tz`. In America/New_York, 02:00 EST jumps to 03:00 EDT on
+//! 2021-03-14, and 02:00 EDT goes back to 01:00 EST on 2021-11-07.
+use chrono::{DateTime, FixedOffset, TimeZone};
+use oni_comb_crond_rs::{CronOptions, CronSchedule, DstPolicy};
+
+fn upcoming<Tz: TimeZone>(expr: &str, options: CronOptions, start: DateTime<Tz>, n: usize) -> Vec<String>
+where
+  Tz::Offset: std::fmt::Display, {
+  CronSchedule::<Tz>::with_options(expr, options)
+    .unwrap()
+    .upcoming(start)
+    .take(n)
+    .map(|date_time| date_time.format("%Y-%m-%d %H:%M %z").to_string())
+    .collect()
+}
+
+fn preceding<Tz: TimeZone>(expr: &str, options: CronOptions, end: DateTime<Tz>, n: usize) -> Vec<String>
+where
+  Tz::Offset: std::fmt::Display, {
+  let mut date_times = CronSchedule::<Tz>::with_options(expr, options)
+    .unwrap()
+    .preceding(end)
+    .take(n)
+    .map(|date_time| date_time.format("%Y-%m-%d %H:%M %z").to_string())
+    .collect::<Vec<_>>();
+  date_times.reverse();
+  date_times
+}
+
+#[test]
+fn test_fixed_offset_has_no_transitions() {
+  let tokyo = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+  let start = tokyo.with_ymd_and_hms(2021, 3, 13, 0, 0, 0).unwrap();
+  for policy in [DstPolicy::Vixie, DstPolicy::FireAtTransition, DstPolicy::Skip] {
+    let options = CronOptions::new().with_dst_policy(policy);
+    assert_eq!(
+      upcoming("30 2 * * *", options, start, 3),
+      [
+        "2021-03-13 02:30 +0900",
+        "2021-03-14 02:30 +0900",
+        "2021-03-15 02:30 +0900"
+      ]
+    );
+    let end = tokyo.with_ymd_and_hms(2021, 3, 15, 2, 30, 0).unwrap();
+    assert_eq!(
+      preceding("30 2 * * *", options, end, 3),
+      upcoming("30 2 * * *", options, start, 3)
+    );
+  }
+  let new_york = FixedOffset::west_opt(5 * 60 * 60).unwrap();
+  let start = new_york.with_ymd_and_hms(2021, 11, 7, 0, 30, 0).unwrap();
+  assert_eq!(
+    upcoming("*/30 * * * *", CronOptions::default(), start, 4),
+    [
+      "2021-11-07 00:30 -0500",
+      "2021-11-07 01:00 -0500",
+      "2021-11-07 01:30 -0500",
+      "2021-11-07 02:00 -0500"
+    ]
+  );
+}
+
+mod iana {
+  use super::*;
+  use chrono_tz::America::New_York;
+
+  #[test]
+  fn test_fixed_time_in_gap_fires_at_transition() {
+    let start = New_York.with_ymd_and_hms(2021, 3, 13, 0, 0, 0).unwrap();
+    let expected = [
+      "2021-03-13 02:30 -0500",
+      "2021-03-14 03:00 -0400",
+      "2021-03-15 02:30 -0400",
+    ];
+    for options in [
+      CronOptions::default(),
+      CronOptions::new().with_dst_policy(DstPolicy::FireAtTransition),
+    ] {
+      assert_eq!(upcoming("30 2 * * *", options, start, 3), expected);
+      let end = New_York.with_ymd_and_hms(2021, 3, 15, 2, 30, 0).unwrap();
+      assert_eq!(preceding("30 2 * * *", options, end, 3), expected);
+    }
+  }
+
+  #[test]
+  fn test_fixed_time_in_gap_is_skipped() {
+    let start = New_York.with_ymd_and_hms(2021, 3, 13, 0, 0, 0).unwrap();
+    let options = CronOptions::new().with_dst_policy(DstPolicy::Skip);
+    assert_eq!(
+      upcoming("30 2 * * *", options, start, 2),
+      ["2021-03-13 02:30 -0500", "2021-03-15 02:30 -0400"]
+    );
+  }
+
+  #[test]
+  fn test_wildcard_in_gap_follows_wall_clock() {
+    let start = New_York.with_ymd_and_hms(2021, 3, 14, 1, 0, 0).unwrap();
+    assert_eq!(
+      upcoming("15 * * * *", CronOptions::default(), start, 3),
+      [
+        "2021-03-14 01:15 -0500",
+        "2021-03-14 03:15 -0400",
+        "2021-03-14 04:15 -0400"
+      ]
+    );
+    assert_eq!(
+      upcoming(
+        "15 * * * *",
+        CronOptions::new().with_dst_policy(DstPolicy::FireAtTransition),
+        start,
+        3
+      ),
+      [
+        "2021-03-14 01:15 -0500",
+        "2021-03-14 03:00 -0400",
+        "2021-03-14 03:15 -0400"
+      ]
+    );
+  }
+
+  #[test]
+  fn test_fixed_time_in_overlap_fires_once() {
+    let start = New_York.with_ymd_and_hms(2021, 11, 6, 0, 0, 0).unwrap();
+    let expected = [
+      "2021-11-06 01:30 -0400",
+      "2021-11-07 01:30 -0400",
+      "2021-11-08 01:30 -0500",
+    ];
+    assert_eq!(upcoming("30 1 * * *", CronOptions::default(), start, 3), expected);
+    let end = New_York.with_ymd_and_hms(2021, 11, 8, 1, 30, 0).unwrap();
+    assert_eq!(preceding("30 1 * * *", CronOptions::default(), end, 3), expected);
+
+    // Starting in the repeated hour after the fire time does not fire it again.
+    let start = New_York.with_ymd_and_hms(2021, 11, 7, 1, 10, 0).latest().unwrap();
+    assert_eq!(
+      upcoming("30 1 * * *", CronOptions::default(), start, 1),
+      ["2021-11-08 01:30 -0500"]
+    );
+  }
+
+  #[test]
+  fn test_wildcard_in_overlap_follows_wall_clock() {
+    let start = New_York.with_ymd_and_hms(2021, 11, 7, 0, 30, 0).unwrap();
+    let expected = [
+      "2021-11-07 00:30 -0400",
+      "2021-11-07 01:00 -0400",
+      "2021-11-07 01:30 -0400",
+      "2021-11-07 01:00 -0500",
+      "2021-11-07 01:30 -0500",
+      "2021-11-07 02:00 -0500",
+    ];
+    assert_eq!(upcoming("*/30 * * * *", CronOptions::default(), start, 6), expected);
+    let end = New_York.with_ymd_and_hms(2021, 11, 7, 2, 0, 0).unwrap();
+    assert_eq!(preceding("*/30 * * * *", CronOptions::default(), end, 6), expected);
+
+    for policy in [DstPolicy::FireAtTransition, DstPolicy::Skip] {
+      assert_eq!(
+        upcoming("*/30 * * * *", CronOptions::new().with_dst_policy(policy), start, 4),
+        [
+          "2021-11-07 00:30 -0400",
+          "2021-11-07 01:00 -0400",
+          "2021-11-07 01:30 -0400",
+          "2021-11-07 02:00 -0500"
+        ]
+      );
+    }
+  }
+}
@@ -0,0 +1,113 @@
+//! The special characters of the day fields of Quartz: `?`, `L`, `L-n`, `nW`, `LW`, `d#n` and `dL`.
+use chrono::{TimeZone, Utc};
+use oni_comb_crond_rs::{CronOptions, CronSchedule};
+
+fn assert_upcoming(expr: &str, options: CronOptions, expected: &[&str]) {
+  let start = Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap();
+  let dates = CronSchedule::<Utc>::with_options(expr, options)
+    .unwrap()
+    .upcoming(start)
+    .take(expected.len())
+    .map(|date_time| date_time.format("%Y-%m-%d").to_string())
+    .collect::<Vec<_>>();
+  assert_eq!(dates, expected, "{}", expr);
+}
+
+#[test]
+fn test_last_day_of_month() {
+  assert_upcoming(
+    "0 0 0 L * ?",
+    CronOptions::quartz(),
+    &["2021-01-31", "2021-02-28", "2021-03-31", "2021-04-30"],
+  );
+  assert_upcoming(
+    "0 0 0 L-3 * ?",
+    CronOptions::quartz(),
+    &["2021-01-28", "2021-02-25", "2021-03-28", "2021-04-27"],
+  );
+}
+
+#[test]
+fn test_last_business_day_of_month() {
+  assert_upcoming(
+    "0 0 18 LW * ?",
+    CronOptions::quartz(),
+    &[
+      "2021-01-29",
+      "2021-02-26",
+      "2021-03-31",
+      "2021-04-30",
+      "2021-05-31",
+      "2021-06-30",
+      "2021-07-30",
+    ],
+  );
+}
+
+#[test]
+fn test_nearest_weekday() {
+  assert_upcoming(
+    "0 0 0 15W * ?",
+    CronOptions::quartz(),
+    &[
+      "2021-01-15",
+      "2021-02-15",
+      "2021-03-15",
+      "2021-04-15",
+      "2021-05-14",
+      "2021-06-15",
+      "2021-07-15",
+      "2021-08-16",
+    ],
+  );
+  // The nearest weekday does not leave the month.
+  assert_upcoming(
+    "0 0 0 1W * ?",
+    CronOptions::quartz(),
+    &["2021-01-01", "2021-02-01", "2021-03-01", "2021-04-01", "2021-05-03"],
+  );
+  assert_upcoming(
+    "0 0 0 31W * ?",
+    CronOptions::quartz(),
+    &["2021-01-29", "2021-03-31", "2021-05-31", "2021-07-30"],
+  );
+}
+
+#[test]
+fn test_nth_day_of_week() {
+  assert_upcoming(
+    "0 0 0 ? * 5#3",
+    CronOptions::quartz(),
+    &["2021-01-21", "2021-02-18", "2021-03-18"],
+  );
+  assert_upcoming(
+    "0 0 * * THU#3",
+    CronOptions::default(),
+    &["2021-01-21", "2021-02-18", "2021-03-18"],
+  );
+}
+
+#[test]
+fn test_last_day_of_week() {
+  assert_upcoming(
+    "0 0 0 ? * 6L",
+    CronOptions::quartz(),
+    &["2021-01-29", "2021-02-26", "2021-03-26", "2021-04-30"],
+  );
+  assert_upcoming(
+    "0 0 * * 5L",
+    CronOptions::default(),
+    &["2021-01-29", "2021-02-26", "2021-03-26", "2021-04-30"],
+  );
+}
+
+#[test]
+fn test_no_specific_value_is_unrestricted() {
+  // `?` does not restrict the days, so the day of the month alone decides with Vixie matching.
+  assert_upcoming("0 0 15 * ?", CronOptions::default(), &["2021-01-15", "2021-02-15"]);
+  assert_upcoming(
+    "0 0 ? * MON",
+    CronOptions::default(),
+    &["2021-01-04", "2021-01-11", "2021-01-18"],
+  );
+}
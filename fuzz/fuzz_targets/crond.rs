@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use oni_comb_crond_rs::CronParser;
+
+fuzz_target!(|data: &[u8]| {
+  if let Ok(text) = std::str::from_utf8(data) {
+    let _ = CronParser::parse(text);
+  }
+});
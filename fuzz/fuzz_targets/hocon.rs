@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use oni_comb_hocon_rs::hocon;
+use oni_comb_parser_rs::prelude::*;
+
+fuzz_target!(|data: &[u8]| {
+  if let Err(error) = hocon().parse(data).to_result() {
+    let _ = error.input_string();
+  }
+});
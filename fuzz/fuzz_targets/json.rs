@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use oni_comb_parser_rs::prelude::*;
+
+#[path = "../../parser/examples/json_byte.rs"]
+mod json_byte;
+
+fuzz_target!(|data: &[u8]| {
+  let _ = json_byte::json().parse(data);
+});
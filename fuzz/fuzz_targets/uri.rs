@@ -0,0 +1,12 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use oni_comb_uri_rs::models::uri::Uri;
+
+fuzz_target!(|data: &[u8]| {
+  if let Ok(text) = std::str::from_utf8(data) {
+    if let Err(error) = Uri::parse(text) {
+      let _ = error.input_string();
+    }
+  }
+});
@@ -4,3 +4,4 @@ extern crate core;
 extern crate serial_test;
 pub mod model;
 mod parsers;
+pub use parsers::{hocon, hocon_cst, hocon_cst_with_limits, HoconKind};
@@ -1,8 +1,9 @@
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::fs::File;
 use std::io::Read;
 
-use oni_comb_parser_rs::prelude::ParserRunner;
+use oni_comb_parser_rs::prelude::{ParseLimits, ParserRunner};
 
 use crate::parsers::hocon;
 
@@ -59,15 +60,23 @@ impl FileReader for DefaultFileReader {
 
 pub struct ConfigFactory {
   file_reader: Box<dyn FileReader>,
+  parse_limits: ParseLimits,
 }
 
 impl ConfigFactory {
   pub fn new() -> Self {
     Self {
       file_reader: Box::new(DefaultFileReader),
+      parse_limits: ParseLimits::new(),
     }
   }
 
+  /// Restricts the nesting depth and the work done when parsing untrusted configurations.
+  pub fn with_parse_limits(mut self, parse_limits: ParseLimits) -> Self {
+    self.parse_limits = parse_limits;
+    self
+  }
+
   pub fn load_from_file(&mut self, filename: &str) -> Result<Config, ConfigError> {
     let mut text = String::new();
     let _ = self.file_reader.read_to_string(filename, &mut text);
@@ -76,7 +85,7 @@ impl ConfigFactory {
 
   pub fn parse_from_string(&self, text: &str) -> Result<Config, ConfigError> {
     hocon()
-      .parse(text.as_bytes())
+      .parse_with_limits(text.as_bytes(), self.parse_limits)
       .to_result()
       .map(|config_values| Self::resolve_stage0(&config_values))
       .map(|config_value| Self::resolve_stage1(&config_value))
@@ -95,9 +104,13 @@ impl ConfigFactory {
   }
 
   fn resolve_stage0(config_values: &Vec<ConfigValue>) -> ConfigValue {
-    let mut cur = config_values[0].clone();
+    let (head, tail) = match config_values.split_first() {
+      Some(values) => values,
+      None => return ConfigValue::Object(ConfigObjectValue::new(HashMap::new())),
+    };
+    let mut cur = head.clone();
     cur.resolve(None);
-    for cv in &config_values[1..] {
+    for cv in tail {
       let mut t = cv.clone();
       t.resolve(None);
       cur.merge_with(t);
@@ -207,6 +220,116 @@ mod tests {
     assert_eq!(x_value, "a");
   }
 
+  #[test]
+  fn trailing_comma() {
+    let input = r#"
+        a = [1, 2, 3,]
+        b { c = 1, d = 2, }
+        "#;
+    let config = ConfigFactory::new().parse_from_string(input).unwrap();
+
+    let a_value = config.get_value("a").unwrap();
+    let array = a_value
+      .as_array()
+      .unwrap()
+      .iter()
+      .map(|e| e.as_number().unwrap().to_u32().unwrap())
+      .collect::<Vec<_>>();
+    assert_eq!(array, vec![1, 2, 3]);
+
+    let d_value = config.get_value("b.d").unwrap().as_number().unwrap().to_u32().unwrap();
+    assert_eq!(d_value, 2);
+  }
+
+  #[test]
+  fn numbers() {
+    let input = r#"
+        a = 1e3
+        b = -2.5
+        c = 1.5E-2
+        "#;
+    let config = ConfigFactory::new().parse_from_string(input).unwrap();
+    assert_eq!(config.get_value("a").unwrap().as_number().unwrap().to_string(), "1000");
+    assert_eq!(config.get_value("b").unwrap().as_number().unwrap().to_string(), "-2.5");
+    assert_eq!(config.get_value("c").unwrap().as_number().unwrap().to_string(), "0.015");
+
+    let input = "a = 1e99";
+    let config = ConfigFactory::new().parse_from_string(input).unwrap();
+    assert_eq!(config.get_value("a").unwrap().as_number(), None);
+  }
+
+  #[test]
+  fn parse_limits() {
+    let factory = ConfigFactory::new().with_parse_limits(ParseLimits::new().with_max_depth(32));
+    assert!(factory.parse_from_string("a = [[[1]]]").is_ok());
+
+    let input = format!("a = {}1{}", "[".repeat(10_000), "]".repeat(10_000));
+    let error = factory.parse_from_string(&input).err().unwrap();
+    match error {
+      ConfigError::ParseError(msg) => assert!(msg.starts_with("Limit of 32 depth exceeded"), "{}", msg),
+      _ => panic!("unexpected error: {:?}", error),
+    }
+  }
+
+  #[test]
+  fn duration_units() {
+    let input = r#"
+        a = 10 seconds
+        b = 5 minutes
+        c = 3 ms
+        d = 2 days
+        "#;
+    let config = ConfigFactory::new().parse_from_string(input).unwrap();
+    let millis = |path: &str| {
+      config
+        .get_value(path)
+        .unwrap()
+        .as_config_duration_value()
+        .unwrap()
+        .to_duration()
+        .unwrap()
+        .num_milliseconds()
+    };
+    assert_eq!(millis("a"), 10_000);
+    assert_eq!(millis("b"), 300_000);
+    assert_eq!(millis("c"), 3);
+    assert_eq!(millis("d"), 172_800_000);
+  }
+
+  #[test]
+  fn duration_unit_names() {
+    let units = [
+      ("ns nano nanos nanosecond nanoseconds", 1),
+      ("us micro micros microsecond microseconds", 1_000),
+      ("ms milli millis millisecond milliseconds", 1_000_000),
+      ("s second seconds", 1_000_000_000),
+      ("m minute minutes", 60_000_000_000),
+      ("h hour hours", 3_600_000_000_000),
+      ("d day days", 86_400_000_000_000),
+    ];
+    for (names, nanos) in units {
+      for name in names.split(' ') {
+        let config = ConfigFactory::new()
+          .parse_from_string(&format!("a = 2 {}", name))
+          .unwrap();
+        let duration = config
+          .get_value("a")
+          .unwrap()
+          .as_config_duration_value()
+          .unwrap()
+          .to_duration()
+          .unwrap();
+        assert_eq!(duration.num_nanoseconds(), Some(2 * nanos), "{}", name);
+      }
+    }
+  }
+
+  #[test]
+  fn empty_input() {
+    let config = ConfigFactory::new().parse_from_string("").unwrap();
+    assert_eq!(config.get_value("a"), None);
+  }
+
   #[test]
   fn test_eval_reference() {
     let input = r#"
@@ -3,15 +3,35 @@ use std::char::{decode_utf16, REPLACEMENT_CHARACTER};
 use std::collections::HashMap;
 
 use oni_comb_parser_rs::prelude::*;
+use rust_decimal::Decimal;
+
+/// The kinds of the nodes and tokens of the concrete syntax tree built by [hocon_cst].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HoconKind {
+  Document,
+  Property,
+  Key,
+  Value,
+  Object,
+  Array,
+  Whitespace,
+  Comment,
+}
+
+impl From<HoconKind> for SyntaxKind {
+  fn from(kind: HoconKind) -> Self {
+    SyntaxKind(kind as u16)
+  }
+}
 
 fn comment<'a>() -> Parser<'a, u8, &'a [u8]> {
   let head = seq(b"//").collect().attempt() | elm_ref(b'#').collect();
   let tail = take_till0(|c| matches!(*c, b'\r' | b'\n'));
-  (space() + (head + tail).of_many1()).collect()
+  (space() + token(HoconKind::Comment.into(), head + tail).of_many1()).collect()
 }
 
 fn space<'a>() -> Parser<'a, u8, &'a [u8]> {
-  elm_ref_of(b" \t\r\n").of_many0().collect()
+  token(HoconKind::Whitespace.into(), elm_ref_of(b" \t\r\n").of_skip_many0()).collect()
 }
 
 fn space_or_comment<'a>() -> Parser<'a, u8, ()> {
@@ -44,30 +64,30 @@ fn number<'a>() -> Parser<'a, u8, (Option<&'a u8>, String, Option<String>, Optio
 }
 
 fn duration<'a>() -> Parser<'a, u8, (ConfigNumberValue, TimeUnit)> {
-  let ns = (seq(b"ns").attempt()
-    | seq(b"nano").attempt()
-    | seq(b"nanos").attempt()
-    | seq(b"nanosecond").attempt()
-    | seq(b"nanoseconds"))
-  .map(|_| TimeUnit::Nanoseconds);
-  let us = (seq(b"us").attempt()
-    | seq(b"micro").attempt()
-    | seq(b"micros").attempt()
-    | seq(b"microsecond").attempt()
-    | seq(b"microseconds"))
-  .map(|_| TimeUnit::Microseconds);
-  let ms = (seq(b"ms").attempt()
-    | seq(b"milli").attempt()
-    | seq(b"millis").attempt()
-    | seq(b"millisecond").attempt()
-    | seq(b"milliseconds"))
-  .map(|_| TimeUnit::Milliseconds);
-  let s = (seq(b"s").attempt() | seq(b"second").attempt() | seq(b"seconds")).map(|_| TimeUnit::Seconds);
-  let m = (seq(b"m").attempt() | seq(b"minute").attempt() | seq(b"minutes")).map(|_| TimeUnit::Minutes);
-  let h = (seq(b"h").attempt() | seq(b"hour").attempt() | seq(b"hours")).map(|_| TimeUnit::Hours);
-  let d = (seq(b"d").attempt() | seq(b"day").attempt() | seq(b"days")).map(|_| TimeUnit::Days);
-
-  number_value() + (ns.attempt() | us.attempt() | ms.attempt() | s.attempt() | m.attempt() | h.attempt() | d)
+  // The unit names are told apart by their first letters, so no alternative has to backtrack over consumed input.
+  // Once a prefix such as `n` or `mi` is recognized, the rest of the name is required with `cut()`.
+  let plural = || elm(b's').opt();
+  // `nano`, `micro` and `milli` stand alone or are followed by `s`, `second` or `seconds`.
+  let seconds = || (elm(b's') + (seq(b"econd") + plural()).opt()).opt();
+  let ns = elm(b'n') + (elm(b's').map(|_| ()) | (seq(b"ano") + seconds()).map(|_| ())).cut();
+  let us = elm(b'u') + elm(b's').cut();
+  let mi = elm(b'i')
+    * ((seq(b"cro") + seconds()).map(|_| TimeUnit::Microseconds)
+      | (seq(b"lli") + seconds()).map(|_| TimeUnit::Milliseconds)
+      | (seq(b"nute") + plural()).map(|_| TimeUnit::Minutes))
+    .cut();
+  let m = elm(b'm') * (elm(b's').map(|_| TimeUnit::Milliseconds) | mi | successful(TimeUnit::Minutes));
+  let s = elm(b's') + (seq(b"econd") + plural()).opt();
+  let h = elm(b'h') + (seq(b"our") + plural()).opt();
+  let d = elm(b'd') + (seq(b"ay") + plural()).opt();
+
+  number_value()
+    + (ns.map(|_| TimeUnit::Nanoseconds)
+      | us.map(|_| TimeUnit::Microseconds)
+      | m
+      | s.map(|_| TimeUnit::Seconds)
+      | h.map(|_| TimeUnit::Hours)
+      | d.map(|_| TimeUnit::Days))
 }
 
 fn duration_value<'a>() -> Parser<'a, u8, ConfigValue> {
@@ -75,21 +95,23 @@ fn duration_value<'a>() -> Parser<'a, u8, ConfigValue> {
 }
 
 fn number_value<'a>() -> Parser<'a, u8, ConfigNumberValue> {
-  number().map(|(s, i, f, e)| match (s, i, f, e) {
-    (None, i, None, None) => ConfigNumberValue::from(i),
-    (Some(_), i, None, None) => ConfigNumberValue::from(i),
-    (_, i, Some(f), None) => {
-      let mut s = i;
-      s.push_str(&f);
-      ConfigNumberValue::from(s)
+  number().map_res(|(s, i, f, e)| {
+    let mut text = String::new();
+    if s.is_some() {
+      text.push('-');
+    }
+    text.push_str(&i);
+    if let Some(f) = f {
+      text.push_str(&f);
     }
-    (_, i, Some(f), Some(e)) => {
-      let mut s = i;
-      s.push_str(&f);
-      s.push_str(&e);
-      ConfigNumberValue::from(s)
+    match e {
+      Some(e) => {
+        text.push_str(&e);
+        Decimal::from_scientific(&text)
+      }
+      None => Decimal::from_str_exact(&text),
     }
-    _ => panic!("no match !!!"),
+    .map(ConfigNumberValue::from)
   })
 }
 
@@ -176,8 +198,11 @@ fn array_right_bracket<'a>() -> Parser<'a, u8, &'a u8> {
 }
 
 fn array<'a>() -> Parser<'a, u8, Vec<ConfigValue>> {
-  let elems = lazy(config_value).of_many0_sep(comma());
-  elems.surround(array_left_bracket(), array_right_bracket())
+  let elems = lazy(config_value).of_many0_sep_trailing(comma());
+  node(
+    HoconKind::Array.into(),
+    elems.surround(array_left_bracket(), array_right_bracket()),
+  )
 }
 
 fn kv<'a>() -> Parser<'a, u8, ()> {
@@ -185,11 +210,17 @@ fn kv<'a>() -> Parser<'a, u8, ()> {
 }
 
 pub fn key<'a>() -> Parser<'a, u8, String> {
-  (path().attempt() | string()).surround(space_or_comment(), space_or_comment())
+  node(
+    HoconKind::Key.into(),
+    (path().attempt() | string()).surround(space_or_comment(), space_or_comment()),
+  )
 }
 
 fn property<'a>() -> Parser<'a, u8, (String, ConfigValue)> {
-  key() + ((kv() * lazy(config_value)).attempt() | object_config_value().attempt() | array_config_value())
+  node(
+    HoconKind::Property.into(),
+    key() + ((kv() * lazy(config_value)).attempt() | object_config_value().attempt() | array_config_value()),
+  )
 }
 
 fn property_config_value<'a>() -> Parser<'a, u8, (String, ConfigValue)> {
@@ -209,9 +240,11 @@ fn comma<'a>() -> Parser<'a, u8, &'a u8> {
 }
 
 fn object<'a>() -> Parser<'a, u8, HashMap<String, ConfigValue>> {
-  let properties: Parser<'a, u8, Vec<(String, ConfigValue)>> = lazy(property).of_many0_sep(comma().opt());
-  let obj: Parser<'a, u8, Vec<(String, ConfigValue)>> =
-    properties.surround(object_left_bracket(), object_right_bracket());
+  let properties: Parser<'a, u8, Vec<(String, ConfigValue)>> = lazy(property).of_many0_sep_trailing(comma().opt());
+  let obj: Parser<'a, u8, Vec<(String, ConfigValue)>> = node(
+    HoconKind::Object.into(),
+    properties.surround(object_left_bracket(), object_right_bracket()),
+  );
   obj.map(|properties| {
     let m: HashMap<String, ConfigValue> = HashMap::new();
     properties.into_iter().fold(m, |mut r, e| {
@@ -227,7 +260,10 @@ fn object<'a>() -> Parser<'a, u8, HashMap<String, ConfigValue>> {
 }
 
 fn path_element<'a>() -> Parser<'a, u8, &'a [u8]> {
-  (elm_alpha() | elm_of(b"-_")).of_many1().collect()
+  let ascii = ('a'..='z').union('A'..='Z').union("-_");
+  utf8_char_of(ascii.union(MajorCategory::Letter))
+    .of_skip_many1()
+    .collect()
 }
 
 fn path<'a>() -> Parser<'a, u8, String> {
@@ -282,13 +318,13 @@ fn enumeration_config_value<'a>() -> Parser<'a, u8, ConfigValue> {
 }
 
 fn simple_config_value<'a>() -> Parser<'a, u8, ConfigValue> {
-  (seq(b"null").map(|_| ConfigValue::Null).attempt()
+  let value = seq(b"null").map(|_| ConfigValue::Null).attempt()
     | seq(b"true").map(|_| ConfigValue::Bool(true)).attempt()
     | seq(b"false").map(|_| ConfigValue::Bool(false)).attempt()
     | duration_value().attempt()
     | number_value().map(ConfigValue::Number).attempt()
-    | string_config_value())
-  .surround(space_or_comment(), space_or_comment())
+    | string_config_value();
+  node(HoconKind::Value.into(), value).surround(space_or_comment(), space_or_comment())
 }
 
 fn config_value<'a>() -> Parser<'a, u8, ConfigValue> {
@@ -326,6 +362,21 @@ pub fn hocon<'a>() -> Parser<'a, u8, Vec<ConfigValue>> {
   space_or_comment() * config() - end()
 }
 
+/// Parses the input into a lossless concrete syntax tree, keeping whitespace and comments, whose text is exactly the
+/// input.
+pub fn hocon_cst(input: &[u8]) -> Result<GreenNode<u8>, ParseError<'_, u8>> {
+  hocon()
+    .parse_cst(input, HoconKind::Document.into())
+    .map(|(_, tree)| tree)
+}
+
+/// Parses the input into a concrete syntax tree like [hocon_cst], within the given limits.
+pub fn hocon_cst_with_limits(input: &[u8], limits: ParseLimits) -> Result<GreenNode<u8>, ParseError<'_, u8>> {
+  hocon()
+    .parse_cst_with_limits(input, HoconKind::Document.into(), limits)
+    .map(|(_, tree)| tree)
+}
+
 #[cfg(test)]
 mod gens {
   use super::*;
@@ -390,6 +441,7 @@ mod gens {
           (1, Gens::pure('_')),
           (4, Gens::choose_char('a', 'z')),
           (4, Gens::choose_char('A', 'Z')),
+          (1, Gens::choose_char('α', 'ω')),
         ]),
       )
       .map(|chars| chars.iter().collect::<String>())
@@ -497,4 +549,46 @@ mod tests {
     });
     prop::test_with_prop(prop, MAX_SIZE, TEST_COUNT, new_rng())
   }
+
+  #[test]
+  fn unicode_path_test() {
+    for input in ["größe.単位", "ключ.value-x", "_a.b_"] {
+      let result = (path() - end()).parse(input.as_bytes()).to_result();
+      assert_eq!(result.unwrap(), input);
+    }
+    for input in ["a.b$", "1abc", "a.b1", "a.١"] {
+      assert!((path() - end()).parse(input.as_bytes()).is_failure(), "{}", input);
+    }
+  }
+
+  #[test]
+  fn comment_lines_test() {
+    for input in [&b"# a\n// b\n# c"[..], b"  // only\n"] {
+      assert!((comment() - end()).parse(input).is_success(), "{:?}", input);
+    }
+  }
+
+  #[test]
+  fn hocon_cst_test() {
+    let input = b"# settings\nfoo {\n  bar = 10 // ten\n  baz: [1, \"a\"]\n}\n";
+    let tree = hocon_cst(input).unwrap();
+    assert_eq!(tree.text(), input.to_vec());
+    assert_eq!(tree.kind(), HoconKind::Document.into());
+
+    let comments = tree
+      .tokens()
+      .into_iter()
+      .filter(|token| token.kind() == HoconKind::Comment.into())
+      .map(|token| token.text().to_vec())
+      .collect::<Vec<_>>();
+    assert_eq!(comments, vec![b"# settings\n".to_vec(), b"// ten\n".to_vec()]);
+    assert_eq!(tree.descendants(HoconKind::Property.into()).len(), 3);
+    assert_eq!(tree.descendants(HoconKind::Array.into()).len(), 1);
+    assert!(hocon_cst(b"foo = [").is_err());
+
+    let limits = ParseLimits::new().with_max_depth(8);
+    assert_eq!(hocon_cst_with_limits(input, limits).unwrap(), tree);
+    let nested = [b"a = ".to_vec(), b"[".repeat(64), b"]".repeat(64)].concat();
+    assert!(hocon_cst_with_limits(&nested, limits).unwrap_err().is_limit_exceeded());
+  }
 }
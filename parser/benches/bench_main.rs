@@ -15,10 +15,12 @@ use criterion::*;
 use crate::nom_json::nom_parse_json;
 use crate::oni_comb_json::{oni_comb_parse_json, oni_comb_parse_json_bytes};
 use crate::pom_json::pom_parse_json;
+use crate::scan::scan_benchmark;
 
 mod nom_json;
 mod oni_comb_json;
 mod pom_json;
+mod scan;
 
 /// シンプルなJSONテスト用データ
 fn get_simple_test_data() -> Vec<(&'static str, &'static str)> {
@@ -57,7 +59,7 @@ fn criterion_benchmark(criterion: &mut Criterion) {
 criterion_group! {
   name = benches;
   config = Criterion::default();
-  targets = criterion_benchmark
+  targets = criterion_benchmark, scan_benchmark
 }
 
 criterion_main! {
@@ -15,7 +15,7 @@ pub enum JsonValue {
 }
 
 fn space<'a>() -> Parser<'a, char, ()> {
-  elm_of(" \t\r\n").of_many0().discard()
+  elm_of(" \t\r\n").of_skip_many0()
 }
 
 fn number<'a>() -> Parser<'a, char, f64> {
@@ -134,7 +134,7 @@ pub fn oni_comb_parse_json(s: &str) {
 
 // 空白文字をスキップ
 fn space_bytes<'a>() -> Parser<'a, u8, ()> {
-  elm_of(&b" \t\r\n"[..]).of_many0().discard()
+  elm_of(&b" \t\r\n"[..]).of_skip_many0()
 }
 
 // 数値のパース
@@ -0,0 +1,59 @@
+use criterion::*;
+use oni_comb_parser_rs::prelude::*;
+
+/// 長いコメント行を含む入力
+fn comment_input() -> Vec<u8> {
+  let mut input = b"# ".to_vec();
+  input.extend(std::iter::repeat_n(b'x', 64 * 1024));
+  input.extend(b"\r\nkey = value\n");
+  input
+}
+
+/// 長いブロックコメントを含む入力
+fn block_comment_input() -> Vec<u8> {
+  let mut input = b"/*".to_vec();
+  input.extend(std::iter::repeat_n(b"* a ".iter().copied(), 16 * 1024).flatten());
+  input.extend(b"*/");
+  input
+}
+
+pub fn scan_benchmark(criterion: &mut Criterion) {
+  let mut group = criterion.benchmark_group("scan");
+  group.sample_size(30);
+  group.measurement_time(std::time::Duration::from_secs(1));
+
+  let input = comment_input();
+  group.bench_with_input(BenchmarkId::new("take_till0", "line"), &input, |b, i| {
+    b.iter(|| take_till0(|c: &u8| matches!(*c, b'\r' | b'\n')).parse(i).success())
+  });
+  group.bench_with_input(BenchmarkId::new("take_until_byte", "line"), &input, |b, i| {
+    b.iter(|| take_until_byte(b'\n').parse(i).success())
+  });
+  group.bench_with_input(BenchmarkId::new("take_until_any", "line"), &input, |b, i| {
+    b.iter(|| take_until_any(b"\r\n").parse(i).success())
+  });
+
+  let input = block_comment_input();
+  group.bench_with_input(BenchmarkId::new("not_elm_any", "block"), &input, |b, i| {
+    b.iter(|| {
+      let p = seq(b"/*") * (seq(b"*/").not() * elm_any()).of_skip_many0() - seq(b"*/");
+      p.parse(i).is_success()
+    })
+  });
+  group.bench_with_input(BenchmarkId::new("take_until_seq", "block"), &input, |b, i| {
+    b.iter(|| {
+      let p = seq(b"/*") * take_until_seq(b"*/") - seq(b"*/");
+      p.parse(i).is_success()
+    })
+  });
+
+  let input = [b" \t\r\n".repeat(16 * 1024), b"value".to_vec()].concat();
+  group.bench_with_input(BenchmarkId::new("elm_of_many0", "whitespace"), &input, |b, i| {
+    b.iter(|| elm_of(b" \t\r\n").of_many0().parse(i).is_success())
+  });
+  group.bench_with_input(BenchmarkId::new("take_while_in", "whitespace"), &input, |b, i| {
+    b.iter(|| take_while_in(b" \t\r\n").parse(i).success())
+  });
+
+  group.finish();
+}
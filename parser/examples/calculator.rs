@@ -36,7 +36,7 @@ impl Expr {
 }
 
 fn space<'a>() -> Parser<'a, char, ()> {
-  elm_of(" \t\r\n").of_many0().discard()
+  elm_of(" \t\r\n").of_skip_many0()
 }
 
 fn expr<'a>() -> Parser<'a, char, Rc<Expr>> {
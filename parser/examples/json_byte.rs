@@ -14,7 +14,7 @@ pub enum JsonValue {
 }
 
 fn space<'a>() -> Parser<'a, u8, ()> {
-  elm_of(b" \t\r\n").of_many0().discard()
+  elm_of(b" \t\r\n").of_skip_many0()
 }
 
 fn number<'a>() -> Parser<'a, u8, f64> {
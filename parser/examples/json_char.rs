@@ -15,7 +15,7 @@ pub enum JsonValue {
 }
 
 fn space<'a>() -> Parser<'a, char, ()> {
-  elm_of(" \t\r\n").of_many0().discard()
+  elm_of(" \t\r\n").of_skip_many0()
 }
 
 fn number<'a>() -> Parser<'a, char, f64> {
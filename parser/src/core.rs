@@ -1,7 +1,13 @@
 pub use committed_status::*;
+pub use diagnostics::*;
 pub use element::*;
+pub use green_tree::*;
 pub use parse_error::*;
+pub use parse_iter::*;
+pub use parse_limits::*;
+pub use parse_options::*;
 pub use parse_result::*;
+pub use parse_session::*;
 pub use parse_state::*;
 pub use parser::*;
 pub use parser_filter::*;
@@ -10,11 +16,20 @@ pub use parser_monad::*;
 pub use parser_pure::*;
 pub use parser_runner::*;
 pub use parsers::*;
+pub use record_decoder::*;
+#[cfg(feature = "async")]
+pub use record_stream::*;
 
 mod committed_status;
+mod diagnostics;
 mod element;
+mod green_tree;
 mod parse_error;
+mod parse_iter;
+mod parse_limits;
+mod parse_options;
 mod parse_result;
+mod parse_session;
 mod parse_state;
 mod parser;
 mod parser_filter;
@@ -23,3 +38,6 @@ mod parser_monad;
 mod parser_pure;
 mod parser_runner;
 mod parsers;
+mod record_decoder;
+#[cfg(feature = "async")]
+mod record_stream;
@@ -0,0 +1,115 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::fmt::Display;
+use std::panic::Location;
+
+/// A warning about the grammar, found while parsing in debug mode.<br/>
+/// デバッグモードでの解析中に見つかった文法に関する警告。
+///
+/// See [crate::core::ParserRunner::parse_with_diagnostics].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnostic {
+  /// An `attempt()` that never turned a committed failure into an uncommitted one, so it did not change the outcome.
+  RedundantAttempt {
+    location: &'static Location<'static>,
+    calls: usize,
+  },
+  /// An alternative of `or` that would have matched more input than an earlier alternative that succeeded first,
+  /// e.g. `seq(b"s") | seq(b"seconds")`.
+  ShadowedAlternative {
+    location: &'static Location<'static>,
+    offset: usize,
+    length: usize,
+    shadowing_length: usize,
+  },
+}
+
+impl Display for Diagnostic {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Diagnostic::RedundantAttempt { location, calls } => write!(
+        f,
+        "attempt at {} never changed the outcome in {} calls",
+        location, calls
+      ),
+      Diagnostic::ShadowedAlternative {
+        location,
+        offset,
+        length,
+        shadowing_length,
+      } => write!(
+        f,
+        "alternative of or at {} is shadowed at {}: it matches {} elements, but an earlier alternative matched {}",
+        location, offset, length, shadowing_length
+      ),
+    }
+  }
+}
+
+#[derive(Debug)]
+struct AttemptUsage {
+  location: &'static Location<'static>,
+  calls: usize,
+  changed: bool,
+}
+
+/// Collects the diagnostics of a parse, shared by all the parse states of the parse.
+#[derive(Debug, Default)]
+pub(crate) struct DiagnosticsCollector {
+  attempts: RefCell<Vec<AttemptUsage>>,
+  shadowed: RefCell<Vec<Diagnostic>>,
+}
+
+impl DiagnosticsCollector {
+  pub(crate) fn record_attempt(&self, location: &'static Location<'static>, changed: bool) {
+    let mut attempts = self.attempts.borrow_mut();
+    match attempts.iter_mut().find(|usage| usage.location == location) {
+      Some(usage) => {
+        usage.calls += 1;
+        usage.changed |= changed;
+      }
+      None => attempts.push(AttemptUsage {
+        location,
+        calls: 1,
+        changed,
+      }),
+    }
+  }
+
+  pub(crate) fn record_shadowed(
+    &self,
+    location: &'static Location<'static>,
+    offset: usize,
+    length: usize,
+    shadowing_length: usize,
+  ) {
+    let mut shadowed = self.shadowed.borrow_mut();
+    let recorded = shadowed
+      .iter()
+      .any(|diagnostic| matches!(diagnostic, Diagnostic::ShadowedAlternative { location: l, .. } if *l == location));
+    if !recorded {
+      shadowed.push(Diagnostic::ShadowedAlternative {
+        location,
+        offset,
+        length,
+        shadowing_length,
+      });
+    }
+  }
+
+  pub(crate) fn diagnostics(&self) -> Vec<Diagnostic> {
+    let mut diagnostics = self.shadowed.borrow().clone();
+    diagnostics.extend(
+      self
+        .attempts
+        .borrow()
+        .iter()
+        .filter(|usage| !usage.changed)
+        .map(|usage| Diagnostic::RedundantAttempt {
+          location: usage.location,
+          calls: usage.calls,
+        }),
+    );
+    diagnostics
+  }
+}
@@ -4,6 +4,10 @@ use std::fmt::{Debug, Display};
 pub trait Element: PartialEq + PartialOrd + Display + Copy + Debug + Sized + 'static {
   /// Convert to a character.
   fn to_char(self) -> char;
+  /// Convert from an ASCII character, or `None` if the element cannot represent it.
+  fn from_ascii(_b: u8) -> Option<Self> {
+    None
+  }
   /// Check if it is an ASCII space.
   fn is_ascii_space(&self) -> bool;
   /// Check if it is an ASCII space including newlines.
@@ -48,6 +52,10 @@ impl Element for u8 {
     char::from(self)
   }
 
+  fn from_ascii(b: u8) -> Option<Self> {
+    b.is_ascii().then_some(b)
+  }
+
   fn is_ascii_space(&self) -> bool {
     matches!(*self, b' ' | b'\t')
   }
@@ -97,7 +105,7 @@ impl Element for u8 {
   }
 
   fn is_ascii_oct_digit(&self) -> bool {
-    matches!(*self, b'0'..=b'8')
+    matches!(*self, b'0'..=b'7')
   }
 
   fn is_ascii_punctuation(&self) -> bool {
@@ -118,6 +126,10 @@ impl Element for char {
     self
   }
 
+  fn from_ascii(b: u8) -> Option<Self> {
+    b.is_ascii().then_some(char::from(b))
+  }
+
   fn is_ascii_space(&self) -> bool {
     matches!(*self, ' ' | '\t')
   }
@@ -167,7 +179,7 @@ impl Element for char {
   }
 
   fn is_ascii_oct_digit(&self) -> bool {
-    matches!(*self, '0'..='8')
+    matches!(*self, '0'..='7')
   }
 
   fn is_ascii_punctuation(&self) -> bool {
@@ -236,7 +248,7 @@ impl Element for char {
 //   }
 //
 //   fn is_ascii_oct_digit(&self) -> bool {
-//     matches!(**self, '0'..='8')
+//     matches!(**self, '0'..='7')
 //   }
 //
 //   fn is_ascii_punctuation(&self) -> bool {
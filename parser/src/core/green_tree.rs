@@ -0,0 +1,230 @@
+use std::cell::RefCell;
+
+/// The kind of a node or token of a concrete syntax tree, defined by the grammar.<br/>
+/// 具象構文木のノードやトークンの種類。文法が定義する。
+///
+/// Like rowan, the kinds are plain numbers, so that a grammar can map its own enum to them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SyntaxKind(pub u16);
+
+impl SyntaxKind {
+  /// The kind of the input consumed by a node that is not covered by any of its tokens or child nodes.
+  pub const TEXT: SyntaxKind = SyntaxKind(u16::MAX);
+}
+
+/// A leaf of a concrete syntax tree, holding the input it was parsed from.<br/>
+/// 具象構文木の葉。解析元の入力を保持する。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GreenToken<I> {
+  kind: SyntaxKind,
+  text: Vec<I>,
+}
+
+impl<I> GreenToken<I> {
+  pub fn new(kind: SyntaxKind, text: Vec<I>) -> Self {
+    Self { kind, text }
+  }
+
+  pub fn kind(&self) -> SyntaxKind {
+    self.kind
+  }
+
+  pub fn text(&self) -> &[I] {
+    &self.text
+  }
+}
+
+/// A child of a [GreenNode].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GreenElement<I> {
+  Node(GreenNode<I>),
+  Token(GreenToken<I>),
+}
+
+impl<I> GreenElement<I> {
+  pub fn kind(&self) -> SyntaxKind {
+    match self {
+      GreenElement::Node(node) => node.kind(),
+      GreenElement::Token(token) => token.kind(),
+    }
+  }
+
+  /// Returns the number of elements of the input covered by the element.
+  pub fn text_len(&self) -> usize {
+    match self {
+      GreenElement::Node(node) => node.text_len(),
+      GreenElement::Token(token) => token.text().len(),
+    }
+  }
+}
+
+/// A node of a lossless concrete syntax tree, similar to the green nodes of rowan.<br/>
+/// ロスレスな具象構文木のノード。rowanのグリーンノードに相当する。
+///
+/// The tokens of a node cover all the input it consumed, including whitespace and comments, so that the text of the
+/// tree is exactly the parsed input. See [crate::prelude::node] and [crate::prelude::token].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GreenNode<I> {
+  kind: SyntaxKind,
+  text_len: usize,
+  children: Vec<GreenElement<I>>,
+}
+
+impl<I> GreenNode<I> {
+  pub fn new(kind: SyntaxKind, children: Vec<GreenElement<I>>) -> Self {
+    let text_len = children.iter().map(GreenElement::text_len).sum();
+    Self {
+      kind,
+      text_len,
+      children,
+    }
+  }
+
+  pub fn kind(&self) -> SyntaxKind {
+    self.kind
+  }
+
+  /// Returns the number of elements of the input covered by the node.
+  pub fn text_len(&self) -> usize {
+    self.text_len
+  }
+
+  pub fn children(&self) -> &[GreenElement<I>] {
+    &self.children
+  }
+
+  /// Returns the tokens of the node in order, descending into the child nodes.
+  pub fn tokens(&self) -> Vec<&GreenToken<I>> {
+    let mut tokens = Vec::new();
+    for child in &self.children {
+      match child {
+        GreenElement::Node(node) => tokens.extend(node.tokens()),
+        GreenElement::Token(token) => tokens.push(token),
+      }
+    }
+    tokens
+  }
+
+  /// Returns the child nodes of the given kind, descending into the child nodes of other kinds.
+  pub fn descendants(&self, kind: SyntaxKind) -> Vec<&GreenNode<I>> {
+    let mut nodes = Vec::new();
+    for child in &self.children {
+      if let GreenElement::Node(node) = child {
+        if node.kind == kind {
+          nodes.push(node);
+        }
+        nodes.extend(node.descendants(kind));
+      }
+    }
+    nodes
+  }
+}
+
+impl<I: Clone> GreenNode<I> {
+  /// Returns the text of the node, which is the input it was parsed from.
+  pub fn text(&self) -> Vec<I> {
+    self
+      .tokens()
+      .into_iter()
+      .flat_map(|token| token.text.iter().cloned())
+      .collect()
+  }
+}
+
+#[derive(Debug, Clone)]
+struct RawElement {
+  kind: SyntaxKind,
+  start: usize,
+  end: usize,
+  children: Option<Vec<RawElement>>,
+}
+
+impl RawElement {
+  fn to_green<I: Clone>(&self, input: &[I]) -> GreenElement<I> {
+    match &self.children {
+      None => GreenElement::Token(GreenToken::new(self.kind, input[self.start..self.end].to_vec())),
+      Some(children) => GreenElement::Node(green_node(self.kind, self.start, self.end, children, input)),
+    }
+  }
+}
+
+/// Builds a node from its recorded children, covering the input between them with [SyntaxKind::TEXT] tokens.
+fn green_node<I: Clone>(
+  kind: SyntaxKind,
+  start: usize,
+  end: usize,
+  children: &[RawElement],
+  input: &[I],
+) -> GreenNode<I> {
+  let text = |from: usize, to: usize| GreenElement::Token(GreenToken::new(SyntaxKind::TEXT, input[from..to].to_vec()));
+  let mut elements = Vec::new();
+  let mut offset = start;
+  for child in children {
+    if offset < child.start {
+      elements.push(text(offset, child.start));
+    }
+    elements.push(child.to_green(input));
+    offset = child.end;
+  }
+  if offset < end {
+    elements.push(text(offset, end));
+  }
+  GreenNode::new(kind, elements)
+}
+
+/// Records the nodes and tokens of a parse, shared by all the parse states of the parse.
+///
+/// The elements recorded by a failed `node()`, `token()` or `attempt()` are rolled back, so only the successful parse
+/// remains.
+#[derive(Debug, Default)]
+pub(crate) struct CstRecorder {
+  elements: RefCell<Vec<RawElement>>,
+}
+
+impl CstRecorder {
+  pub(crate) fn checkpoint(&self) -> usize {
+    self.elements.borrow().len()
+  }
+
+  pub(crate) fn rollback(&self, checkpoint: usize) {
+    self.elements.borrow_mut().truncate(checkpoint);
+  }
+
+  pub(crate) fn token(&self, kind: SyntaxKind, start: usize, end: usize) {
+    if start < end {
+      self.push(RawElement {
+        kind,
+        start,
+        end,
+        children: None,
+      });
+    }
+  }
+
+  /// Turns the elements recorded since the checkpoint into the children of a new node.
+  pub(crate) fn node(&self, kind: SyntaxKind, checkpoint: usize, start: usize, end: usize) {
+    let children = self.elements.borrow_mut().split_off(checkpoint);
+    self.push(RawElement {
+      kind,
+      start,
+      end,
+      children: Some(children),
+    });
+  }
+
+  fn push(&self, element: RawElement) {
+    let mut elements = self.elements.borrow_mut();
+    // Elements left by a successful lookahead (e.g. `peek`) overlap the ones consumed after it.
+    while elements.last().is_some_and(|last| last.end > element.start) {
+      elements.pop();
+    }
+    elements.push(element);
+  }
+
+  /// Returns the root node of the given kind covering `input[..end]`.
+  pub(crate) fn finish<I: Clone>(&self, kind: SyntaxKind, input: &[I], end: usize) -> GreenNode<I> {
+    let elements = self.elements.borrow();
+    let end_of_children = elements.iter().take_while(|element| element.end <= end).count();
+    green_node(kind, 0, end, &elements[..end_of_children], input)
+  }
+}
@@ -1,5 +1,70 @@
+use crate::core::LimitKind;
+use std::cmp::Ordering;
+use std::error::Error;
 use std::fmt;
-use std::fmt::Display;
+use std::fmt::{Debug, Display};
+use std::sync::Arc;
+
+/// The original error of a failed conversion or a custom error, kept so that it can be inspected by type.<br/>
+/// 変換に失敗した元のエラー、またはカスタムエラー。型を指定して取り出すことができる。
+///
+/// Two causes are equal only if they share the same error, e.g. clones of the same cause, since errors are not
+/// comparable in general.
+#[derive(Clone)]
+pub struct ErrorCause {
+  error: Arc<dyn Error + Send + Sync + 'static>,
+}
+
+impl ErrorCause {
+  pub fn new<E>(error: E) -> Self
+  where
+    E: Error + Send + Sync + 'static, {
+    Self { error: Arc::new(error) }
+  }
+
+  /// Returns the original error.
+  pub fn error(&self) -> &(dyn Error + Send + Sync + 'static) {
+    self.error.as_ref()
+  }
+
+  /// Returns a reference to the original error if it is of type `E`.
+  pub fn downcast_ref<E: Error + 'static>(&self) -> Option<&E> {
+    self.error.downcast_ref::<E>()
+  }
+
+  /// Returns true if the original error is of type `E`.
+  pub fn is<E: Error + 'static>(&self) -> bool {
+    self.error.is::<E>()
+  }
+}
+
+impl Debug for ErrorCause {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    Debug::fmt(&self.error, f)
+  }
+}
+
+impl Display for ErrorCause {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    Display::fmt(&self.error, f)
+  }
+}
+
+impl PartialEq for ErrorCause {
+  fn eq(&self, other: &Self) -> bool {
+    Arc::ptr_eq(&self.error, &other.error)
+  }
+}
+
+impl PartialOrd for ErrorCause {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    if self == other {
+      Some(Ordering::Equal)
+    } else {
+      None
+    }
+  }
+}
 
 /// The enum type representing the parsing error.
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
@@ -12,11 +77,17 @@ pub enum ParseError<'a, I> {
     message: String,
   },
   /// Error when conversion fails
+  ///
+  /// `cause` holds the original error of `map_res_with_cause`, and is `None` for `map_res` and `map_opt`.
+  ///
+  /// Adding `cause` to this variant and to [ParseError::Custom] is a breaking change for code that constructs the
+  /// variants or matches them without `..`; use the `of_*` constructors and `..` patterns instead.
   Conversion {
     input: &'a [I],
     offset: usize,
     length: usize,
     message: String,
+    cause: Option<ErrorCause>,
   },
   /// Error when parsing is interrupted or incomplete
   Incomplete,
@@ -27,10 +98,19 @@ pub enum ParseError<'a, I> {
     message: String,
   },
   /// Custom error
+  ///
+  /// `cause` holds the domain error given to [ParseError::of_custom_with_cause], and is `None` otherwise.
   Custom {
     offset: usize,
     inner: Option<Box<ParseError<'a, I>>>,
     message: String,
+    cause: Option<ErrorCause>,
+  },
+  /// Error when a limit of [crate::core::ParseLimits] is exceeded
+  LimitExceeded {
+    offset: usize,
+    kind: LimitKind,
+    limit: usize,
   },
 }
 
@@ -57,16 +137,29 @@ impl<'a, I> Display for ParseError<'a, I> {
         ref message,
         ref offset,
         inner: Some(ref inner),
+        ..
       } => write!(f, "{} at {}, (inner: {})", message, offset, inner),
       ParseError::Custom {
         ref message,
         ref offset,
         inner: None,
+        ..
       } => write!(f, "{} at {}", message, offset),
+      ParseError::LimitExceeded {
+        ref offset,
+        ref kind,
+        ref limit,
+      } => write!(f, "Limit of {} {} exceeded at {}", limit, kind, offset),
     }
   }
 }
 
+impl<'a, I: Debug> Error for ParseError<'a, I> {
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    self.cause().map(|cause| cause.error() as &(dyn Error + 'static))
+  }
+}
+
 impl<'a> ParseError<'a, char> {
   pub fn input_string(&self) -> Option<String> {
     self.input().map(|chars| String::from_iter(chars))
@@ -86,23 +179,43 @@ impl<'a> ParseError<'a, u8> {
 }
 
 impl<'a, I> ParseError<'a, I> {
+  /// Returns the input that caused the error, or `None` if the recorded range does not fit into the input.
   pub fn input(&self) -> Option<&'a [I]> {
     match self {
       ParseError::Incomplete => None,
       ParseError::Mismatch {
         input, offset, length, ..
-      } => Some(&input[*offset..(*offset + length)]),
+      } => input.get(*offset..offset.checked_add(*length)?),
       ParseError::Conversion {
         input, offset, length, ..
-      } => Some(&input[*offset..(*offset + length)]),
+      } => input.get(*offset..offset.checked_add(*length)?),
       ParseError::Expect { ref inner, .. } => inner.input(),
       ParseError::Custom {
         inner: Some(ref inner), ..
       } => inner.input(),
       ParseError::Custom { inner: None, .. } => None,
+      ParseError::LimitExceeded { .. } => None,
+    }
+  }
+
+  /// Returns the original error of a failed conversion or a custom error, searching the inner errors.
+  pub fn cause(&self) -> Option<&ErrorCause> {
+    match self {
+      ParseError::Conversion { cause, .. } => cause.as_ref(),
+      ParseError::Expect { ref inner, .. } => inner.cause(),
+      ParseError::Custom { cause: Some(cause), .. } => Some(cause),
+      ParseError::Custom {
+        inner: Some(ref inner), ..
+      } => inner.cause(),
+      _ => None,
     }
   }
 
+  /// Returns the original error of a failed conversion or a custom error if it is of type `E`.
+  pub fn downcast_ref<E: Error + 'static>(&self) -> Option<&E> {
+    self.cause().and_then(|cause| cause.downcast_ref::<E>())
+  }
+
   pub fn is_expect(&self) -> bool {
     match self {
       ParseError::Expect { .. } => true,
@@ -131,6 +244,10 @@ impl<'a, I> ParseError<'a, I> {
     }
   }
 
+  pub fn is_limit_exceeded(&self) -> bool {
+    matches!(self, ParseError::LimitExceeded { .. })
+  }
+
   pub fn is_in_complete(&self) -> bool {
     match self {
       ParseError::Incomplete => true,
@@ -143,7 +260,22 @@ impl<'a, I> ParseError<'a, I> {
   }
 
   pub fn of_custom(offset: usize, inner: Option<Box<ParseError<'a, I>>>, message: String) -> Self {
-    ParseError::Custom { offset, inner, message }
+    ParseError::Custom {
+      offset,
+      inner,
+      message,
+      cause: None,
+    }
+  }
+
+  /// Creates a custom error from a domain error, whose message is the [Display] of the error.
+  pub fn of_custom_with_cause(offset: usize, inner: Option<Box<ParseError<'a, I>>>, cause: ErrorCause) -> Self {
+    ParseError::Custom {
+      offset,
+      inner,
+      message: cause.to_string(),
+      cause: Some(cause),
+    }
   }
 
   pub fn of_mismatch(input: &'a [I], offset: usize, length: usize, message: String) -> Self {
@@ -161,9 +293,30 @@ impl<'a, I> ParseError<'a, I> {
       offset,
       length,
       message,
+      cause: None,
+    }
+  }
+
+  pub fn of_conversion_with_cause(
+    input: &'a [I],
+    offset: usize,
+    length: usize,
+    message: String,
+    cause: ErrorCause,
+  ) -> Self {
+    ParseError::Conversion {
+      input,
+      offset,
+      length,
+      message,
+      cause: Some(cause),
     }
   }
 
+  pub fn of_limit_exceeded(offset: usize, kind: LimitKind, limit: usize) -> Self {
+    ParseError::LimitExceeded { offset, kind, limit }
+  }
+
   pub fn of_in_complete() -> Self {
     ParseError::Incomplete
   }
@@ -0,0 +1,69 @@
+use crate::core::{ParseError, ParseResult, ParseState, Parser};
+
+/// An iterator that applies a [Parser] repeatedly and yields each parsed value lazily.<br/>
+/// [Parser]を繰り返し適用し、解析した値を遅延的に返すイテレータ。
+///
+/// The iteration stops at the first failure, or after a value that consumed no input.
+/// If the failure happened before the end of the input, it can be obtained with [ParseIter::error].
+pub struct ParseIter<'a, I, A> {
+  parser: Parser<'a, I, A>,
+  input: &'a [I],
+  offset: usize,
+  error: Option<ParseError<'a, I>>,
+  done: bool,
+}
+
+impl<'a, I, A> ParseIter<'a, I, A> {
+  /// Creates a new iterator that parses the given input from the beginning.
+  pub fn new(parser: Parser<'a, I, A>, input: &'a [I]) -> Self {
+    Self {
+      parser,
+      input,
+      offset: 0,
+      error: None,
+      done: false,
+    }
+  }
+
+  /// Returns the offset of the input consumed so far.
+  pub fn offset(&self) -> usize {
+    self.offset
+  }
+
+  /// Returns the input that has not been consumed yet.
+  pub fn rest(&self) -> &'a [I] {
+    &self.input[self.offset..]
+  }
+
+  /// Returns the error that stopped the iteration before the end of the input, if any.
+  pub fn error(&self) -> Option<&ParseError<'a, I>> {
+    self.error.as_ref()
+  }
+}
+
+impl<'a, I, A> Iterator for ParseIter<'a, I, A> {
+  type Item = A;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.done {
+      return None;
+    }
+    let parse_state = ParseState::new(self.input, self.offset);
+    match (self.parser.method)(&parse_state) {
+      ParseResult::Success { value, length } => {
+        if length == 0 {
+          self.done = true;
+        }
+        self.offset += length;
+        Some(value)
+      }
+      ParseResult::Failure { error, .. } => {
+        self.done = true;
+        if self.offset < self.input.len() {
+          self.error = Some(error);
+        }
+        None
+      }
+    }
+  }
+}
@@ -0,0 +1,164 @@
+use std::cell::Cell;
+use std::fmt;
+use std::fmt::Display;
+
+/// The kind of a resource limit of [ParseLimits].<br/>
+/// [ParseLimits]の制限の種類。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LimitKind {
+  /// The nesting depth of `lazy` parsers.
+  Depth,
+  /// The number of steps, i.e. `lazy` calls, `or` alternatives and repetitions.
+  Steps,
+  /// The number of repetitions of a single repeat parser.
+  Repetitions,
+}
+
+impl Display for LimitKind {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      LimitKind::Depth => write!(f, "depth"),
+      LimitKind::Steps => write!(f, "steps"),
+      LimitKind::Repetitions => write!(f, "repetitions"),
+    }
+  }
+}
+
+/// Resource limits for parsing untrusted input.<br/>
+/// 信頼できない入力を解析するための資源の制限。
+///
+/// All limits are unlimited by default.
+///
+/// # Example
+///
+/// ```rust
+/// # use oni_comb_parser_rs::prelude::*;
+///
+/// fn nested<'a>() -> Parser<'a, u8, usize> {
+///   (elm(b'[') * lazy(nested) - elm(b']')).map(|n| n + 1) | unit().map(|_| 0)
+/// }
+///
+/// let limits = ParseLimits::new().with_max_depth(8);
+///
+/// let result = nested().parse_with_limits(b"[[[]]]", limits);
+/// assert_eq!(result.success(), Some(3));
+///
+/// let input = [b"[".repeat(100), b"]".repeat(100)].concat();
+/// let result = nested().parse_with_limits(&input, limits);
+/// assert!(result.failure().unwrap().is_limit_exceeded());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseLimits {
+  max_depth: Option<usize>,
+  max_steps: Option<usize>,
+  max_repetitions: Option<usize>,
+}
+
+impl ParseLimits {
+  /// Creates limits without any restriction.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Limits the nesting depth of `lazy` parsers.
+  pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+    self.max_depth = Some(max_depth);
+    self
+  }
+
+  /// Limits the total number of steps, i.e. `lazy` calls, `or` alternatives and repetitions.
+  pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+    self.max_steps = Some(max_steps);
+    self
+  }
+
+  /// Limits the number of repetitions of a single repeat parser.
+  pub fn with_max_repetitions(mut self, max_repetitions: usize) -> Self {
+    self.max_repetitions = Some(max_repetitions);
+    self
+  }
+
+  pub fn max_depth(&self) -> Option<usize> {
+    self.max_depth
+  }
+
+  pub fn max_steps(&self) -> Option<usize> {
+    self.max_steps
+  }
+
+  pub fn max_repetitions(&self) -> Option<usize> {
+    self.max_repetitions
+  }
+}
+
+/// The resources used by a parse, shared by all the parse states of the parse.
+#[derive(Debug)]
+pub(crate) struct ParseBudget {
+  limits: ParseLimits,
+  depth: Cell<usize>,
+  steps: Cell<usize>,
+  exceeded: Cell<Option<(LimitKind, usize, usize)>>,
+}
+
+impl ParseBudget {
+  pub(crate) fn new(limits: ParseLimits) -> Self {
+    Self {
+      limits,
+      depth: Cell::new(0),
+      steps: Cell::new(0),
+      exceeded: Cell::new(None),
+    }
+  }
+
+  /// Returns the exceeded limit as `(kind, limit, offset)`.
+  pub(crate) fn exceeded(&self) -> Option<(LimitKind, usize, usize)> {
+    self.exceeded.get()
+  }
+
+  /// Counts a step, failing from now on once any limit has been exceeded.
+  pub(crate) fn step(&self, offset: usize) -> Result<(), (LimitKind, usize, usize)> {
+    if let Some(exceeded) = self.exceeded.get() {
+      return Err(exceeded);
+    }
+    let steps = self.steps.get() + 1;
+    self.steps.set(steps);
+    self.check(LimitKind::Steps, steps, self.limits.max_steps, offset)
+  }
+
+  pub(crate) fn enter(&self, offset: usize) -> Result<(), (LimitKind, usize, usize)> {
+    self.step(offset)?;
+    let depth = self.depth.get() + 1;
+    self.depth.set(depth);
+    let result = self.check(LimitKind::Depth, depth, self.limits.max_depth, offset);
+    if result.is_err() {
+      self.leave();
+    }
+    result
+  }
+
+  pub(crate) fn leave(&self) {
+    self.depth.set(self.depth.get().saturating_sub(1));
+  }
+
+  pub(crate) fn repeat(&self, count: usize, offset: usize) -> Result<(), (LimitKind, usize, usize)> {
+    self.step(offset)?;
+    self.check(LimitKind::Repetitions, count, self.limits.max_repetitions, offset)
+  }
+
+  fn check(
+    &self,
+    kind: LimitKind,
+    value: usize,
+    max: Option<usize>,
+    offset: usize,
+  ) -> Result<(), (LimitKind, usize, usize)> {
+    match max {
+      Some(max) if value > max => {
+        let exceeded = (kind, max, offset);
+        self.exceeded.set(Some(exceeded));
+        Err(exceeded)
+      }
+      _ => Ok(()),
+    }
+  }
+}
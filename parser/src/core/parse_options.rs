@@ -0,0 +1,82 @@
+use crate::core::ParseLimits;
+
+/// The optional features of a parse, combined into one [crate::core::ParseState] with
+/// [crate::core::ParseState::with_options].<br/>
+/// 解析の任意の機能。[crate::core::ParseState::with_options]で一つの[crate::core::ParseState]にまとめる。
+///
+/// No feature is enabled by default.
+///
+/// # Example
+///
+/// ```rust
+/// # use oni_comb_parser_rs::prelude::*;
+///
+/// let options = ParseOptions::new()
+///   .with_limits(ParseLimits::new().with_max_depth(8))
+///   .with_diagnostics();
+/// let parse_state = ParseState::with_options(b"abc", 0, options);
+///
+/// let result = seq(b"ab").run(&parse_state);
+/// assert!(result.is_success());
+/// assert!(parse_state.limit_error().is_none());
+/// assert!(parse_state.diagnostics().is_empty());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+  limits: Option<ParseLimits>,
+  diagnostics: bool,
+  cst: bool,
+  partial: bool,
+}
+
+impl ParseOptions {
+  /// Creates options without any feature.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Restricts the parse by the given limits.
+  pub fn with_limits(mut self, limits: ParseLimits) -> Self {
+    self.limits = Some(limits);
+    self
+  }
+
+  /// Collects [crate::core::Diagnostic]s about the grammar.
+  pub fn with_diagnostics(mut self) -> Self {
+    self.diagnostics = true;
+    self
+  }
+
+  /// Records a concrete syntax tree with `node()` and `token()`.
+  pub fn with_cst(mut self) -> Self {
+    self.cst = true;
+    self
+  }
+
+  /// Records whether the parse depended on the end of an input that may be continued, see
+  /// [crate::core::ParserRunner::parse_partial].
+  pub fn with_partial(mut self) -> Self {
+    self.partial = true;
+    self
+  }
+
+  /// Returns the limits, if any.
+  pub fn limits(&self) -> Option<ParseLimits> {
+    self.limits
+  }
+
+  /// Returns true if diagnostics are collected.
+  pub fn is_diagnostics(&self) -> bool {
+    self.diagnostics
+  }
+
+  /// Returns true if a concrete syntax tree is recorded.
+  pub fn is_cst(&self) -> bool {
+    self.cst
+  }
+
+  /// Returns true if the end of the input is tracked.
+  pub fn is_partial(&self) -> bool {
+    self.partial
+  }
+}
@@ -0,0 +1,237 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::ops::Range;
+use std::rc::Rc;
+
+use fnv::FnvHashMap;
+
+use crate::core::{ParseLimits, ParseOptions, ParseResult, ParseState, Parser, ParserRunner};
+
+/// An edit of the input of a [ParseSession]: the elements in `range` are replaced with `replacement`.<br/>
+/// [ParseSession]の入力の編集。`range`の要素を`replacement`で置き換える。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit<I> {
+  range: Range<usize>,
+  replacement: Vec<I>,
+}
+
+impl<I> TextEdit<I> {
+  /// Creates an edit that replaces the elements in `range` with `replacement`.
+  pub fn new(range: Range<usize>, replacement: Vec<I>) -> Self {
+    Self { range, replacement }
+  }
+
+  /// Creates an edit that inserts `elements` at `offset`.
+  pub fn insert(offset: usize, elements: Vec<I>) -> Self {
+    Self::new(offset..offset, elements)
+  }
+
+  /// Creates an edit that deletes the elements in `range`.
+  pub fn delete(range: Range<usize>) -> Self {
+    Self::new(range, Vec::new())
+  }
+
+  pub fn range(&self) -> &Range<usize> {
+    &self.range
+  }
+
+  pub fn replacement(&self) -> &[I] {
+    &self.replacement
+  }
+}
+
+#[derive(Debug)]
+struct MemoEntry {
+  value: Rc<dyn Any>,
+  length: usize,
+}
+
+thread_local! {
+  /// The number of `memo()` parsers built so far by the parser factory of a [ParseSession], while it is called.
+  static MEMO_IDS: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// Allocates the id of a `memo()` parser being built, or `None` if it is not built by the factory of a session.
+pub(crate) fn next_memo_id() -> Option<usize> {
+  MEMO_IDS.with(|ids| {
+    let id = ids.get()?;
+    ids.set(Some(id + 1));
+    Some(id)
+  })
+}
+
+/// Calls the parser factory of a session, numbering its `memo()` parsers in the order they are built.
+fn build_parser<T, F>(factory: F) -> T
+where
+  F: FnOnce() -> T, {
+  let previous = MEMO_IDS.with(|ids| ids.replace(Some(0)));
+  let parser = factory();
+  MEMO_IDS.with(|ids| ids.set(previous));
+  parser
+}
+
+/// The results of `memo()` parsers, kept by a [ParseSession] across parses.
+#[derive(Debug, Default)]
+pub(crate) struct MemoTable {
+  entries: RefCell<FnvHashMap<(usize, usize), MemoEntry>>,
+  hits: Cell<usize>,
+}
+
+impl MemoTable {
+  pub(crate) fn get<A: Clone + 'static>(&self, id: usize, offset: usize) -> Option<(A, usize)> {
+    let entries = self.entries.borrow();
+    let entry = entries.get(&(id, offset))?;
+    let value = entry.value.downcast_ref::<A>()?.clone();
+    self.hits.set(self.hits.get() + 1);
+    Some((value, entry.length))
+  }
+
+  pub(crate) fn insert<A: 'static>(&self, id: usize, offset: usize, value: A, length: usize) {
+    let entry = MemoEntry {
+      value: Rc::new(value),
+      length,
+    };
+    self.entries.borrow_mut().insert((id, offset), entry);
+  }
+
+  /// Drops the entries that examined the replaced range and shifts the entries after it.
+  fn apply_edit(&self, range: &Range<usize>, inserted: usize, lookahead: usize) {
+    let entries = std::mem::take(&mut *self.entries.borrow_mut());
+    let shifted = entries
+      .into_iter()
+      .filter_map(|((id, offset), entry)| {
+        if offset + entry.length + lookahead <= range.start {
+          Some(((id, offset), entry))
+        } else if offset >= range.end {
+          Some(((id, offset - range.end + range.start + inserted), entry))
+        } else {
+          None
+        }
+      })
+      .collect();
+    *self.entries.borrow_mut() = shifted;
+  }
+
+  fn len(&self) -> usize {
+    self.entries.borrow().len()
+  }
+}
+
+/// A persistent parse of an input that is edited over time, e.g. a document in an editor.<br/>
+/// 時間とともに編集される入力(エディタのドキュメントなど)の永続的な解析。
+///
+/// The session keeps the results of the parsers marked with `memo()`, and [ParseSession::reparse] reuses the ones
+/// outside the edited range instead of parsing the whole input again.
+///
+/// The session builds the parser with the given factory on every parse, and identifies each `memo()` parser by the
+/// order in which the factory builds it, so the factory must build the same grammar every time. A `memo()` parser
+/// built while parsing, e.g. inside `lazy`, is not built by the factory and parses as is; memoize the `lazy` parser
+/// instead. The result of a memoized parser is assumed to depend only on the input it matched and the next
+/// `lookahead` elements (1 by default, see [ParseSession::with_lookahead]).
+///
+/// # Example
+///
+/// ```rust
+/// # use oni_comb_parser_rs::prelude::*;
+///
+/// fn words<'a>() -> Parser<'a, u8, Vec<String>> {
+///   let word = elm_alpha().of_many1().collect().map_res(std::str::from_utf8).map(str::to_string);
+///   (word - elm(b' ').opt()).memo().of_many0()
+/// }
+///
+/// let mut session = ParseSession::new(b"hello big world".to_vec());
+/// assert_eq!(session.parse(words).success().unwrap(), vec!["hello", "big", "world"]);
+///
+/// let result = session.reparse(TextEdit::new(6..9, b"small".to_vec()), words);
+/// assert_eq!(result.success().unwrap(), vec!["hello", "small", "world"]);
+/// assert_eq!(session.input(), b"hello small world");
+/// assert_eq!(session.memo_hits(), 1);
+/// ```
+#[derive(Debug)]
+pub struct ParseSession<I> {
+  input: Vec<I>,
+  memo: Rc<MemoTable>,
+  lookahead: usize,
+  limits: Option<ParseLimits>,
+}
+
+impl<I> ParseSession<I> {
+  /// Creates a session for the input.
+  pub fn new(input: Vec<I>) -> Self {
+    Self {
+      input,
+      memo: Rc::new(MemoTable::default()),
+      lookahead: 1,
+      limits: None,
+    }
+  }
+
+  /// Sets how many elements after its match a memoized parser may examine.
+  pub fn with_lookahead(mut self, lookahead: usize) -> Self {
+    self.lookahead = lookahead;
+    self
+  }
+
+  /// Restricts every parse of the session by the given limits, like [ParserRunner::parse_with_limits].
+  ///
+  /// A reused result does not count against the limits again.
+  pub fn with_limits(mut self, limits: ParseLimits) -> Self {
+    self.limits = Some(limits);
+    self
+  }
+
+  /// Returns the current input.
+  pub fn input(&self) -> &[I] {
+    &self.input
+  }
+
+  /// Returns the number of memoized results currently kept.
+  pub fn memo_len(&self) -> usize {
+    self.memo.len()
+  }
+
+  /// Returns the number of times a memoized result was reused.
+  pub fn memo_hits(&self) -> usize {
+    self.memo.hits.get()
+  }
+
+  /// Parses the current input with the parser built by the factory, reusing the memoized results.
+  ///
+  /// The factory must build the same grammar on every call.
+  pub fn parse<'a, A, F>(&'a self, factory: F) -> ParseResult<'a, I, A>
+  where
+    F: FnOnce() -> Parser<'a, I, A>, {
+    let parser = build_parser(factory);
+    let options = match self.limits {
+      Some(limits) => ParseOptions::new().with_limits(limits),
+      None => ParseOptions::new(),
+    };
+    let parse_state = ParseState::with_options_and_memo(&self.input, 0, options, Some(self.memo.clone()));
+    let result = parser.run(&parse_state);
+    parse_state.check_limits(result)
+  }
+
+  /// Applies the edit to the input, invalidating the memoized results it affects.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the range of the edit is out of bounds of the input, like [Vec::splice].
+  pub fn edit(&mut self, edit: TextEdit<I>) {
+    let TextEdit { range, replacement } = edit;
+    let inserted = replacement.len();
+    self.input.splice(range.clone(), replacement);
+    self.memo.apply_edit(&range, inserted, self.lookahead);
+  }
+
+  /// Applies the edit to the input and parses it again, reusing the memoized results outside the edited range.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the range of the edit is out of bounds of the input, like [Vec::splice].
+  pub fn reparse<'a, A, F>(&'a mut self, edit: TextEdit<I>, factory: F) -> ParseResult<'a, I, A>
+  where
+    F: FnOnce() -> Parser<'a, I, A>, {
+    self.edit(edit);
+    self.parse(factory)
+  }
+}
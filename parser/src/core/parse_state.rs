@@ -1,14 +1,215 @@
+use crate::core::{
+  CstRecorder, Diagnostic, DiagnosticsCollector, GreenElement, GreenNode, GreenToken, LimitKind, MemoTable,
+  ParseBudget, ParseError, ParseLimits, ParseOptions, ParseResult, SyntaxKind,
+};
+use std::cell::Cell;
+use std::rc::Rc;
+
 /// A struct representing the current parsing state.
 #[derive(Clone)]
 pub struct ParseState<'a, I> {
   input: &'a [I],
   offset: usize,
+  context: Option<Rc<ParseContext>>,
+}
+
+/// The optional contexts of a parse, shared by all the parse states of the parse.
+struct ParseContext {
+  budget: Option<ParseBudget>,
+  diagnostics: Option<DiagnosticsCollector>,
+  memo: Option<Rc<MemoTable>>,
+  cst: Option<CstRecorder>,
+  reached_end: Option<Cell<bool>>,
 }
 
 impl<'a, I> ParseState<'a, I> {
   /// Creates a new parsing state with the given input and offset.
   pub fn new(input: &'a [I], offset: usize) -> Self {
-    Self { input, offset }
+    Self {
+      input,
+      offset,
+      context: None,
+    }
+  }
+
+  /// Creates a new parsing state with the given features, e.g. limits combined with a concrete syntax tree.
+  pub fn with_options(input: &'a [I], offset: usize, options: ParseOptions) -> Self {
+    Self::with_options_and_memo(input, offset, options, None)
+  }
+
+  /// Creates a new parsing state with the given features, whose `memo()` parsers use the given memo table.
+  pub(crate) fn with_options_and_memo(
+    input: &'a [I],
+    offset: usize,
+    options: ParseOptions,
+    memo: Option<Rc<MemoTable>>,
+  ) -> Self {
+    let context = ParseContext {
+      budget: options.limits().map(ParseBudget::new),
+      diagnostics: options.is_diagnostics().then(DiagnosticsCollector::default),
+      memo,
+      cst: options.is_cst().then(CstRecorder::default),
+      reached_end: options.is_partial().then(|| Cell::new(false)),
+    };
+    Self {
+      input,
+      offset,
+      context: Some(Rc::new(context)),
+    }
+  }
+
+  fn context(&self) -> Option<&ParseContext> {
+    self.context.as_deref()
+  }
+
+  /// Creates a new parsing state whose parse is restricted by the given limits.
+  pub fn with_limits(input: &'a [I], offset: usize, limits: ParseLimits) -> Self {
+    Self::with_options(input, offset, ParseOptions::new().with_limits(limits))
+  }
+
+  /// Creates a new parsing state whose parse collects [Diagnostic]s about the grammar.
+  pub fn with_diagnostics(input: &'a [I], offset: usize) -> Self {
+    Self::with_options(input, offset, ParseOptions::new().with_diagnostics())
+  }
+
+  /// Records that the parse depended on the end of the input, so that more input might change its result.
+  pub(crate) fn reach_end(&self) {
+    if let Some(reached_end) = self.context().and_then(|context| context.reached_end.as_ref()) {
+      reached_end.set(true);
+    }
+  }
+
+  /// Returns true if the parse depended on the end of the input.
+  pub(crate) fn has_reached_end(&self) -> bool {
+    self
+      .context()
+      .and_then(|context| context.reached_end.as_ref())
+      .is_some_and(Cell::get)
+  }
+
+  pub(crate) fn memo_table(&self) -> Option<&MemoTable> {
+    self.context().and_then(|context| context.memo.as_deref())
+  }
+
+  pub(crate) fn cst_recorder(&self) -> Option<&CstRecorder> {
+    self.context().and_then(|context| context.cst.as_ref())
+  }
+
+  /// Returns the concrete syntax tree recorded for `input[..end]`, with a root node of the given kind.
+  pub(crate) fn cst(&self, kind: SyntaxKind, end: usize) -> GreenNode<I>
+  where
+    I: Clone, {
+    match self.cst_recorder() {
+      Some(recorder) => recorder.finish(kind, self.input, end),
+      None => GreenNode::new(
+        kind,
+        vec![GreenElement::Token(GreenToken::new(
+          SyntaxKind::TEXT,
+          self.input[..end].to_vec(),
+        ))],
+      ),
+    }
+  }
+
+  /// Runs a lookahead, whose nodes and tokens are not part of the tree even if it succeeds.
+  pub(crate) fn lookahead<T, F>(&self, f: F) -> T
+  where
+    F: FnOnce() -> T, {
+    match self.cst_recorder() {
+      None => f(),
+      Some(recorder) => {
+        let checkpoint = recorder.checkpoint();
+        let result = f();
+        recorder.rollback(checkpoint);
+        result
+      }
+    }
+  }
+
+  /// Runs a parse that may be backtracked, whose nodes and tokens are not part of the tree if it fails.
+  pub(crate) fn backtrackable<A, F>(&self, f: F) -> ParseResult<'a, I, A>
+  where
+    F: FnOnce() -> ParseResult<'a, I, A>, {
+    match self.cst_recorder() {
+      None => f(),
+      Some(recorder) => {
+        let checkpoint = recorder.checkpoint();
+        let result = f();
+        if result.is_failure() {
+          recorder.rollback(checkpoint);
+        }
+        result
+      }
+    }
+  }
+
+  /// Returns the diagnostics collected so far, empty unless created with [ParseOptions::with_diagnostics].
+  pub fn diagnostics(&self) -> Vec<Diagnostic> {
+    self
+      .diagnostics_collector()
+      .map(|diagnostics| diagnostics.diagnostics())
+      .unwrap_or_default()
+  }
+
+  pub(crate) fn diagnostics_collector(&self) -> Option<&DiagnosticsCollector> {
+    self.context().and_then(|context| context.diagnostics.as_ref())
+  }
+
+  /// Returns the same position without limits and diagnostics, for speculative parses.
+  pub(crate) fn detached(&self) -> Self {
+    Self::new(self.input, self.offset)
+  }
+
+  /// Returns the error of the limit exceeded during the parse, if any.
+  pub fn limit_error(&self) -> Option<ParseError<'a, I>> {
+    self
+      .budget()
+      .and_then(|budget| budget.exceeded())
+      .map(|(kind, limit, offset)| ParseError::of_limit_exceeded(offset, kind, limit))
+  }
+
+  /// Replaces the result of the parse with the error of the exceeded limit, if any.
+  pub(crate) fn check_limits<A>(&self, result: ParseResult<'a, I, A>) -> ParseResult<'a, I, A> {
+    match self.limit_error() {
+      Some(error) => ParseResult::failed_with_commit(error),
+      None => result,
+    }
+  }
+
+  /// Counts a step of the parse.
+  pub(crate) fn step(&self) -> Result<(), ParseError<'a, I>> {
+    self.check_budget(|budget, offset| budget.step(offset))
+  }
+
+  /// Enters a nested parser, which must be followed by [ParseState::leave] if successful.
+  pub(crate) fn enter(&self) -> Result<(), ParseError<'a, I>> {
+    self.check_budget(|budget, offset| budget.enter(offset))
+  }
+
+  pub(crate) fn leave(&self) {
+    if let Some(budget) = self.budget() {
+      budget.leave();
+    }
+  }
+
+  /// Counts a repetition, `count` is the number of repetitions including this one.
+  pub(crate) fn repeat(&self, count: usize) -> Result<(), ParseError<'a, I>> {
+    self.check_budget(|budget, offset| budget.repeat(count, offset))
+  }
+
+  fn budget(&self) -> Option<&ParseBudget> {
+    self.context().and_then(|context| context.budget.as_ref())
+  }
+
+  fn check_budget<F>(&self, f: F) -> Result<(), ParseError<'a, I>>
+  where
+    F: FnOnce(&ParseBudget, usize) -> Result<(), (LimitKind, usize, usize)>, {
+    match self.budget() {
+      Some(budget) => {
+        f(budget, self.offset).map_err(|(kind, limit, offset)| ParseError::of_limit_exceeded(offset, kind, limit))
+      }
+      None => Ok(()),
+    }
   }
 
   /// Returns the offset of the previous position, or None if at the beginning.
@@ -27,7 +228,16 @@ impl<'a, I> ParseState<'a, I> {
 
   /// Creates a new parse state with an offset increased by the specified number of characters.
   pub fn advance_by(&self, num_chars: usize) -> ParseState<'a, I> {
-    Self::new(self.input, self.offset + num_chars)
+    Self {
+      input: self.input,
+      offset: self.offset + num_chars,
+      context: self.context.clone(),
+    }
+  }
+
+  /// Returns the whole input, which the offsets of [ParseError]s point into.
+  pub(crate) fn whole_input(&self) -> &'a [I] {
+    self.input
   }
 
   /// Returns the slice of input starting from the current offset.
@@ -1,5 +1,14 @@
 use crate::core::ParserMonad;
-use crate::core::{ParseError, ParseResult, ParseState};
+use crate::core::{
+  Diagnostic, GreenNode, ParseError, ParseIter, ParseLimits, ParseOptions, ParseResult, ParseState, PartialResult,
+  SyntaxKind,
+};
+
+/// The result of [ParserRunner::parse_prefix], the value with the remaining input.
+pub type PrefixResult<'a, I, A> = Result<(A, &'a [I]), ParseError<'a, I>>;
+
+/// The result of [ParserRunner::parse_cst], the value with the concrete syntax tree.
+pub type CstResult<'a, I, A> = Result<(A, GreenNode<I>), ParseError<'a, I>>;
 
 pub trait ParserRunner<'a> {
   type Input;
@@ -18,6 +27,136 @@ pub trait ParserRunner<'a> {
     self.parse(input).to_result()
   }
 
+  /// Analyze input value within the given resource limits(for [ParseResult]).<br/>
+  /// 資源の制限の範囲内で入力を解析する。
+  ///
+  /// Fails with [ParseError::LimitExceeded] once any of the limits is exceeded.
+  fn parse_with_limits(
+    &self,
+    input: &'a [Self::Input],
+    limits: ParseLimits,
+  ) -> ParseResult<'a, Self::Input, Self::Output> {
+    let parse_state = ParseState::with_limits(input, 0, limits);
+    let result = self.run(&parse_state);
+    parse_state.check_limits(result)
+  }
+
+  /// Analyze the whole input within the given resource limits, failing if any input is left over.<br/>
+  /// 資源の制限の範囲内で入力全体を解析する。入力が残った場合は失敗する。
+  ///
+  /// Reports trailing input like [ParserRunner::parse_all], and exceeded limits like
+  /// [ParserRunner::parse_with_limits].
+  fn parse_all_with_limits(
+    &self,
+    input: &'a [Self::Input],
+    limits: ParseLimits,
+  ) -> Result<Self::Output, ParseError<'a, Self::Input>> {
+    complete(input, self.parse_with_limits(input, limits))
+  }
+
+  /// Analyze input value in debug mode, returning the [Diagnostic]s about the grammar found while parsing.<br/>
+  /// デバッグモードで入力を解析し、解析中に見つかった文法に関する[Diagnostic]を返す。
+  ///
+  /// Reports `attempt()`s that never changed the outcome, and alternatives of `or` that were shadowed by an earlier
+  /// alternative matching a shorter input. Each diagnostic is also logged as a warning.
+  fn parse_with_diagnostics(
+    &self,
+    input: &'a [Self::Input],
+  ) -> (ParseResult<'a, Self::Input, Self::Output>, Vec<Diagnostic>) {
+    let parse_state = ParseState::with_diagnostics(input, 0);
+    let result = self.run(&parse_state);
+    let diagnostics = parse_state.diagnostics();
+    for diagnostic in &diagnostics {
+      log::warn!("{}", diagnostic);
+    }
+    (result, diagnostics)
+  }
+
+  /// Analyze input value and build a lossless concrete syntax tree whose root node has the given kind.<br/>
+  /// 入力を解析し、指定した種類を根とするロスレスな具象構文木を構築する。
+  ///
+  /// The tree is made of the `node()`s and `token()`s of the parser, and covers the consumed input exactly.
+  fn parse_cst(&self, input: &'a [Self::Input], root: SyntaxKind) -> CstResult<'a, Self::Input, Self::Output>
+  where
+    Self::Input: Clone, {
+    run_cst(self, input, root, ParseOptions::new().with_cst())
+  }
+
+  /// Analyze input value within the given resource limits and build a concrete syntax tree, like
+  /// [ParserRunner::parse_cst].<br/>
+  /// 資源の制限の範囲内で入力を解析し、[ParserRunner::parse_cst]と同様に具象構文木を構築する。
+  fn parse_cst_with_limits(
+    &self,
+    input: &'a [Self::Input],
+    root: SyntaxKind,
+    limits: ParseLimits,
+  ) -> CstResult<'a, Self::Input, Self::Output>
+  where
+    Self::Input: Clone, {
+    run_cst(self, input, root, ParseOptions::new().with_cst().with_limits(limits))
+  }
+
+  /// Analyze the beginning of the input and return the value with the remaining input.<br/>
+  /// 入力の先頭を解析し、値と残りの入力を返す。
+  fn parse_prefix(&self, input: &'a [Self::Input]) -> PrefixResult<'a, Self::Input, Self::Output> {
+    match self.parse(input) {
+      ParseResult::Success { value, length } => Ok((value, &input[length..])),
+      ParseResult::Failure { error, .. } => Err(error),
+    }
+  }
+
+  /// Analyze the whole input, failing if any input is left over.<br/>
+  /// 入力全体を解析する。入力が残った場合は失敗する。
+  fn parse_all(&self, input: &'a [Self::Input]) -> Result<Self::Output, ParseError<'a, Self::Input>> {
+    complete(input, self.parse(input))
+  }
+
+  /// Analyze an input that may be continued, e.g. the data received so far from a socket.<br/>
+  /// 続きがあるかもしれない入力(ソケットからそれまでに受信したデータなど)を解析する。
+  ///
+  /// Returns [PartialResult::Incomplete] if the result depended on the end of the input: a parser failed or a
+  /// repetition stopped because the input ran out. The parse can then be retried from the start once more input is
+  /// available, or completed with [ParserRunner::parse] at the end of the input. See [crate::core::RecordDecoder].
+  fn parse_partial(&self, input: &'a [Self::Input]) -> PartialResult<'a, Self::Input, Self::Output> {
+    run_partial(self, input, ParseOptions::new().with_partial())
+  }
+
+  /// Analyze an input that may be continued within the given resource limits, like
+  /// [ParserRunner::parse_partial].<br/>
+  /// 資源の制限の範囲内で、[ParserRunner::parse_partial]と同様に続きがあるかもしれない入力を解析する。
+  ///
+  /// An exceeded limit is a [PartialResult::Failure], since more input cannot lower the resources used.
+  fn parse_partial_with_limits(
+    &self,
+    input: &'a [Self::Input],
+    limits: ParseLimits,
+  ) -> PartialResult<'a, Self::Input, Self::Output> {
+    run_partial(self, input, ParseOptions::new().with_partial().with_limits(limits))
+  }
+
+  /// Analyze records repeatedly until the whole input is consumed.<br/>
+  /// 入力全体を消費するまで、レコードを繰り返し解析する。
+  ///
+  /// Fails with the error of the first record that could not be parsed.
+  fn parse_many_from(&self, input: &'a [Self::Input]) -> Result<Vec<Self::Output>, ParseError<'a, Self::Input>>
+  where
+    Self::Input: Clone, {
+    let mut iter = self.parse_iter(input);
+    let values = iter.by_ref().collect::<Vec<_>>();
+    match iter.error() {
+      Some(error) => Err(error.clone()),
+      None if !iter.rest().is_empty() => {
+        let msg = "unexpected trailing input".to_string();
+        Err(ParseError::of_mismatch(input, iter.offset(), iter.rest().len(), msg))
+      }
+      None => Ok(values),
+    }
+  }
+
+  /// Returns an iterator that parses the input repeatedly and yields values lazily.<br/>
+  /// 入力を繰り返し解析し、値を遅延的に返すイテレータを返す。
+  fn parse_iter(&self, input: &'a [Self::Input]) -> ParseIter<'a, Self::Input, Self::Output>;
+
   /// Analyze input value(for [ParseResult]).<br/>
   /// 入力を解析する。
   ///
@@ -25,3 +164,49 @@ pub trait ParserRunner<'a> {
   /// 引数に[ParseState]が必要です。
   fn run(&self, param: &ParseState<'a, Self::Input>) -> ParseResult<'a, Self::Input, Self::Output>;
 }
+
+fn run_cst<'a, R>(
+  runner: &R,
+  input: &'a [R::Input],
+  root: SyntaxKind,
+  options: ParseOptions,
+) -> CstResult<'a, R::Input, R::Output>
+where
+  R: ParserRunner<'a> + ?Sized,
+  R::Input: Clone, {
+  let parse_state = ParseState::with_options(input, 0, options);
+  let result = runner.run(&parse_state);
+  match parse_state.check_limits(result) {
+    ParseResult::Success { value, length } => Ok((value, parse_state.cst(root, length))),
+    ParseResult::Failure { error, .. } => Err(error),
+  }
+}
+
+fn run_partial<'a, R>(
+  runner: &R,
+  input: &'a [R::Input],
+  options: ParseOptions,
+) -> PartialResult<'a, R::Input, R::Output>
+where
+  R: ParserRunner<'a> + ?Sized, {
+  let parse_state = ParseState::with_options(input, 0, options);
+  let result = parse_state.check_limits(runner.run(&parse_state));
+  match result {
+    ParseResult::Failure { error, .. } if error.is_limit_exceeded() => PartialResult::Failure(error),
+    _ if parse_state.has_reached_end() => PartialResult::Incomplete,
+    ParseResult::Success { value, length } => PartialResult::Complete { value, length },
+    ParseResult::Failure { error, .. } => PartialResult::Failure(error),
+  }
+}
+
+/// Returns the value of a parse of the whole `input`, or an error if the parse failed or left input over.
+fn complete<'a, I, A>(input: &'a [I], result: ParseResult<'a, I, A>) -> Result<A, ParseError<'a, I>> {
+  match result {
+    ParseResult::Success { value, length } if length == input.len() => Ok(value),
+    ParseResult::Success { length, .. } => {
+      let msg = "unexpected trailing input".to_string();
+      Err(ParseError::of_mismatch(input, length, input.len() - length, msg))
+    }
+    ParseResult::Failure { error, .. } => Err(error),
+  }
+}
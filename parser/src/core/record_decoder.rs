@@ -0,0 +1,247 @@
+use std::fmt;
+use std::fmt::{Debug, Display};
+
+use crate::core::{ParseError, ParseLimits, ParseResult, Parser, ParserRunner};
+
+/// The default maximum length of a record of a [RecordDecoder], in elements.
+pub const DEFAULT_MAX_RECORD_LEN: usize = 1024 * 1024;
+
+/// The result of [ParserRunner::parse_partial], parsing an input that may be continued.<br/>
+/// 続きがあるかもしれない入力を解析した[ParserRunner::parse_partial]の結果。
+#[derive(Debug, Clone, PartialEq)]
+pub enum PartialResult<'a, I, A> {
+  /// The value was parsed from the first `length` elements, and more input cannot change it.
+  Complete { value: A, length: usize },
+  /// The parse depended on the end of the input, so it must be retried once more input is available.
+  Incomplete,
+  /// The parse failed before reaching the end of the input.
+  Failure(ParseError<'a, I>),
+}
+
+impl<'a, I, A> PartialResult<'a, I, A> {
+  pub fn is_complete(&self) -> bool {
+    matches!(self, PartialResult::Complete { .. })
+  }
+
+  pub fn is_incomplete(&self) -> bool {
+    matches!(self, PartialResult::Incomplete)
+  }
+
+  pub fn is_failure(&self) -> bool {
+    matches!(self, PartialResult::Failure(_))
+  }
+}
+
+/// The error of a record that could not be parsed by a [RecordDecoder].<br/>
+/// [RecordDecoder]が解析できなかったレコードのエラー。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordError {
+  position: usize,
+  message: String,
+}
+
+impl RecordError {
+  /// Returns the position of the record in the whole stream, counted in elements.
+  pub fn position(&self) -> usize {
+    self.position
+  }
+
+  pub fn message(&self) -> &str {
+    &self.message
+  }
+}
+
+impl Display for RecordError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "invalid record at {}: {}", self.position, self.message)
+  }
+}
+
+impl std::error::Error for RecordError {}
+
+/// Decodes records from an input that arrives in chunks, e.g. from a socket.<br/>
+/// ソケットなどから分割して届く入力からレコードを復号する。
+///
+/// The chunks are buffered and the record parser is retried from the start of the record whenever a chunk extends
+/// a record it reported [PartialResult::Incomplete] for, until [RecordDecoder::finish] marks the end of the input. A
+/// record is therefore parsed in time linear in its length times the number of chunks it spans. Each retry is bounded
+/// by the maximum length of a record, [DEFAULT_MAX_RECORD_LEN] unless set with [RecordDecoder::with_max_record_len],
+/// and the parse of a record can be restricted further with [RecordDecoder::with_limits].
+///
+/// The parser is built by a function for each record, so that its values may not borrow the buffer.
+///
+/// # Example
+///
+/// ```rust
+/// # use oni_comb_parser_rs::prelude::*;
+///
+/// fn line<'a>() -> Parser<'a, u8, String> {
+///   (take_until_byte(b'\n') - elm(b'\n')).map_res(std::str::from_utf8).map(str::to_string)
+/// }
+///
+/// let mut decoder = RecordDecoder::new(|_| line());
+/// decoder.extend(b"GET /a\nGE");
+/// assert_eq!(decoder.decode(), Some(Ok("GET /a".to_string())));
+/// assert_eq!(decoder.decode(), None);
+///
+/// decoder.extend(b"T /b\n");
+/// assert_eq!(decoder.decode(), Some(Ok("GET /b".to_string())));
+///
+/// decoder.extend(b"oops");
+/// decoder.finish();
+/// assert!(decoder.decode().unwrap().is_err());
+/// assert_eq!(decoder.decode(), None);
+/// ```
+pub struct RecordDecoder<I, F> {
+  factory: F,
+  buffer: Vec<I>,
+  start: usize,
+  position: usize,
+  max_record_len: usize,
+  limits: ParseLimits,
+  incomplete_len: Option<usize>,
+  finished: bool,
+  failed: bool,
+}
+
+impl<I, A, F> RecordDecoder<I, F>
+where
+  I: Clone,
+  F: for<'x> Fn(&'x [I]) -> Parser<'x, I, A>,
+{
+  /// Creates a decoder whose records are parsed by the parser built by `factory`.
+  ///
+  /// `factory` receives the buffered input, and usually ignores it.
+  pub fn new(factory: F) -> Self {
+    Self {
+      factory,
+      buffer: Vec::new(),
+      start: 0,
+      position: 0,
+      max_record_len: DEFAULT_MAX_RECORD_LEN,
+      limits: ParseLimits::new(),
+      incomplete_len: None,
+      finished: false,
+      failed: false,
+    }
+  }
+
+  /// Decodes the next record.
+  ///
+  /// Returns `None` if more input is needed, or at the end of the input. After an error, no more records are decoded.
+  pub fn decode(&mut self) -> Option<Result<A, RecordError>> {
+    let buffered = self.buffer.len() - self.start;
+    if self.failed || (self.finished && buffered == 0) {
+      return None;
+    }
+    // The record is still incomplete unless more input arrived since the last retry.
+    if !self.finished && self.incomplete_len == Some(self.buffer.len()) {
+      return None;
+    }
+    let record_len = self.max_record_len.min(buffered);
+    let (value, length) = {
+      let parser = (self.factory)(&self.buffer[self.start..]);
+      let record = &self.buffer[self.start..self.start + record_len];
+      let result = if self.finished && record_len == buffered {
+        match parser.parse_with_limits(record, self.limits) {
+          ParseResult::Success { value, length } => PartialResult::Complete { value, length },
+          ParseResult::Failure { error, .. } => PartialResult::Failure(error),
+        }
+      } else {
+        parser.parse_partial_with_limits(record, self.limits)
+      };
+      match result {
+        PartialResult::Incomplete if record_len < buffered => {
+          (Err(format!("the record is longer than {} elements", record_len)), 0)
+        }
+        PartialResult::Incomplete => {
+          self.incomplete_len = Some(self.buffer.len());
+          return None;
+        }
+        PartialResult::Complete { length: 0, .. } => (Err("the record parser consumed no input".to_string()), 0),
+        PartialResult::Complete { value, length } => (Ok(value), length),
+        PartialResult::Failure(error) => (Err(error.to_string()), 0),
+      }
+    };
+    let position = self.position;
+    self.incomplete_len = None;
+    self.start += length;
+    self.position += length;
+    // Drop the decoded records only once they make up half of the buffer, so that each element is moved at most
+    // once on average.
+    if self.start > self.buffer.len() / 2 {
+      self.buffer.drain(..self.start);
+      self.start = 0;
+    }
+    Some(value.map_err(|message| {
+      self.failed = true;
+      RecordError { position, message }
+    }))
+  }
+}
+
+impl<I, F> RecordDecoder<I, F> {
+  /// Limits the length of a record, so that a record is never retried on more than `max_record_len` elements.
+  /// The default is [DEFAULT_MAX_RECORD_LEN].
+  ///
+  /// A record that is still incomplete at this length is reported as an error.
+  pub fn with_max_record_len(mut self, max_record_len: usize) -> Self {
+    self.max_record_len = max_record_len;
+    self
+  }
+
+  pub fn max_record_len(&self) -> usize {
+    self.max_record_len
+  }
+
+  /// Restricts the parse of each record by the given limits, see [ParserRunner::parse_with_limits].
+  ///
+  /// A record that exceeds a limit is reported as an error.
+  pub fn with_limits(mut self, limits: ParseLimits) -> Self {
+    self.limits = limits;
+    self
+  }
+
+  pub fn limits(&self) -> ParseLimits {
+    self.limits
+  }
+
+  /// Appends a chunk of the input.
+  pub fn extend(&mut self, chunk: &[I])
+  where
+    I: Clone, {
+    self.buffer.extend_from_slice(chunk);
+  }
+
+  /// Marks the end of the input, so that the last record is parsed without waiting for more input.
+  pub fn finish(&mut self) {
+    self.finished = true;
+  }
+
+  pub fn is_finished(&self) -> bool {
+    self.finished
+  }
+
+  /// Returns the buffered input that has not been decoded yet.
+  pub fn buffered(&self) -> &[I] {
+    &self.buffer[self.start..]
+  }
+
+  /// Returns the position of the next record in the whole input.
+  pub fn position(&self) -> usize {
+    self.position
+  }
+}
+
+impl<I: Debug, F> Debug for RecordDecoder<I, F> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.debug_struct("RecordDecoder")
+      .field("buffer", &self.buffered())
+      .field("position", &self.position)
+      .field("max_record_len", &self.max_record_len)
+      .field("limits", &self.limits)
+      .field("finished", &self.finished)
+      .field("failed", &self.failed)
+      .finish()
+  }
+}
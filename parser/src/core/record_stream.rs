@@ -0,0 +1,238 @@
+use std::fmt;
+use std::fmt::{Debug, Display};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+
+use crate::core::{ParseLimits, Parser, RecordDecoder, RecordError};
+
+/// The error of a [RecordStream]: either reading the input or parsing a record failed.<br/>
+/// [RecordStream]のエラー。入力の読み込みかレコードの解析に失敗した。
+#[derive(Debug)]
+pub enum StreamError<E> {
+  /// The input could not be read.
+  Read(E),
+  /// A record could not be parsed.
+  Record(RecordError),
+}
+
+impl<E: Display> Display for StreamError<E> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      StreamError::Read(error) => write!(f, "read failed: {}", error),
+      StreamError::Record(error) => write!(f, "{}", error),
+    }
+  }
+}
+
+impl<E: Debug + Display> std::error::Error for StreamError<E> {}
+
+/// A [Stream] of the records parsed from a [Stream] of chunks, e.g. the data received from a socket.<br/>
+/// チャンクの[Stream]から解析したレコードの[Stream]。例えばソケットから受信したデータを解析する。
+///
+/// The records are decoded with a [RecordDecoder], which retries a record that is incomplete once the next chunk
+/// arrives. The stream ends after the last record, or after the first error.
+///
+/// With the `tokio` or `futures-io` feature, the chunks can be read from an `AsyncRead`, see
+/// `RecordStream::from_tokio` and `RecordStream::from_futures_io`.
+///
+/// # Example
+///
+/// ```rust
+/// # use oni_comb_parser_rs::prelude::*;
+/// use futures_util::{stream, StreamExt};
+///
+/// fn line<'a>() -> Parser<'a, u8, String> {
+///   (take_until_byte(b'\n') - elm(b'\n')).map_res(std::str::from_utf8).map(str::to_string)
+/// }
+///
+/// let chunks = stream::iter([&b"PING\nPI"[..], b"NG\n"].map(Ok::<_, std::io::Error>));
+/// let records = RecordStream::new(chunks, |_| line());
+///
+/// let lines = futures_executor::block_on(records.map(Result::unwrap).collect::<Vec<_>>());
+/// assert_eq!(lines, vec!["PING", "PING"]);
+/// ```
+pub struct RecordStream<S, I, F> {
+  chunks: S,
+  decoder: RecordDecoder<I, F>,
+  done: bool,
+}
+
+impl<S, I, F> RecordStream<S, I, F> {
+  /// Limits the length of a record, see [RecordDecoder::with_max_record_len].
+  pub fn with_max_record_len(mut self, max_record_len: usize) -> Self {
+    self.decoder = self.decoder.with_max_record_len(max_record_len);
+    self
+  }
+
+  /// Restricts the parse of each record by the given limits, see [RecordDecoder::with_limits].
+  pub fn with_limits(mut self, limits: ParseLimits) -> Self {
+    self.decoder = self.decoder.with_limits(limits);
+    self
+  }
+
+  /// Returns the position of the next record in the whole input.
+  pub fn position(&self) -> usize {
+    self.decoder.position()
+  }
+}
+
+impl<S, C, E, I, A, F> RecordStream<S, I, F>
+where
+  S: Stream<Item = Result<C, E>> + Unpin,
+  C: AsRef<[I]>,
+  I: Clone + Unpin,
+  F: for<'x> Fn(&'x [I]) -> Parser<'x, I, A> + Unpin,
+{
+  /// Creates a stream of the records parsed by the parser built by `factory`, see [RecordDecoder::new].
+  pub fn new(chunks: S, factory: F) -> Self {
+    Self {
+      chunks,
+      decoder: RecordDecoder::new(factory),
+      done: false,
+    }
+  }
+}
+
+impl<S, C, E, I, A, F> Stream for RecordStream<S, I, F>
+where
+  S: Stream<Item = Result<C, E>> + Unpin,
+  C: AsRef<[I]>,
+  I: Clone + Unpin,
+  F: for<'x> Fn(&'x [I]) -> Parser<'x, I, A> + Unpin,
+{
+  type Item = Result<A, StreamError<E>>;
+
+  fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+    let this = self.get_mut();
+    loop {
+      if this.done {
+        return Poll::Ready(None);
+      }
+      if let Some(record) = this.decoder.decode() {
+        this.done = record.is_err();
+        return Poll::Ready(Some(record.map_err(StreamError::Record)));
+      }
+      if this.decoder.is_finished() {
+        this.done = true;
+        continue;
+      }
+      match Pin::new(&mut this.chunks).poll_next(cx) {
+        Poll::Ready(Some(Ok(chunk))) => this.decoder.extend(chunk.as_ref()),
+        Poll::Ready(Some(Err(error))) => {
+          this.done = true;
+          return Poll::Ready(Some(Err(StreamError::Read(error))));
+        }
+        Poll::Ready(None) => this.decoder.finish(),
+        Poll::Pending => return Poll::Pending,
+      }
+    }
+  }
+}
+
+impl<S, I: Debug, F> Debug for RecordStream<S, I, F> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.debug_struct("RecordStream")
+      .field("decoder", &self.decoder)
+      .field("done", &self.done)
+      .finish()
+  }
+}
+
+#[cfg(any(feature = "tokio", feature = "futures-io"))]
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// A [Stream] of the chunks read from a `tokio::io::AsyncRead`.
+#[cfg(feature = "tokio")]
+#[derive(Debug)]
+pub struct TokioChunks<R> {
+  reader: R,
+  buffer: Box<[u8]>,
+}
+
+#[cfg(feature = "tokio")]
+impl<R> TokioChunks<R> {
+  pub fn new(reader: R) -> Self {
+    Self {
+      reader,
+      buffer: vec![0; CHUNK_SIZE].into_boxed_slice(),
+    }
+  }
+}
+
+#[cfg(feature = "tokio")]
+impl<R: tokio::io::AsyncRead + Unpin> Stream for TokioChunks<R> {
+  type Item = std::io::Result<Vec<u8>>;
+
+  fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+    let this = self.get_mut();
+    let mut buffer = tokio::io::ReadBuf::new(&mut this.buffer);
+    match Pin::new(&mut this.reader).poll_read(cx, &mut buffer) {
+      Poll::Ready(Ok(())) if buffer.filled().is_empty() => Poll::Ready(None),
+      Poll::Ready(Ok(())) => Poll::Ready(Some(Ok(buffer.filled().to_vec()))),
+      Poll::Ready(Err(error)) => Poll::Ready(Some(Err(error))),
+      Poll::Pending => Poll::Pending,
+    }
+  }
+}
+
+#[cfg(feature = "tokio")]
+impl<R, A, F> RecordStream<TokioChunks<R>, u8, F>
+where
+  R: tokio::io::AsyncRead + Unpin,
+  F: for<'x> Fn(&'x [u8]) -> Parser<'x, u8, A> + Unpin,
+{
+  /// Creates a stream of the records read from a `tokio::io::AsyncRead`.
+  pub fn from_tokio(reader: R, factory: F) -> Self {
+    Self::new(TokioChunks::new(reader), factory)
+  }
+}
+
+/// A [Stream] of the chunks read from a `futures_io::AsyncRead`.
+#[cfg(feature = "futures-io")]
+#[derive(Debug)]
+pub struct FuturesIoChunks<R> {
+  reader: R,
+  buffer: Box<[u8]>,
+}
+
+#[cfg(feature = "futures-io")]
+impl<R> FuturesIoChunks<R> {
+  pub fn new(reader: R) -> Self {
+    Self {
+      reader,
+      buffer: vec![0; CHUNK_SIZE].into_boxed_slice(),
+    }
+  }
+}
+
+#[cfg(feature = "futures-io")]
+impl<R: futures_io::AsyncRead + Unpin> Stream for FuturesIoChunks<R> {
+  type Item = std::io::Result<Vec<u8>>;
+
+  fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+    let this = self.get_mut();
+    loop {
+      return match Pin::new(&mut this.reader).poll_read(cx, &mut this.buffer) {
+        Poll::Ready(Ok(0)) => Poll::Ready(None),
+        Poll::Ready(Ok(n)) => Poll::Ready(Some(Ok(this.buffer[..n].to_vec()))),
+        Poll::Ready(Err(error)) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+        Poll::Ready(Err(error)) => Poll::Ready(Some(Err(error))),
+        Poll::Pending => Poll::Pending,
+      };
+    }
+  }
+}
+
+#[cfg(feature = "futures-io")]
+impl<R, A, F> RecordStream<FuturesIoChunks<R>, u8, F>
+where
+  R: futures_io::AsyncRead + Unpin,
+  F: for<'x> Fn(&'x [u8]) -> Parser<'x, u8, A> + Unpin,
+{
+  /// Creates a stream of the records read from a `futures_io::AsyncRead`.
+  pub fn from_futures_io(reader: R, factory: F) -> Self {
+    Self::new(FuturesIoChunks::new(reader), factory)
+  }
+}
@@ -6,4 +6,14 @@ pub trait CacheParser<'a>: ParserRunner<'a> {
   where
     Self::Input: Clone + 'a,
     Self::Output: Clone + Debug + 'a;
+
+  /// Returns a parser whose results are kept by the [crate::core::ParseSession] it runs in, and reused by
+  /// [crate::core::ParseSession::reparse] outside the edited range.<br/>
+  /// 結果を[crate::core::ParseSession]に保持し、再解析時に編集範囲外で再利用するパーサーを返す。
+  ///
+  /// The parser is identified by the order in which the parser factory of the session builds it, see
+  /// [crate::core::ParseSession]. A parser not built by the factory of a session parses as is.
+  fn memo(self) -> Self::P<'a, Self::Input, Self::Output>
+  where
+    Self::Output: Clone + Debug + 'static;
 }
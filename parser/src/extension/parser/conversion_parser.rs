@@ -1,11 +1,31 @@
 use crate::extension::parser::OperatorParser;
+use std::error::Error;
 use std::fmt::Debug;
 
 pub trait ConversionParser<'a>: OperatorParser<'a> {
+  /// Converts the value with a fallible function, failing with a [crate::core::ParseError::Conversion] if it
+  /// returns an error.<br/>
+  /// 失敗しうる関数で値を変換し、エラーの場合は[crate::core::ParseError::Conversion]で失敗する。
+  ///
+  /// Since `E` only has to implement [Debug], the error is kept only as the `{:?}` text in the message, and the
+  /// cause of the [crate::core::ParseError::Conversion] is `None`. Use [ConversionParser::map_res_with_cause] to keep
+  /// the error itself.
   fn map_res<B, E, F>(self, f: F) -> Self::P<'a, Self::Input, B>
   where
     F: Fn(Self::Output) -> Result<B, E> + 'a,
     E: Debug,
     Self::Output: Debug + 'a,
     B: Debug + 'a;
+
+  /// Converts the value like [ConversionParser::map_res], keeping the error as the cause of the
+  /// [crate::core::ParseError::Conversion].<br/>
+  /// [ConversionParser::map_res]と同様に変換し、エラーを[crate::core::ParseError::Conversion]の原因として保持する。
+  ///
+  /// The error can be inspected with [crate::core::ParseError::downcast_ref] or [Error::source].
+  fn map_res_with_cause<B, E, F>(self, f: F) -> Self::P<'a, Self::Input, B>
+  where
+    F: Fn(Self::Output) -> Result<B, E> + 'a,
+    E: Error + Send + Sync + 'static,
+    Self::Output: Debug + 'a,
+    B: Debug + 'a;
 }
@@ -27,6 +27,10 @@ pub trait OperatorParser<'a>: ParserRunner<'a> {
   where
     Self::Output: Debug + 'a;
 
+  fn cut(self) -> Self::P<'a, Self::Input, Self::Output>
+  where
+    Self::Output: Debug + 'a;
+
   fn scan_right1<BOP>(self, op: Self::P<'a, Self::Input, BOP>) -> Self::P<'a, Self::Input, Self::Output>
   where
     BOP: Fn(Self::Output, Self::Output) -> Self::Output + 'a + Clone,
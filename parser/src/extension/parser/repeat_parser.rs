@@ -73,4 +73,74 @@ pub trait RepeatParser<'a>: OperatorParser<'a> {
     Self::Input: Clone + 'a,
     Self::Output: Clone + Debug + 'a,
     B: Clone + Debug + 'a;
+
+  /// Repeats zero or more times separated by `separator`, allowing a trailing separator.<br/>
+  /// 区切り文字で区切られた0回以上の繰り返しを解析する。末尾の区切り文字を許容する。
+  ///
+  /// A committed failure of the item or the separator is propagated as a committed failure.
+  fn of_many0_sep_trailing<B>(
+    self,
+    separator: Self::P<'a, Self::Input, B>,
+  ) -> Self::P<'a, Self::Input, Vec<Self::Output>>
+  where
+    Self::Input: Clone + 'a,
+    Self::Output: Clone + Debug + 'a,
+    B: Clone + Debug + 'a;
+
+  /// Repeats one or more times separated by `separator`, allowing a trailing separator.<br/>
+  /// 区切り文字で区切られた1回以上の繰り返しを解析する。末尾の区切り文字を許容する。
+  ///
+  /// A committed failure of the item or the separator is propagated as a committed failure.
+  fn of_sep_end_by1<B>(self, separator: Self::P<'a, Self::Input, B>) -> Self::P<'a, Self::Input, Vec<Self::Output>>
+  where
+    Self::Input: Clone + 'a,
+    Self::Output: Clone + Debug + 'a,
+    B: Clone + Debug + 'a;
+
+  /// Repeats until `end` succeeds, returning the items and the result of `end`.<br/>
+  /// `end`が成功するまで繰り返し、要素と`end`の結果を返す。
+  ///
+  /// `end` is tried before each item. If the item fails after input has been consumed, the failure is committed.
+  fn of_many_till<B>(self, end: Self::P<'a, Self::Input, B>) -> Self::P<'a, Self::Input, (Vec<Self::Output>, B)>
+  where
+    Self::Input: Clone + 'a,
+    Self::Output: Clone + Debug + 'a,
+    B: Clone + Debug + 'a;
+
+  /// Repeats zero or more times, folding the items into an accumulator without building a [Vec].<br/>
+  /// 0回以上の繰り返しを[Vec]を作らずに畳み込む。
+  ///
+  /// As with [RepeatParser::of_many0], any failure of the item ends the repetition.
+  fn of_fold_many0<B, F>(self, init: B, f: F) -> Self::P<'a, Self::Input, B>
+  where
+    F: Fn(B, Self::Output) -> B + 'a,
+    Self::Input: Clone + 'a,
+    Self::Output: Clone + Debug + 'a,
+    B: Clone + Debug + 'a;
+
+  /// Repeats within `range` times, folding the items into an accumulator without building a [Vec].<br/>
+  /// 指定範囲の回数だけ繰り返し、[Vec]を作らずに畳み込む。
+  ///
+  /// As with [RepeatParser::of_many0], any failure of the item ends the repetition.
+  fn of_fold_many<C, R, F>(self, range: R, init: C, f: F) -> Self::P<'a, Self::Input, C>
+  where
+    R: RangeArgument<usize> + Debug + 'a,
+    F: Fn(C, Self::Output) -> C + 'a,
+    Self::Input: Clone + 'a,
+    Self::Output: Clone + Debug + 'a,
+    C: Clone + Debug + 'a;
+
+  /// Skips zero or more repetitions without allocating.<br/>
+  /// 0回以上の繰り返しをアロケーションせずに読み飛ばす。
+  fn of_skip_many0(self) -> Self::P<'a, Self::Input, ()>
+  where
+    Self::Input: Clone + 'a,
+    Self::Output: Clone + Debug + 'a;
+
+  /// Skips one or more repetitions without allocating.<br/>
+  /// 1回以上の繰り返しをアロケーションせずに読み飛ばす。
+  fn of_skip_many1(self) -> Self::P<'a, Self::Input, ()>
+  where
+    Self::Input: Clone + 'a,
+    Self::Output: Clone + Debug + 'a;
 }
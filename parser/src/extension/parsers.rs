@@ -2,6 +2,7 @@
 pub use cache_parsers::*;
 pub use collect_parsers::*;
 pub use conversion_parsers::*;
+pub use cst_parsers::*;
 pub use discard_parsers::*;
 pub use element_parsers::*;
 pub use elements_parsers::*;
@@ -10,6 +11,7 @@ pub use logging_parsers::*;
 pub use offset_parsers::*;
 pub use operator_parsers::*;
 pub use peek_parsers::*;
+pub use permutation_parsers::*;
 pub use primitive_parsers::*;
 pub use repeat_parsers::*;
 pub use skip_parsers::*;
@@ -17,6 +19,7 @@ pub use taken_parsers::*;
 
 mod collect_parsers;
 mod conversion_parsers;
+mod cst_parsers;
 mod discard_parsers;
 mod lazy_parsers;
 mod offset_parsers;
@@ -29,5 +32,6 @@ mod element_parsers;
 mod elements_parsers;
 mod logging_parsers;
 mod peek_parsers;
+mod permutation_parsers;
 mod primitive_parsers;
 mod taken_parsers;
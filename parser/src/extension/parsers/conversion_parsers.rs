@@ -1,3 +1,4 @@
+use std::error::Error;
 use std::fmt::Debug;
 use std::str::FromStr;
 
@@ -11,6 +12,13 @@ pub trait ConversionParsers: Parsers {
     A: Debug + 'a,
     B: Debug + 'a;
 
+  fn map_res_with_cause<'a, I, A, B, E, F>(parser: Self::P<'a, I, A>, f: F) -> Self::P<'a, I, B>
+  where
+    F: Fn(A) -> Result<B, E> + 'a,
+    E: Error + Send + Sync + 'static,
+    A: Debug + 'a,
+    B: Debug + 'a;
+
   fn map_opt<'a, I, A, B, E, F>(parser: Self::P<'a, I, A>, f: F) -> Self::P<'a, I, B>
   where
     F: Fn(A) -> Option<B> + 'a,
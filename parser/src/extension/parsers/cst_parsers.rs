@@ -0,0 +1,11 @@
+use crate::core::{Parsers, SyntaxKind};
+
+pub trait CstParsers: Parsers {
+  fn node<'a, I, A>(kind: SyntaxKind, parser: Self::P<'a, I, A>) -> Self::P<'a, I, A>
+  where
+    A: 'a;
+
+  fn token<'a, I, A>(kind: SyntaxKind, parser: Self::P<'a, I, A>) -> Self::P<'a, I, A>
+  where
+    A: 'a;
+}
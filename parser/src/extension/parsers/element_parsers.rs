@@ -125,7 +125,9 @@ pub trait ElementParsers: Parsers {
   fn elm_ref_of<'a, I, S>(set: &'a S) -> Self::P<'a, I, &'a I>
   where
     I: Element,
-    S: Set<I> + ?Sized + 'a;
+    S: Set<I> + ?Sized + 'a, {
+    Self::elm_ref_of_set(set)
+  }
 
   fn elm_of<'a, I, S>(set: &'a S) -> Self::P<'a, I, I>
   where
@@ -157,7 +159,9 @@ pub trait ElementParsers: Parsers {
   fn none_ref_of<'a, I, S>(set: &'a S) -> Self::P<'a, I, &'a I>
   where
     I: Element,
-    S: Set<I> + ?Sized + 'a;
+    S: Set<I> + ?Sized + 'a, {
+    Self::none_ref_of_set(set)
+  }
 
   fn none_of<'a, I, S>(set: &'a S) -> Self::P<'a, I, I>
   where
@@ -165,4 +169,32 @@ pub trait ElementParsers: Parsers {
     S: Set<I> + ?Sized + 'a, {
     Self::map(Self::none_ref_of(set), Clone::clone)
   }
+
+  fn elm_ref_of_set<'a, I, S>(set: S) -> Self::P<'a, I, &'a I>
+  where
+    I: Element,
+    S: Set<I> + 'a;
+
+  fn elm_of_set<'a, I, S>(set: S) -> Self::P<'a, I, I>
+  where
+    I: Element,
+    S: Set<I> + 'a, {
+    Self::map(Self::elm_ref_of_set(set), Clone::clone)
+  }
+
+  fn none_ref_of_set<'a, I, S>(set: S) -> Self::P<'a, I, &'a I>
+  where
+    I: Element,
+    S: Set<I> + 'a;
+
+  fn none_of_set<'a, I, S>(set: S) -> Self::P<'a, I, I>
+  where
+    I: Element,
+    S: Set<I> + 'a, {
+    Self::map(Self::none_ref_of_set(set), Clone::clone)
+  }
+
+  fn utf8_char_of<'a, S>(set: S) -> Self::P<'a, u8, char>
+  where
+    S: Set<char> + 'a;
 }
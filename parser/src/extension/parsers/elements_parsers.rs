@@ -16,4 +16,6 @@ pub trait ElementsParsers: Parsers {
     'b: 'a;
 
   fn regex<'a>(pattern: &str) -> Self::P<'a, char, String>;
+
+  fn try_regex<'a>(pattern: &str) -> Result<Self::P<'a, char, String>, regex::Error>;
 }
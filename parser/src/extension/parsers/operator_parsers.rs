@@ -30,6 +30,10 @@ pub trait OperatorParsers: Parsers {
   where
     A: Debug + 'a;
 
+  fn cut<'a, I, A>(parser: Self::P<'a, I, A>) -> Self::P<'a, I, A>
+  where
+    A: Debug + 'a;
+
   fn chain_right1<'a, I, A, BOP>(p: Self::P<'a, I, A>, op: Self::P<'a, I, BOP>) -> Self::P<'a, I, A>
   where
     BOP: Fn(A, A) -> A + 'a,
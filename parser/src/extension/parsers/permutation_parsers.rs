@@ -0,0 +1,17 @@
+use crate::core::{ParseResult, ParseState, Parsers};
+
+/// A tuple of parsers that can be parsed in any order by `permutation`.<br/>
+/// `permutation`で任意の順序で解析できるパーサーのタプル。
+pub trait PermutationMembers<'a, I> {
+  type Output;
+
+  fn parse_permutation(&self, parse_state: &ParseState<'a, I>) -> ParseResult<'a, I, Self::Output>;
+}
+
+pub trait PermutationParsers: Parsers {
+  fn permutation<'a, I, M>(members: M) -> Self::P<'a, I, M::Output>
+  where
+    M: PermutationMembers<'a, I> + 'a,
+    I: 'a,
+    M::Output: 'a;
+}
@@ -46,4 +46,40 @@ pub trait RepeatParsers: Parsers {
     I: Clone + 'a,
     A: Clone + Debug + 'a,
     B: Clone + Debug + 'a;
+
+  fn fold_many<'a, I, A, C, R, F>(parser: Self::P<'a, I, A>, range: R, init: C, f: F) -> Self::P<'a, I, C>
+  where
+    R: RangeArgument<usize> + Debug + 'a,
+    F: Fn(C, A) -> C + 'a,
+    I: Clone + 'a,
+    A: Clone + Debug + 'a,
+    C: Clone + Debug + 'a;
+
+  fn skip_many0<'a, I, A>(parser: Self::P<'a, I, A>) -> Self::P<'a, I, ()>
+  where
+    I: Clone + 'a,
+    A: Clone + Debug + 'a;
+
+  fn skip_many1<'a, I, A>(parser: Self::P<'a, I, A>) -> Self::P<'a, I, ()>
+  where
+    I: Clone + 'a,
+    A: Clone + Debug + 'a;
+
+  fn many0_sep_trailing<'a, I, A, B>(parser: Self::P<'a, I, A>, sep: Self::P<'a, I, B>) -> Self::P<'a, I, Vec<A>>
+  where
+    I: Clone + 'a,
+    A: Clone + Debug + 'a,
+    B: Clone + Debug + 'a;
+
+  fn sep_end_by1<'a, I, A, B>(parser: Self::P<'a, I, A>, sep: Self::P<'a, I, B>) -> Self::P<'a, I, Vec<A>>
+  where
+    I: Clone + 'a,
+    A: Clone + Debug + 'a,
+    B: Clone + Debug + 'a;
+
+  fn many_till<'a, I, A, B>(parser: Self::P<'a, I, A>, end: Self::P<'a, I, B>) -> Self::P<'a, I, (Vec<A>, B)>
+  where
+    I: Clone + 'a,
+    A: Clone + Debug + 'a,
+    B: Clone + Debug + 'a;
 }
@@ -1,5 +1,6 @@
 use crate::core::Element;
 use crate::extension::parsers::element_parsers::ElementParsers;
+use crate::utils::ByteSet;
 use std::fmt::Debug;
 
 pub trait TakenParsers: ElementParsers {
@@ -29,4 +30,14 @@ pub trait TakenParsers: ElementParsers {
   where
     F: Fn(&I) -> bool + 'a,
     I: Element + Debug + 'a;
+
+  fn take_until_byte<'a>(byte: u8) -> Self::P<'a, u8, &'a [u8]>;
+
+  fn take_until_any<'a>(bytes: &[u8]) -> Self::P<'a, u8, &'a [u8]>;
+
+  fn take_until_seq<'a>(seq: &[u8]) -> Self::P<'a, u8, &'a [u8]>;
+
+  fn take_while_in<'a>(set: &[u8]) -> Self::P<'a, u8, &'a [u8]>;
+
+  fn take_while_in_set<'a>(set: ByteSet) -> Self::P<'a, u8, &'a [u8]>;
 }
@@ -9,6 +9,7 @@ where
 {
   type Output = Self;
 
+  #[track_caller]
   fn bitor(self, rhs: Parser<'a, I, A>) -> Self::Output {
     self.or(rhs)
   }
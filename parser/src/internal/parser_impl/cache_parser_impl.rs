@@ -12,4 +12,10 @@ impl<'a, I, A> CacheParser<'a> for Parser<'a, I, A> {
     Self::Output: Clone + Debug + 'a, {
     ParsersImpl::cache(self)
   }
+
+  fn memo(self) -> Self::P<'a, Self::Input, Self::Output>
+  where
+    Self::Output: Clone + Debug + 'static, {
+    ParsersImpl::memo(self)
+  }
 }
@@ -2,6 +2,7 @@ use crate::core::Parser;
 use crate::extension::parser::ConversionParser;
 use crate::extension::parsers::ConversionParsers;
 use crate::internal::ParsersImpl;
+use std::error::Error;
 use std::fmt::Debug;
 
 impl<'a, I, A> ConversionParser<'a> for Parser<'a, I, A> {
@@ -13,4 +14,13 @@ impl<'a, I, A> ConversionParser<'a> for Parser<'a, I, A> {
     B: Debug + 'a, {
     ParsersImpl::map_res(self, f)
   }
+
+  fn map_res_with_cause<B, E, F>(self, f: F) -> Self::P<'a, Self::Input, B>
+  where
+    F: Fn(Self::Output) -> Result<B, E> + 'a,
+    E: Error + Send + Sync + 'static,
+    Self::Output: Debug + 'a,
+    B: Debug + 'a, {
+    ParsersImpl::map_res_with_cause(self, f)
+  }
 }
@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use std::panic::Location;
 
 use crate::core::Parser;
 use crate::extension::parser::OperatorParser;
@@ -13,10 +14,11 @@ impl<'a, I, A> OperatorParser<'a> for Parser<'a, I, A> {
     ParsersImpl::and_then(self, pb)
   }
 
+  #[track_caller]
   fn or(self, pb: Self::P<'a, Self::Input, Self::Output>) -> Self::P<'a, Self::Input, Self::Output>
   where
     Self::Output: Debug + 'a, {
-    ParsersImpl::or(self, pb)
+    ParsersImpl::or_at(self, pb, Some(Location::caller()))
   }
 
   fn exists(self) -> Self::P<'a, Self::Input, bool>
@@ -37,10 +39,17 @@ impl<'a, I, A> OperatorParser<'a> for Parser<'a, I, A> {
     ParsersImpl::opt(self)
   }
 
+  #[track_caller]
   fn attempt(self) -> Self::P<'a, Self::Input, Self::Output>
   where
     Self::Output: Debug + 'a, {
-    ParsersImpl::attempt(self)
+    ParsersImpl::attempt_at(self, Some(Location::caller()))
+  }
+
+  fn cut(self) -> Self::P<'a, Self::Input, Self::Output>
+  where
+    Self::Output: Debug + 'a, {
+    ParsersImpl::cut(self)
   }
 
   fn scan_right1<BOP>(self, op: Self::P<'a, Self::Input, BOP>) -> Self::P<'a, Self::Input, Self::Output>
@@ -1,4 +1,4 @@
-use crate::core::{ParseResult, ParseState, Parser, ParserRunner};
+use crate::core::{ParseIter, ParseResult, ParseState, Parser, ParserRunner};
 
 impl<'a, I, A> ParserRunner<'a> for Parser<'a, I, A> {
   type Input = I;
@@ -13,6 +13,10 @@ impl<'a, I, A> ParserRunner<'a> for Parser<'a, I, A> {
     self.run(&parse_state)
   }
 
+  fn parse_iter(&self, input: &'a [Self::Input]) -> ParseIter<'a, Self::Input, Self::Output> {
+    ParseIter::new(self.clone(), input)
+  }
+
   fn run(&self, param: &ParseState<'a, Self::Input>) -> ParseResult<'a, Self::Input, Self::Output> {
     (self.method)(param)
   }
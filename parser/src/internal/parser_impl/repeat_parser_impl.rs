@@ -96,4 +96,64 @@ impl<'a, I, A> RepeatParser<'a> for Parser<'a, I, A> {
     B: Clone + Debug + 'a, {
     ParsersImpl::repeat_sep(self, n..=n, Some(separator))
   }
+
+  fn of_many0_sep_trailing<B>(
+    self,
+    separator: Self::P<'a, Self::Input, B>,
+  ) -> Self::P<'a, Self::Input, Vec<Self::Output>>
+  where
+    Self::Input: Clone + 'a,
+    Self::Output: Clone + Debug + 'a,
+    B: Clone + Debug + 'a, {
+    ParsersImpl::many0_sep_trailing(self, separator)
+  }
+
+  fn of_sep_end_by1<B>(self, separator: Self::P<'a, Self::Input, B>) -> Self::P<'a, Self::Input, Vec<Self::Output>>
+  where
+    Self::Input: Clone + 'a,
+    Self::Output: Clone + Debug + 'a,
+    B: Clone + Debug + 'a, {
+    ParsersImpl::sep_end_by1(self, separator)
+  }
+
+  fn of_many_till<B>(self, end: Self::P<'a, Self::Input, B>) -> Self::P<'a, Self::Input, (Vec<Self::Output>, B)>
+  where
+    Self::Input: Clone + 'a,
+    Self::Output: Clone + Debug + 'a,
+    B: Clone + Debug + 'a, {
+    ParsersImpl::many_till(self, end)
+  }
+
+  fn of_fold_many0<B, F>(self, init: B, f: F) -> Self::P<'a, Self::Input, B>
+  where
+    F: Fn(B, Self::Output) -> B + 'a,
+    Self::Input: Clone + 'a,
+    Self::Output: Clone + Debug + 'a,
+    B: Clone + Debug + 'a, {
+    ParsersImpl::fold_many(self, 0.., init, f)
+  }
+
+  fn of_fold_many<C, R, F>(self, range: R, init: C, f: F) -> Self::P<'a, Self::Input, C>
+  where
+    R: RangeArgument<usize> + Debug + 'a,
+    F: Fn(C, Self::Output) -> C + 'a,
+    Self::Input: Clone + 'a,
+    Self::Output: Clone + Debug + 'a,
+    C: Clone + Debug + 'a, {
+    ParsersImpl::fold_many(self, range, init, f)
+  }
+
+  fn of_skip_many0(self) -> Self::P<'a, Self::Input, ()>
+  where
+    Self::Input: Clone + 'a,
+    Self::Output: Clone + Debug + 'a, {
+    ParsersImpl::skip_many0(self)
+  }
+
+  fn of_skip_many1(self) -> Self::P<'a, Self::Input, ()>
+  where
+    Self::Input: Clone + 'a,
+    Self::Output: Clone + Debug + 'a, {
+    ParsersImpl::skip_many1(self)
+  }
 }
@@ -5,6 +5,7 @@ pub struct ParsersImpl;
 mod cache_parsers_impl;
 mod collect_parsers_impl;
 mod conversion_parsers_impl;
+mod cst_parsers_impl;
 mod discard_parsers_impl;
 mod element_parsers_impl;
 mod elements_parsers_impl;
@@ -13,6 +14,7 @@ mod logging_parsers_impl;
 mod offset_parsers_impl;
 mod operator_parsers_impl;
 mod peek_parsers_impl;
+mod permutation_parsers_impl;
 mod primitive_parsers_impl;
 mod repeat_parsers_impl;
 mod skip_parser_impl;
@@ -3,7 +3,8 @@ use crate::extension::parsers::CacheParsers;
 use crate::internal::ParsersImpl;
 use std::cell::RefCell;
 
-use crate::core::ParseResult;
+use crate::core::next_memo_id;
+use crate::core::{ParseResult, ParserRunner};
 use fnv::FnvHashMap;
 use std::fmt::Debug;
 use std::ptr;
@@ -36,3 +37,31 @@ impl CacheParsers for ParsersImpl {
     })
   }
 }
+
+impl ParsersImpl {
+  /// Memoizes the successful results of the parser in the memo table of the session, keyed by the id allocated
+  /// when the parser is built by the parser factory of the session.
+  pub(crate) fn memo<'a, I, A>(parser: Parser<'a, I, A>) -> Parser<'a, I, A>
+  where
+    A: Clone + Debug + 'static, {
+    let id = match next_memo_id() {
+      Some(id) => id,
+      None => return parser,
+    };
+    Parser::new(move |parse_state| {
+      let memo = match parse_state.memo_table() {
+        Some(memo) => memo,
+        None => return parser.run(parse_state),
+      };
+      let offset = parse_state.current_offset();
+      if let Some((value, length)) = memo.get::<A>(id, offset) {
+        return ParseResult::successful(value, length);
+      }
+      let result = parser.run(parse_state);
+      if let ParseResult::Success { value, length } = &result {
+        memo.insert(id, offset, value.clone(), *length);
+      }
+      result
+    })
+  }
+}
@@ -1,6 +1,7 @@
-use crate::core::{ParseError, ParseResult, Parser};
+use crate::core::{ErrorCause, ParseError, ParseResult, Parser};
 use crate::extension::parsers::ConversionParsers;
 use crate::internal::ParsersImpl;
+use std::error::Error;
 use std::fmt::Debug;
 
 impl ConversionParsers for ParsersImpl {
@@ -11,22 +12,17 @@ impl ConversionParsers for ParsersImpl {
     E: Debug,
     A: 'a,
     B: 'a, {
-    let method = parser.method.clone();
-    Parser::new(move |parse_state| match method(parse_state) {
-      ParseResult::Success { value: a, length } => match f(a) {
-        Ok(value) => ParseResult::successful(value, length),
-        Err(err) => {
-          let msg = format!("Conversion error: {:?}", err);
-          let parser_error =
-            ParseError::of_conversion(parse_state.input(), parse_state.last_offset().unwrap_or(0), 0, msg);
-          ParseResult::failed_with_uncommitted(parser_error)
-        }
-      },
-      ParseResult::Failure {
-        error,
-        committed_status,
-      } => ParseResult::failed(error, committed_status),
-    })
+    convert(parser, f, |_| None)
+  }
+
+  #[inline]
+  fn map_res_with_cause<'a, I, A, B, E, F>(parser: Self::P<'a, I, A>, f: F) -> Self::P<'a, I, B>
+  where
+    F: Fn(A) -> Result<B, E> + 'a,
+    E: Error + Send + Sync + 'static,
+    A: 'a,
+    B: 'a, {
+    convert(parser, f, |err| Some(ErrorCause::new(err)))
   }
 
   fn map_opt<'a, I, A, B, E, F>(parser: Self::P<'a, I, A>, f: F) -> Self::P<'a, I, B>
@@ -40,9 +36,9 @@ impl ConversionParsers for ParsersImpl {
         Some(value) => ParseResult::successful(value, length),
         None => {
           let parser_error = ParseError::of_conversion(
-            parse_state.input(),
-            parse_state.last_offset().unwrap_or(0),
-            0,
+            parse_state.whole_input(),
+            parse_state.current_offset(),
+            length,
             "Conversion error".to_string(),
           );
           ParseResult::failed_with_uncommitted(parser_error)
@@ -55,3 +51,33 @@ impl ConversionParsers for ParsersImpl {
     })
   }
 }
+
+/// Converts the value of `parser` with `f`, turning its error into a [ParseError::Conversion] with the given cause.
+fn convert<'a, I, A, B, E, F, C>(parser: Parser<'a, I, A>, f: F, cause: C) -> Parser<'a, I, B>
+where
+  F: Fn(A) -> Result<B, E> + 'a,
+  C: Fn(E) -> Option<ErrorCause> + 'a,
+  E: Debug,
+  A: 'a,
+  B: 'a, {
+  let method = parser.method.clone();
+  Parser::new(move |parse_state| match method(parse_state) {
+    ParseResult::Success { value: a, length } => match f(a) {
+      Ok(value) => ParseResult::successful(value, length),
+      Err(err) => {
+        let input = parse_state.whole_input();
+        let offset = parse_state.current_offset();
+        let msg = format!("Conversion error: {:?}", err);
+        let parser_error = match cause(err) {
+          Some(cause) => ParseError::of_conversion_with_cause(input, offset, length, msg, cause),
+          None => ParseError::of_conversion(input, offset, length, msg),
+        };
+        ParseResult::failed_with_uncommitted(parser_error)
+      }
+    },
+    ParseResult::Failure {
+      error,
+      committed_status,
+    } => ParseResult::failed(error, committed_status),
+  })
+}
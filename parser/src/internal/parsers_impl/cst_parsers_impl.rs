@@ -0,0 +1,44 @@
+use crate::core::{ParseResult, Parser, ParserRunner, SyntaxKind};
+use crate::extension::parsers::CstParsers;
+use crate::internal::ParsersImpl;
+
+impl CstParsers for ParsersImpl {
+  fn node<'a, I, A>(kind: SyntaxKind, parser: Self::P<'a, I, A>) -> Self::P<'a, I, A>
+  where
+    A: 'a, {
+    Parser::new(move |parse_state| match parse_state.cst_recorder() {
+      None => parser.run(parse_state),
+      Some(recorder) => {
+        let checkpoint = recorder.checkpoint();
+        let result = parser.run(parse_state);
+        match &result {
+          ParseResult::Success { length, .. } => {
+            let start = parse_state.current_offset();
+            recorder.node(kind, checkpoint, start, start + length);
+          }
+          ParseResult::Failure { .. } => recorder.rollback(checkpoint),
+        }
+        result
+      }
+    })
+  }
+
+  fn token<'a, I, A>(kind: SyntaxKind, parser: Self::P<'a, I, A>) -> Self::P<'a, I, A>
+  where
+    A: 'a, {
+    Parser::new(move |parse_state| match parse_state.cst_recorder() {
+      None => parser.run(parse_state),
+      Some(recorder) => {
+        let checkpoint = recorder.checkpoint();
+        let result = parser.run(parse_state);
+        // A token is a leaf, the nodes and tokens inside it are dropped.
+        recorder.rollback(checkpoint);
+        if let ParseResult::Success { length, .. } = &result {
+          let start = parse_state.current_offset();
+          recorder.token(kind, start, start + length);
+        }
+        result
+      }
+    })
+  }
+}
@@ -10,14 +10,14 @@ impl ElementParsers for ParsersImpl {
     F: Fn(&I) -> bool + 'a, {
     Parser::new(move |parse_state| {
       let input = parse_state.input();
-      if let Some(actual) = input.first() {
-        if f(actual) {
-          return ParseResult::successful(actual, 1);
-        }
+      match input.first() {
+        Some(actual) if f(actual) => return ParseResult::successful(actual, 1),
+        Some(_) => (),
+        None => parse_state.reach_end(),
       }
       let offset = parse_state.current_offset();
       let msg = format!("offset: {}", offset);
-      let pe = ParseError::of_mismatch(input, offset + 1, 1, msg);
+      let pe = ParseError::of_mismatch(parse_state.whole_input(), offset, 1, msg);
       ParseResult::failed_with_uncommitted(pe)
     })
   }
@@ -64,10 +64,10 @@ impl ElementParsers for ParsersImpl {
     Self::elm_pred_ref(Element::is_ascii_oct_digit)
   }
 
-  fn elm_ref_of<'a, I, S>(set: &'a S) -> Self::P<'a, I, &'a I>
+  fn elm_ref_of_set<'a, I, S>(set: S) -> Self::P<'a, I, &'a I>
   where
     I: Element,
-    S: Set<I> + ?Sized + 'a, {
+    S: Set<I> + 'a, {
     Parser::new(move |parse_state| {
       let input = parse_state.input();
       if let Some(s) = input.first() {
@@ -75,10 +75,11 @@ impl ElementParsers for ParsersImpl {
           ParseResult::successful(s, 1)
         } else {
           let msg = format!("expect one of: {}, found: {}", set.to_str(), s);
-          let pe = ParseError::of_mismatch(input, parse_state.current_offset() + 1, 1, msg);
+          let pe = ParseError::of_mismatch(parse_state.whole_input(), parse_state.current_offset(), 1, msg);
           ParseResult::failed_with_uncommitted(pe)
         }
       } else {
+        parse_state.reach_end();
         ParseResult::failed_with_uncommitted(ParseError::of_in_complete())
       }
     })
@@ -95,10 +96,11 @@ impl ElementParsers for ParsersImpl {
           ParseResult::successful(s, 1)
         } else {
           let msg = format!("expect elm of: {}, found: {}", set.to_str(), s);
-          let pe = ParseError::of_mismatch(input, parse_state.current_offset() + 1, 1, msg);
+          let pe = ParseError::of_mismatch(parse_state.whole_input(), parse_state.current_offset(), 1, msg);
           ParseResult::failed_with_uncommitted(pe)
         }
       } else {
+        parse_state.reach_end();
         ParseResult::failed_with_uncommitted(ParseError::of_in_complete())
       }
     })
@@ -115,19 +117,20 @@ impl ElementParsers for ParsersImpl {
           ParseResult::successful(s, 1)
         } else {
           let msg = format!("expect elm of: {}, found: {}", set.to_str(), s);
-          let pe = ParseError::of_mismatch(input, parse_state.current_offset() + 1, 1, msg);
+          let pe = ParseError::of_mismatch(parse_state.whole_input(), parse_state.current_offset(), 1, msg);
           ParseResult::failed_with_uncommitted(pe)
         }
       } else {
+        parse_state.reach_end();
         ParseResult::failed_with_uncommitted(ParseError::of_in_complete())
       }
     })
   }
 
-  fn none_ref_of<'a, I, S>(set: &'a S) -> Self::P<'a, I, &'a I>
+  fn none_ref_of_set<'a, I, S>(set: S) -> Self::P<'a, I, &'a I>
   where
     I: Element,
-    S: Set<I> + ?Sized + 'a, {
+    S: Set<I> + 'a, {
     Parser::new(move |parse_state| {
       let input = parse_state.input();
       if let Some(s) = input.first() {
@@ -135,12 +138,51 @@ impl ElementParsers for ParsersImpl {
           ParseResult::successful(s, 1)
         } else {
           let msg = format!("expect none of: {}, found: {}", set.to_str(), s);
-          let pe = ParseError::of_mismatch(input, parse_state.current_offset() + 1, 1, msg);
+          let pe = ParseError::of_mismatch(parse_state.whole_input(), parse_state.current_offset(), 1, msg);
           ParseResult::failed_with_uncommitted(pe)
         }
       } else {
+        parse_state.reach_end();
         ParseResult::failed_with_uncommitted(ParseError::of_in_complete())
       }
     })
   }
+
+  fn utf8_char_of<'a, S>(set: S) -> Self::P<'a, u8, char>
+  where
+    S: Set<char> + 'a, {
+    Parser::new(move |parse_state| {
+      let input = parse_state.input();
+      match decode_utf8(input) {
+        Some((c, length)) if set.contains(&c) => ParseResult::successful(c, length),
+        Some((c, length)) => {
+          let msg = format!("expect one of: {}, found: {}", set.to_str(), c);
+          let pe = ParseError::of_mismatch(parse_state.whole_input(), parse_state.current_offset(), length, msg);
+          ParseResult::failed_with_uncommitted(pe)
+        }
+        None if input.is_empty() => {
+          parse_state.reach_end();
+          ParseResult::failed_with_uncommitted(ParseError::of_in_complete())
+        }
+        None => {
+          let msg = format!("expect a UTF-8 character, found: {:?}", &input[..input.len().min(4)]);
+          let pe = ParseError::of_mismatch(parse_state.whole_input(), parse_state.current_offset(), 1, msg);
+          ParseResult::failed_with_uncommitted(pe)
+        }
+      }
+    })
+  }
+}
+
+/// Decodes the first character of the UTF-8 input, returning it with its length in bytes.
+fn decode_utf8(input: &[u8]) -> Option<(char, usize)> {
+  let length = match *input.first()? {
+    0x00..=0x7F => 1,
+    0xC0..=0xDF => 2,
+    0xE0..=0xEF => 3,
+    0xF0..=0xF7 => 4,
+    _ => return None,
+  };
+  let s = std::str::from_utf8(input.get(..length)?).ok()?;
+  s.chars().next().map(|c| (c, length))
 }
@@ -22,10 +22,11 @@ impl ElementsParsers for ParsersImpl {
           if seq[index] != *str {
             let msg = format!("seq {:?} expect: {:?}, found: {:?}", seq, seq[index], str);
             let ps = parse_state.advance_by(index);
-            let pe = ParseError::of_mismatch(input, ps.current_offset(), index, msg);
+            let pe = ParseError::of_mismatch(ps.whole_input(), ps.current_offset(), 1, msg);
             return ParseResult::failed(pe, (index != 0).into());
           }
         } else {
+          parse_state.reach_end();
           return ParseResult::failed_with_uncommitted(ParseError::of_in_complete());
         }
         index += 1;
@@ -45,10 +46,11 @@ impl ElementsParsers for ParsersImpl {
           if c != actual {
             let msg = format!("tag {:?} expect: {:?}, found: {}", tag, c, actual);
             let ps = parse_state.advance_by(index);
-            let pe = ParseError::of_mismatch(input, ps.current_offset(), index, msg);
+            let pe = ParseError::of_mismatch(ps.whole_input(), ps.current_offset(), 1, msg);
             return ParseResult::failed(pe, (index != 0).into());
           }
         } else {
+          parse_state.reach_end();
           return ParseResult::failed_with_uncommitted(ParseError::of_in_complete());
         }
         index += 1;
@@ -68,10 +70,11 @@ impl ElementsParsers for ParsersImpl {
           if !c.eq_ignore_ascii_case(actual) {
             let msg = format!("tag_no_case {:?} expect: {:?}, found: {}", tag, c, actual);
             let ps = parse_state.advance_by(index);
-            let pe = ParseError::of_mismatch(input, ps.current_offset(), index, msg);
+            let pe = ParseError::of_mismatch(ps.whole_input(), ps.current_offset(), 1, msg);
             return ParseResult::failed(pe, (index != 0).into());
           }
         } else {
+          parse_state.reach_end();
           return ParseResult::failed_with_uncommitted(ParseError::of_in_complete());
         }
         index += 1;
@@ -81,28 +84,44 @@ impl ElementsParsers for ParsersImpl {
   }
 
   fn regex<'a>(pattern: &str) -> Self::P<'a, char, String> {
+    match Self::try_regex(pattern) {
+      Ok(parser) => parser,
+      Err(error) => panic!("invalid regex {:?}: {}", pattern, error),
+    }
+  }
+
+  fn try_regex<'a>(pattern: &str) -> Result<Self::P<'a, char, String>, regex::Error> {
     let pattern = if !pattern.starts_with("^") {
       format!("^{}", pattern)
     } else {
       pattern.to_string()
     };
-    let regex = Regex::new(&pattern).unwrap();
-    Parser::new(move |parse_state| {
+    let regex = Regex::new(&pattern)?;
+    Ok(Parser::new(move |parse_state| {
       let input: &[char] = parse_state.input();
       log::debug!("regex: input = {:?}", input);
       let str = String::from_iter(input);
       if let Some(captures) = regex.captures(&str).as_ref() {
         if let Some(m) = captures.get(0) {
+          if m.end() == str.len() {
+            parse_state.reach_end();
+          }
           let str = m.as_str();
-          ParseResult::successful(str.to_string(), str.len())
+          ParseResult::successful(str.to_string(), str.chars().count())
         } else {
           let msg = format!("regex {:?} found: {:?}", regex, str);
-          let pe = ParseError::of_mismatch(input, parse_state.current_offset(), str.len(), msg);
+          let pe = ParseError::of_mismatch(
+            parse_state.whole_input(),
+            parse_state.current_offset(),
+            input.len(),
+            msg,
+          );
           return ParseResult::failed(pe, (captures.len() != 0).into());
         }
       } else {
+        parse_state.reach_end();
         return ParseResult::failed_with_uncommitted(ParseError::of_in_complete());
       }
-    })
+    }))
   }
 }
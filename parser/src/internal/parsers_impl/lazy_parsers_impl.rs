@@ -1,4 +1,4 @@
-use crate::core::{Parser, ParserRunner};
+use crate::core::{ParseResult, Parser, ParserRunner};
 use crate::extension::parsers::LazyParsers;
 use crate::internal::ParsersImpl;
 use std::fmt::Debug;
@@ -10,8 +10,13 @@ impl LazyParsers for ParsersImpl {
     F: Fn() -> Self::P<'a, I, A> + 'a + Clone,
     A: Clone + Debug + 'a, {
     Parser::new(move |parse_state| {
+      if let Err(error) = parse_state.enter() {
+        return ParseResult::failed_with_commit(error);
+      }
       let parser = f();
-      parser.run(parse_state)
+      let result = parser.run(parse_state);
+      parse_state.leave();
+      result
     })
   }
 }
@@ -1,5 +1,6 @@
-use crate::core::{ParseError, ParseResult, ParserRunner, Parsers};
+use crate::core::{CommittedStatus, ParseError, ParseResult, ParserRunner, Parsers};
 use std::fmt::Debug;
+use std::panic::Location;
 
 use crate::core::Parser;
 use crate::extension::parsers::OperatorParsers;
@@ -10,10 +11,12 @@ impl OperatorParsers for ParsersImpl {
   fn exists<'a, I, A>(parser: Self::P<'a, I, A>) -> Self::P<'a, I, bool>
   where
     A: Debug + 'a, {
-    Parser::new(move |parse_state| match parser.run(parse_state) {
-      ParseResult::Success { .. } => ParseResult::successful(true, 0),
-      ParseResult::Failure { .. } => ParseResult::successful(false, 0),
-    })
+    Parser::new(
+      move |parse_state| match parse_state.lookahead(|| parser.run(parse_state)) {
+        ParseResult::Success { .. } => ParseResult::successful(true, 0),
+        ParseResult::Failure { .. } => ParseResult::successful(false, 0),
+      },
+    )
   }
 
   #[inline]
@@ -21,12 +24,11 @@ impl OperatorParsers for ParsersImpl {
   where
     A: 'a, {
     Parser::new(move |parse_state| match parser.run(parse_state) {
-      ParseResult::Success { .. } => {
-        let ps = parse_state.advance_by(0);
+      ParseResult::Success { length, .. } => {
         let parser_error = ParseError::of_mismatch(
-          ps.input(),
-          ps.last_offset().unwrap_or(0),
-          0,
+          parse_state.whole_input(),
+          parse_state.current_offset(),
+          length,
           "not predicate failed".to_string(),
         );
         ParseResult::failed_with_uncommitted(parser_error)
@@ -39,15 +41,7 @@ impl OperatorParsers for ParsersImpl {
   fn or<'a, I, A>(parser1: Self::P<'a, I, A>, parser2: Self::P<'a, I, A>) -> Self::P<'a, I, A>
   where
     A: 'a, {
-    Parser::new(move |parse_state| {
-      let result = parser1.run(parse_state);
-      if let Some(committed_status) = result.committed_status() {
-        if committed_status.is_uncommitted() {
-          return parser2.run(parse_state);
-        }
-      }
-      result
-    })
+    Self::or_at(parser1, parser2, None)
   }
 
   #[inline]
@@ -79,7 +73,14 @@ impl OperatorParsers for ParsersImpl {
   fn attempt<'a, I, A>(parser: Self::P<'a, I, A>) -> Self::P<'a, I, A>
   where
     A: Debug + 'a, {
-    Parser::new(move |parse_state| parser.run(parse_state).with_uncommitted())
+    Self::attempt_at(parser, None)
+  }
+
+  #[inline]
+  fn cut<'a, I, A>(parser: Self::P<'a, I, A>) -> Self::P<'a, I, A>
+  where
+    A: Debug + 'a, {
+    Parser::new(move |parse_state| parser.run(parse_state).add_commit(true))
   }
 
   #[inline]
@@ -152,3 +153,58 @@ impl OperatorParsers for ParsersImpl {
     )
   }
 }
+
+impl ParsersImpl {
+  /// [OperatorParsers::or] that reports alternatives shadowed by an earlier alternative in debug mode, if the
+  /// location of the `or` is given.
+  pub(crate) fn or_at<'a, I, A>(
+    parser1: Parser<'a, I, A>,
+    parser2: Parser<'a, I, A>,
+    location: Option<&'static Location<'static>>,
+  ) -> Parser<'a, I, A>
+  where
+    A: 'a, {
+    Parser::new(move |parse_state| {
+      let result = parse_state.backtrackable(|| parser1.run(parse_state));
+      match result.committed_status() {
+        None => {
+          if let (Some(location), Some(diagnostics), ParseResult::Success { length, .. }) =
+            (location, parse_state.diagnostics_collector(), &result)
+          {
+            if let ParseResult::Success { length: length2, .. } = parser2.run(&parse_state.detached()) {
+              if length2 > *length {
+                diagnostics.record_shadowed(location, parse_state.current_offset(), length2, *length);
+              }
+            }
+          }
+          result
+        }
+        Some(CommittedStatus::Uncommitted) => {
+          if let Err(error) = parse_state.step() {
+            return ParseResult::failed_with_commit(error);
+          }
+          parser2.run(parse_state)
+        }
+        Some(CommittedStatus::Committed) => result,
+      }
+    })
+  }
+
+  /// [OperatorParsers::attempt] that reports whether it changed the outcome in debug mode, if the location of the
+  /// `attempt` is given.
+  pub(crate) fn attempt_at<'a, I, A>(
+    parser: Parser<'a, I, A>,
+    location: Option<&'static Location<'static>>,
+  ) -> Parser<'a, I, A>
+  where
+    A: 'a, {
+    Parser::new(move |parse_state| {
+      let result = parse_state.backtrackable(|| parser.run(parse_state));
+      if let (Some(location), Some(diagnostics)) = (location, parse_state.diagnostics_collector()) {
+        let changed = matches!(result.committed_status(), Some(CommittedStatus::Committed));
+        diagnostics.record_attempt(location, changed);
+      }
+      result.with_uncommitted()
+    })
+  }
+}
@@ -9,7 +9,7 @@ impl PeekParsers for ParsersImpl {
   where
     A: Debug + 'a, {
     let method = parser.method.clone();
-    Parser::new(move |parse_state| match (method)(parse_state) {
+    Parser::new(move |parse_state| match parse_state.lookahead(|| method(parse_state)) {
       ParseResult::Success { value, .. } => ParseResult::successful(value, 0),
       ParseResult::Failure {
         error,
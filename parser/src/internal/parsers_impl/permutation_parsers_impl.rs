@@ -0,0 +1,98 @@
+use crate::core::{CommittedStatus, ParseError, ParseResult, ParseState, Parser, ParserRunner};
+use crate::extension::parsers::{PermutationMembers, PermutationParsers};
+use crate::internal::ParsersImpl;
+
+impl PermutationParsers for ParsersImpl {
+  #[inline]
+  fn permutation<'a, I, M>(members: M) -> Self::P<'a, I, M::Output>
+  where
+    M: PermutationMembers<'a, I> + 'a,
+    I: 'a,
+    M::Output: 'a, {
+    Parser::new(move |parse_state| members.parse_permutation(parse_state))
+  }
+}
+
+fn missing_members_error<'a, I>(
+  parse_state: &ParseState<'a, I>,
+  missing: Vec<(usize, ParseError<'a, I>)>,
+) -> ParseError<'a, I> {
+  let names = missing
+    .iter()
+    .map(|(index, error)| format!("#{} ({})", index, error))
+    .collect::<Vec<_>>()
+    .join(", ");
+  let inner = missing.into_iter().next().map(|(_, error)| Box::new(error));
+  ParseError::of_custom(
+    parse_state.current_offset(),
+    inner,
+    format!("permutation: missing required members: {}", names),
+  )
+}
+
+macro_rules! impl_permutation_members {
+  ($($t:ident $v:ident $idx:tt),+) => {
+    impl<'a, I, $($t),+> PermutationMembers<'a, I> for ($(Parser<'a, I, $t>,)+) {
+      type Output = ($($t,)+);
+
+      fn parse_permutation(&self, parse_state: &ParseState<'a, I>) -> ParseResult<'a, I, Self::Output> {
+        let mut values = ($(None::<$t>,)+);
+        let mut current_parse_state = parse_state.advance_by(0);
+        let mut all_length = 0;
+
+        // Each round tries every member that has not matched yet; a member is only accepted if it consumes input.
+        loop {
+          let mut progressed = false;
+          $(
+            if values.$idx.is_none() {
+              match self.$idx.run(&current_parse_state) {
+                ParseResult::Success { value, length } if length > 0 => {
+                  values.$idx = Some(value);
+                  current_parse_state = current_parse_state.advance_by(length);
+                  all_length += length;
+                  progressed = true;
+                }
+                ParseResult::Failure {
+                  error,
+                  committed_status: CommittedStatus::Committed,
+                } => return ParseResult::failed(error, CommittedStatus::Committed),
+                _ => (),
+              }
+            }
+          )+
+          if !progressed {
+            break;
+          }
+        }
+
+        // Members that never consumed input get one more chance to succeed without input (e.g. `opt()`).
+        let mut missing = vec![];
+        $(
+          if values.$idx.is_none() {
+            match self.$idx.run(&current_parse_state) {
+              ParseResult::Success { value, .. } => values.$idx = Some(value),
+              ParseResult::Failure { error, .. } => missing.push(($idx, error)),
+            }
+          }
+        )+
+        if !missing.is_empty() {
+          let pe = missing_members_error(&current_parse_state, missing);
+          return ParseResult::failed(pe, (all_length != 0).into());
+        }
+
+        match values {
+          ($(Some($v),)+) => ParseResult::successful(($($v,)+), all_length),
+          _ => unreachable!(),
+        }
+      }
+    }
+  };
+}
+
+impl_permutation_members!(A a 0, B b 1);
+impl_permutation_members!(A a 0, B b 1, C c 2);
+impl_permutation_members!(A a 0, B b 1, C c 2, D d 3);
+impl_permutation_members!(A a 0, B b 1, C c 2, D d 3, E e 4);
+impl_permutation_members!(A a 0, B b 1, C c 2, D d 3, E e 4, F f 5);
+impl_permutation_members!(A a 0, B b 1, C c 2, D d 3, E e 4, F f 5, G g 6);
+impl_permutation_members!(A a 0, B b 1, C c 2, D d 3, E e 4, F f 5, G g 6, H h 7);
@@ -12,10 +12,10 @@ impl PrimitiveParsers for ParsersImpl {
       let input = parse_state.input();
       if let Some(actual) = input.first() {
         let msg = format!("expect end of input, found: {}", actual);
-        let ps = parse_state.advance_by(1);
-        let pe = ParseError::of_mismatch(input, ps.current_offset(), 1, msg);
+        let pe = ParseError::of_mismatch(parse_state.whole_input(), parse_state.current_offset(), 1, msg);
         ParseResult::failed_with_uncommitted(pe)
       } else {
+        parse_state.reach_end();
         ParseResult::successful((), 0)
       }
     })
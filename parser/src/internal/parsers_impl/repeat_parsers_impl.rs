@@ -1,4 +1,4 @@
-use crate::core::{ParseError, ParseResult, Parser};
+use crate::core::{CommittedStatus, ParseError, ParseResult, Parser};
 use crate::extension::parsers::RepeatParsers;
 use crate::internal::ParsersImpl;
 use crate::utils::{Bound, RangeArgument};
@@ -64,70 +64,267 @@ impl RepeatParsers for ParsersImpl {
   ) -> Self::P<'a, I, Vec<A>>
   where
     R: RangeArgument<usize> + Debug + 'a,
+    I: Clone + 'a,
+    A: Clone + Debug + 'a,
+    B: Clone + Debug + 'a, {
+    fold_many_sep(parser, range, separator, Vec::new(), |mut items, value| {
+      items.push(value);
+      items
+    })
+  }
+
+  #[inline]
+  fn fold_many<'a, I, A, C, R, F>(parser: Self::P<'a, I, A>, range: R, init: C, f: F) -> Self::P<'a, I, C>
+  where
+    R: RangeArgument<usize> + Debug + 'a,
+    F: Fn(C, A) -> C + 'a,
+    I: Clone + 'a,
+    A: Clone + Debug + 'a,
+    C: Clone + Debug + 'a, {
+    fold_many_sep(parser, range, None as Option<Self::P<'a, I, ()>>, init, f)
+  }
+
+  #[inline]
+  fn skip_many0<'a, I, A>(parser: Self::P<'a, I, A>) -> Self::P<'a, I, ()>
+  where
+    I: Clone + 'a,
+    A: Clone + Debug + 'a, {
+    Self::fold_many(parser, 0.., (), |_, _| ())
+  }
+
+  #[inline]
+  fn skip_many1<'a, I, A>(parser: Self::P<'a, I, A>) -> Self::P<'a, I, ()>
+  where
+    I: Clone + 'a,
+    A: Clone + Debug + 'a, {
+    Self::fold_many(parser, 1.., (), |_, _| ())
+  }
+
+  #[inline]
+  fn many0_sep_trailing<'a, I, A, B>(parser: Self::P<'a, I, A>, sep: Self::P<'a, I, B>) -> Self::P<'a, I, Vec<A>>
+  where
+    I: Clone + 'a,
+    A: Clone + Debug + 'a,
+    B: Clone + Debug + 'a, {
+    repeat_sep_trailing(parser, 0, sep)
+  }
+
+  #[inline]
+  fn sep_end_by1<'a, I, A, B>(parser: Self::P<'a, I, A>, sep: Self::P<'a, I, B>) -> Self::P<'a, I, Vec<A>>
+  where
+    I: Clone + 'a,
+    A: Clone + Debug + 'a,
+    B: Clone + Debug + 'a, {
+    repeat_sep_trailing(parser, 1, sep)
+  }
+
+  #[inline]
+  fn many_till<'a, I, A, B>(parser: Self::P<'a, I, A>, end: Self::P<'a, I, B>) -> Self::P<'a, I, (Vec<A>, B)>
+  where
     I: Clone + 'a,
     A: Clone + Debug + 'a,
     B: Clone + Debug + 'a, {
     let method = parser.method.clone();
-    let separator_clone = separator.clone();
+    let end_method = end.method.clone();
 
     Parser::new(move |parse_state| {
       let mut all_length = 0;
       let mut items = vec![];
+      let mut current_parse_state = parse_state.advance_by(0);
 
-      if let ParseResult::Success { value, length } = (method)(parse_state) {
-        let mut current_parse_state = parse_state.advance_by(length);
-        items.push(value);
-        all_length += length;
-        loop {
-          match range.end() {
-            Bound::Included(&max_count) => {
-              if items.len() >= max_count {
-                break;
-              }
-            }
-            Bound::Excluded(&max_count) => {
-              if items.len() + 1 >= max_count {
-                break;
-              }
+      loop {
+        match current_parse_state.backtrackable(|| (end_method)(&current_parse_state)) {
+          ParseResult::Success { value, length } => {
+            return ParseResult::successful((items, value), all_length + length);
+          }
+          ParseResult::Failure {
+            error,
+            committed_status: CommittedStatus::Committed,
+          } => return ParseResult::failed(error, CommittedStatus::Committed),
+          ParseResult::Failure { .. } => (),
+        }
+        match current_parse_state.backtrackable(|| (method)(&current_parse_state)) {
+          ParseResult::Success { length: 0, .. } => {
+            let pe = ParseError::of_mismatch(
+              current_parse_state.whole_input(),
+              current_parse_state.current_offset(),
+              0,
+              "many_till: parser succeeded without consuming input".to_string(),
+            );
+            return ParseResult::failed(pe, (all_length != 0).into());
+          }
+          ParseResult::Success { value, length } => {
+            if let Err(error) = current_parse_state.repeat(items.len() + 1) {
+              return ParseResult::failed_with_commit(error);
             }
-            _ => (),
+            current_parse_state = current_parse_state.advance_by(length);
+            items.push(value);
+            all_length += length;
           }
+          ParseResult::Failure {
+            error,
+            committed_status,
+          } => return ParseResult::failed(error, committed_status.or((all_length != 0).into())),
+        }
+      }
+    })
+  }
+}
+
+/// Repeats `parser` separated by `separator`, allowing one optional trailing separator.
+///
+/// An uncommitted failure of an item or separator ends the repetition, a committed failure is propagated. The nodes
+/// and tokens of a failed item or separator are rolled back.
+fn repeat_sep_trailing<'a, I, A, B>(
+  parser: Parser<'a, I, A>,
+  min_count: usize,
+  separator: Parser<'a, I, B>,
+) -> Parser<'a, I, Vec<A>>
+where
+  I: 'a,
+  A: 'a,
+  B: 'a, {
+  let method = parser.method.clone();
+  let sep_method = separator.method.clone();
+
+  Parser::new(move |parse_state| {
+    let mut all_length = 0;
+    let mut items = vec![];
+    let mut current_parse_state = parse_state.advance_by(0);
 
-          if let Some(sep) = &separator_clone {
-            if let ParseResult::Success { length, .. } = (sep.method)(&current_parse_state) {
-              current_parse_state = current_parse_state.advance_by(length);
-              all_length += length;
-            } else {
+    loop {
+      let iteration_start = all_length;
+      match current_parse_state.backtrackable(|| (method)(&current_parse_state)) {
+        ParseResult::Success { value, length } => {
+          if let Err(error) = current_parse_state.repeat(items.len() + 1) {
+            return ParseResult::failed_with_commit(error);
+          }
+          current_parse_state = current_parse_state.advance_by(length);
+          items.push(value);
+          all_length += length;
+        }
+        ParseResult::Failure {
+          error,
+          committed_status: CommittedStatus::Committed,
+        } => return ParseResult::failed(error, CommittedStatus::Committed),
+        ParseResult::Failure { error, .. } => {
+          if items.len() < min_count {
+            return ParseResult::failed_with_uncommitted(error);
+          }
+          break;
+        }
+      }
+      match current_parse_state.backtrackable(|| (sep_method)(&current_parse_state)) {
+        ParseResult::Success { length, .. } => {
+          current_parse_state = current_parse_state.advance_by(length);
+          all_length += length;
+          // Stop if neither the item nor the separator consumed input, otherwise this would loop forever.
+          if all_length == iteration_start {
+            break;
+          }
+        }
+        ParseResult::Failure {
+          error,
+          committed_status: CommittedStatus::Committed,
+        } => return ParseResult::failed(error, CommittedStatus::Committed),
+        ParseResult::Failure { .. } => break,
+      }
+    }
+    ParseResult::successful(items, all_length)
+  })
+}
+
+/// Folds the repetitions of `parser` within `range`, optionally separated by `separator`.
+///
+/// Any failure of an item or separator ends the repetition, whether committed or not. The nodes and tokens of a
+/// failed item or separator are rolled back.
+fn fold_many_sep<'a, I, A, B, C, R, F>(
+  parser: Parser<'a, I, A>,
+  range: R,
+  separator: Option<Parser<'a, I, B>>,
+  init: C,
+  f: F,
+) -> Parser<'a, I, C>
+where
+  R: RangeArgument<usize> + Debug + 'a,
+  F: Fn(C, A) -> C + 'a,
+  I: 'a,
+  A: 'a,
+  B: 'a,
+  C: Clone + 'a, {
+  let method = parser.method.clone();
+  let sep_method = separator.map(|separator| separator.method.clone());
+
+  Parser::new(move |parse_state| {
+    let mut all_length = 0;
+    let mut count = 0;
+    let mut acc = init.clone();
+
+    if let ParseResult::Success { value, length } = parse_state.backtrackable(|| (method)(parse_state)) {
+      if let Err(error) = parse_state.repeat(1) {
+        return ParseResult::failed_with_commit(error);
+      }
+      let mut current_parse_state = parse_state.advance_by(length);
+      acc = f(acc, value);
+      count += 1;
+      all_length += length;
+      loop {
+        match range.end() {
+          Bound::Included(&max_count) => {
+            if count >= max_count {
+              break;
+            }
+          }
+          Bound::Excluded(&max_count) => {
+            if count + 1 >= max_count {
               break;
             }
           }
-          if let ParseResult::Success { value, length } = (method)(&current_parse_state) {
+          _ => (),
+        }
+
+        let iteration_start = all_length;
+        if let Some(sep_method) = &sep_method {
+          if let ParseResult::Success { length, .. } =
+            current_parse_state.backtrackable(|| (sep_method)(&current_parse_state))
+          {
             current_parse_state = current_parse_state.advance_by(length);
-            items.push(value);
             all_length += length;
           } else {
             break;
           }
         }
+        if let ParseResult::Success { value, length } =
+          current_parse_state.backtrackable(|| (method)(&current_parse_state))
+        {
+          if let Err(error) = current_parse_state.repeat(count + 1) {
+            return ParseResult::failed_with_commit(error);
+          }
+          current_parse_state = current_parse_state.advance_by(length);
+          acc = f(acc, value);
+          count += 1;
+          all_length += length;
+          // Stop if neither the separator nor the item consumed input, otherwise this would loop forever.
+          if all_length == iteration_start {
+            break;
+          }
+        } else {
+          break;
+        }
       }
+    }
 
-      if let Bound::Included(&min_count) = range.start() {
-        if items.len() < min_count {
-          let ps = parse_state.advance_by(all_length);
-          let pe = ParseError::of_mismatch(
-            ps.input(),
-            ps.last_offset().unwrap_or(0),
-            all_length,
-            format!(
-              "expect repeat at least {} times, found {} times",
-              min_count,
-              items.len()
-            ),
-          );
-          return ParseResult::failed_with_uncommitted(pe);
-        }
+    if let Bound::Included(&min_count) = range.start() {
+      if count < min_count {
+        let pe = ParseError::of_mismatch(
+          parse_state.whole_input(),
+          parse_state.current_offset(),
+          all_length,
+          format!("expect repeat at least {} times, found {} times", min_count, count),
+        );
+        return ParseResult::failed_with_uncommitted(pe);
       }
-      ParseResult::successful(items, all_length)
-    })
-  }
+    }
+    ParseResult::successful(acc, all_length)
+  })
 }
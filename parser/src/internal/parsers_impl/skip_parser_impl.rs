@@ -12,6 +12,7 @@ impl SkipParsers for ParsersImpl {
       if input.len() >= n {
         ParseResult::successful((), n)
       } else {
+        parse_state.reach_end();
         ParseResult::failed_with_uncommitted(ParseError::of_in_complete())
       }
     })
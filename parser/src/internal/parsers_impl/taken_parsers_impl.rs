@@ -1,8 +1,10 @@
 use crate::core::{Element, ParseError, ParseResult, Parser};
+use memchr::memmem::Finder;
 use std::fmt::Debug;
 
 use crate::extension::parsers::TakenParsers;
 use crate::internal::ParsersImpl;
+use crate::utils::ByteSet;
 
 impl TakenParsers for ParsersImpl {
   #[inline]
@@ -12,6 +14,7 @@ impl TakenParsers for ParsersImpl {
       if input.len() >= n {
         ParseResult::successful(parse_state.slice_with_len(n), n)
       } else {
+        parse_state.reach_end();
         ParseResult::failed_with_uncommitted(ParseError::of_in_complete())
       }
     })
@@ -36,6 +39,9 @@ impl TakenParsers for ParsersImpl {
         }
         index += 1;
       }
+      if start.map_or(input.is_empty(), |s| s + len == input.len()) {
+        parse_state.reach_end();
+      }
       match start {
         Some(s) => ParseResult::successful(&input[s..s + len], len),
         None => ParseResult::successful(parse_state.slice_with_len(0), 0),
@@ -63,8 +69,16 @@ impl TakenParsers for ParsersImpl {
         index += 1;
       }
       match start {
-        Some(s) => ParseResult::successful(&input[s..s + len], len),
-        None => ParseResult::failed_with_uncommitted(ParseError::of_in_complete()),
+        Some(s) => {
+          if s + len == input.len() {
+            parse_state.reach_end();
+          }
+          ParseResult::successful(&input[s..s + len], len)
+        }
+        None => {
+          parse_state.reach_end();
+          ParseResult::failed_with_uncommitted(ParseError::of_in_complete())
+        }
       }
     })
   }
@@ -92,12 +106,19 @@ impl TakenParsers for ParsersImpl {
         Some(s) => {
           let str = &input[s..s + len];
           if n <= str.len() && str.len() <= m {
+            if s + len == input.len() && len < m {
+              parse_state.reach_end();
+            }
             ParseResult::successful(str, len)
           } else {
+            parse_state.reach_end();
             ParseResult::failed_with_uncommitted(ParseError::of_in_complete())
           }
         }
-        None => ParseResult::failed_with_uncommitted(ParseError::of_in_complete()),
+        None => {
+          parse_state.reach_end();
+          ParseResult::failed_with_uncommitted(ParseError::of_in_complete())
+        }
       }
     })
   }
@@ -121,7 +142,7 @@ impl TakenParsers for ParsersImpl {
       if b {
         ParseResult::successful(parse_state.slice_with_len(index + 1), index + 1)
       } else {
-        let input = parse_state.input();
+        parse_state.reach_end();
         ParseResult::successful(input, input.len())
       }
     })
@@ -146,8 +167,58 @@ impl TakenParsers for ParsersImpl {
       if b {
         ParseResult::successful(parse_state.slice_with_len(index + 1), index + 1)
       } else {
+        parse_state.reach_end();
         ParseResult::failed_with_uncommitted(ParseError::of_in_complete())
       }
     })
   }
+
+  #[inline]
+  fn take_until_byte<'a>(byte: u8) -> Self::P<'a, u8, &'a [u8]> {
+    take_until_with(move |input| memchr::memchr(byte, input))
+  }
+
+  #[inline]
+  fn take_until_any<'a>(bytes: &[u8]) -> Self::P<'a, u8, &'a [u8]> {
+    match *bytes {
+      [] => take_until_with(|input| Some(input.len())),
+      [b1] => take_until_with(move |input| memchr::memchr(b1, input)),
+      [b1, b2] => take_until_with(move |input| memchr::memchr2(b1, b2, input)),
+      [b1, b2, b3] => take_until_with(move |input| memchr::memchr3(b1, b2, b3, input)),
+      _ => {
+        let set = ByteSet::from_bytes(bytes);
+        take_until_with(move |input| input.iter().position(|b| set.contains(b)))
+      }
+    }
+  }
+
+  #[inline]
+  fn take_until_seq<'a>(seq: &[u8]) -> Self::P<'a, u8, &'a [u8]> {
+    let finder = Finder::new(seq).into_owned();
+    take_until_with(move |input| finder.find(input))
+  }
+
+  #[inline]
+  fn take_while_in<'a>(set: &[u8]) -> Self::P<'a, u8, &'a [u8]> {
+    Self::take_while_in_set(ByteSet::from_bytes(set))
+  }
+
+  #[inline]
+  fn take_while_in_set<'a>(set: ByteSet) -> Self::P<'a, u8, &'a [u8]> {
+    take_until_with(move |input| input.iter().position(|b| !set.contains(b)))
+  }
+}
+
+/// Returns a parser that consumes the input up to the position found by `find`, or all of it if none is found.
+fn take_until_with<'a, F>(find: F) -> Parser<'a, u8, &'a [u8]>
+where
+  F: Fn(&[u8]) -> Option<usize> + 'a, {
+  Parser::new(move |parse_state| {
+    let input = parse_state.input();
+    let len = find(input).unwrap_or_else(|| {
+      parse_state.reach_end();
+      input.len()
+    });
+    ParseResult::successful(&input[..len], len)
+  })
 }
@@ -8,6 +8,7 @@ pub mod utils;
 pub mod prelude {
   pub use crate::core::*;
   pub use crate::extension::parser::*;
+  pub use crate::extension::parsers::PermutationMembers;
   use crate::extension::parsers::*;
   use crate::internal::*;
   pub use crate::utils::*;
@@ -425,10 +426,10 @@ pub mod prelude {
   /// assert!(result.is_success());
   /// assert_eq!(result.success().unwrap(), text);
   /// ```
-  pub fn elm_ref_of<'a, I, S>(set: &'static S) -> Parser<'a, I, &'a I>
+  pub fn elm_ref_of<'a, I, S>(set: &'a S) -> Parser<'a, I, &'a I>
   where
     I: Element,
-    S: Set<I> + ?Sized + 'static, {
+    S: Set<I> + ?Sized + 'a, {
     ParsersImpl::elm_ref_of(set)
   }
 
@@ -452,10 +453,10 @@ pub mod prelude {
   /// assert!(result.is_success());
   /// assert_eq!(result.success().unwrap(), text);
   /// ```
-  pub fn elm_of<'a, I, S>(set: &'static S) -> Parser<'a, I, I>
+  pub fn elm_of<'a, I, S>(set: &'a S) -> Parser<'a, I, I>
   where
     I: Element,
-    S: Set<I> + ?Sized + 'static, {
+    S: Set<I> + ?Sized + 'a, {
     ParsersImpl::elm_of(set)
   }
 
@@ -590,10 +591,10 @@ pub mod prelude {
   /// assert!(result.is_success());
   /// assert_eq!(result.success().unwrap(), text);
   /// ```
-  pub fn none_ref_of<'a, I, S>(set: &'static S) -> Parser<'a, I, &'a I>
+  pub fn none_ref_of<'a, I, S>(set: &'a S) -> Parser<'a, I, &'a I>
   where
     I: Element,
-    S: Set<I> + ?Sized + 'static, {
+    S: Set<I> + ?Sized + 'a, {
     ParsersImpl::none_ref_of(set)
   }
 
@@ -617,13 +618,92 @@ pub mod prelude {
   /// assert!(result.is_success());
   /// assert_eq!(result.success().unwrap(), text);
   /// ```
-  pub fn none_of<'a, I, S>(set: &'static S) -> Parser<'a, I, I>
+  pub fn none_of<'a, I, S>(set: &'a S) -> Parser<'a, I, I>
   where
     I: Element,
-    S: Set<I> + ?Sized + 'static, {
+    S: Set<I> + ?Sized + 'a, {
     ParsersImpl::none_of(set)
   }
 
+  /// Returns a [Parser] that parses an element in the specified set, which is owned by the parser.
+  ///
+  /// Unlike [elm_of], the set may be built at runtime, e.g. composed with [SetOps] or a [ByteSet].
+  ///
+  /// - set: a set of elements
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use std::iter::FromIterator;
+  /// # use oni_comb_parser_rs::prelude::*;
+  ///
+  /// let text: &str = "k8s-io";
+  /// let input = text.chars().collect::<Vec<_>>();
+  ///
+  /// let label = ('a'..='z').union('0'..='9').union("-");
+  /// let parser: Parser<char, String> = elm_of_set(label).of_many1().map(String::from_iter);
+  ///
+  /// let result: ParseResult<char, String> = parser.parse(&input);
+  ///
+  /// assert!(result.is_success());
+  /// assert_eq!(result.success().unwrap(), text);
+  /// ```
+  pub fn elm_of_set<'a, I, S>(set: S) -> Parser<'a, I, I>
+  where
+    I: Element,
+    S: Set<I> + 'a, {
+    ParsersImpl::elm_of_set(set)
+  }
+
+  /// Returns a [Parser] that parses an element not in the specified set, which is owned by the parser.
+  ///
+  /// - set: a set of elements
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use std::iter::FromIterator;
+  /// # use oni_comb_parser_rs::prelude::*;
+  ///
+  /// let text: &str = "abc";
+  /// let input = text.chars().collect::<Vec<_>>();
+  ///
+  /// let parser: Parser<char, String> = none_of_set(MajorCategory::Separator).of_many1().map(String::from_iter);
+  ///
+  /// let result: ParseResult<char, String> = parser.parse(&input);
+  ///
+  /// assert!(result.is_success());
+  /// assert_eq!(result.success().unwrap(), text);
+  /// ```
+  pub fn none_of_set<'a, I, S>(set: S) -> Parser<'a, I, I>
+  where
+    I: Element,
+    S: Set<I> + 'a, {
+    ParsersImpl::none_of_set(set)
+  }
+
+  /// Returns a [Parser] that decodes a UTF-8 character from bytes, if it is in the specified set.
+  ///
+  /// - set: a set of characters
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use oni_comb_parser_rs::prelude::*;
+  ///
+  /// let input = "größe = 1".as_bytes();
+  ///
+  /// let parser = utf8_char_of(XidContinue).of_many1().collect().map_res(std::str::from_utf8);
+  ///
+  /// assert_eq!(parser.parse(input).success().unwrap(), "größe");
+  /// assert!(utf8_char_of(XidContinue).parse(&[0xC3]).is_failure());
+  /// ```
+  pub fn utf8_char_of<'a, S>(set: S) -> Parser<'a, u8, char>
+  where
+    S: Set<char> + 'a, {
+    ParsersImpl::utf8_char_of(set)
+  }
+
   /// Returns a [Parser] that parses the space (' ', '\t'). (for reference)
   ///
   /// # Example
@@ -961,7 +1041,7 @@ pub mod prelude {
     ParsersImpl::elm_hex_digit()
   }
 
-  /// Returns a [Parser] that parses oct digits ('0'..='8').(for reference)
+  /// Returns a [Parser] that parses oct digits ('0'..='7').(for reference)
   ///
   /// # Example
   ///
@@ -969,7 +1049,7 @@ pub mod prelude {
   /// use std::iter::FromIterator;
   /// use oni_comb_parser_rs::prelude::*;
   ///
-  /// let text: &str = "012345678";
+  /// let text: &str = "01234567";
   /// let input = text.chars().collect::<Vec<_>>();
   ///
   /// let parser: Parser<char, String> = elm_oct_digit_ref().of_many1().map(String::from_iter);
@@ -985,7 +1065,7 @@ pub mod prelude {
     ParsersImpl::elm_oct_digit_ref()
   }
 
-  /// Returns a [Parser] that parses oct digits ('0'..='8').
+  /// Returns a [Parser] that parses oct digits ('0'..='7').
   ///
   /// # Example
   ///
@@ -993,7 +1073,7 @@ pub mod prelude {
   /// use std::iter::FromIterator;
   /// use oni_comb_parser_rs::prelude::*;
   ///
-  /// let text: &str = "012345678";
+  /// let text: &str = "01234567";
   /// let input = text.chars().collect::<Vec<_>>();
   ///
   /// let parser: Parser<char, String> = elm_oct_digit().of_many1().map(String::from_iter);
@@ -1108,10 +1188,40 @@ pub mod prelude {
   /// assert!(result.is_success());
   /// assert_eq!(result.success().unwrap(), "abc");
   /// ```
+  ///
+  /// # Panics
+  ///
+  /// Panics if the pattern is not a valid regular expression. Use [try_regex] to handle the error.
   pub fn regex<'a>(pattern: &str) -> Parser<'a, char, String> {
     ParsersImpl::regex(pattern)
   }
 
+  /// Returns a [Parser] that parses a string that match a regular expression, or an error if the pattern is invalid.
+  ///
+  /// - pattern: a regular expression
+  /// - pattern: 正規表現
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use oni_comb_parser_rs::prelude::*;
+  ///
+  /// let text: &str = "abcdef";
+  /// let input = text.chars().collect::<Vec<_>>();
+  ///
+  /// assert!(try_regex("[abc").is_err());
+  ///
+  /// let parser: Parser<char, String> = try_regex("[abc]+").unwrap();
+  ///
+  /// let result: ParseResult<char, String> = parser.parse(&input);
+  ///
+  /// assert!(result.is_success());
+  /// assert_eq!(result.success().unwrap(), "abc");
+  /// ```
+  pub fn try_regex<'a>(pattern: &str) -> Result<Parser<'a, char, String>, regex::Error> {
+    ParsersImpl::try_regex(pattern)
+  }
+
   /// Returns a [Parser] that returns an element of the specified length.
   ///
   /// - n: Length of the reading element
@@ -1283,6 +1393,98 @@ pub mod prelude {
     ParsersImpl::take_till1(f)
   }
 
+  /// Returns a [Parser] that returns the bytes up to the given byte, or up to the end of the input if it is not found.
+  ///
+  /// The byte itself is not consumed. The input is scanned with `memchr`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use oni_comb_parser_rs::prelude::*;
+  ///
+  /// let parser = take_until_byte(b'\n') - elm(b'\n');
+  ///
+  /// assert_eq!(parser.parse(b"line\nrest").success().unwrap(), b"line");
+  /// assert_eq!(take_until_byte(b'\n').parse(b"no newline").success().unwrap(), b"no newline");
+  /// ```
+  pub fn take_until_byte<'a>(byte: u8) -> Parser<'a, u8, &'a [u8]> {
+    ParsersImpl::take_until_byte(byte)
+  }
+
+  /// Returns a [Parser] that returns the bytes up to any of the given bytes, or up to the end of the input if none is
+  /// found.
+  ///
+  /// The byte found is not consumed. Up to three bytes are scanned with `memchr`, more with a lookup table.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use oni_comb_parser_rs::prelude::*;
+  ///
+  /// let parser = take_until_any(b"\r\n");
+  ///
+  /// assert_eq!(parser.parse(b"# comment\r\n").success().unwrap(), b"# comment");
+  /// ```
+  pub fn take_until_any<'a>(bytes: &[u8]) -> Parser<'a, u8, &'a [u8]> {
+    ParsersImpl::take_until_any(bytes)
+  }
+
+  /// Returns a [Parser] that returns the bytes up to the given sequence, or up to the end of the input if it is not
+  /// found.
+  ///
+  /// The sequence itself is not consumed. The input is scanned with `memchr::memmem`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use oni_comb_parser_rs::prelude::*;
+  ///
+  /// let parser = seq(b"/*") * take_until_seq(b"*/") - seq(b"*/");
+  ///
+  /// assert_eq!(parser.parse(b"/* a * b */").success().unwrap(), b" a * b ");
+  /// assert!(parser.parse(b"/* unterminated").is_failure());
+  /// ```
+  pub fn take_until_seq<'a>(seq: &[u8]) -> Parser<'a, u8, &'a [u8]> {
+    ParsersImpl::take_until_seq(seq)
+  }
+
+  /// Returns a [Parser] that returns the bytes while they are in the given set, which may be empty.
+  ///
+  /// Each byte is checked with a [ByteSet], built once from the set.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use oni_comb_parser_rs::prelude::*;
+  ///
+  /// let parser = take_while_in(b" \t\r\n");
+  ///
+  /// assert_eq!(parser.parse(b" \t\nvalue").success().unwrap(), b" \t\n");
+  /// ```
+  pub fn take_while_in<'a>(set: &[u8]) -> Parser<'a, u8, &'a [u8]> {
+    ParsersImpl::take_while_in(set)
+  }
+
+  /// Returns a [Parser] that returns the bytes while they are in the given [ByteSet], which may be empty.
+  ///
+  /// The set can also be given as a range or a slice of bytes.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use oni_comb_parser_rs::prelude::*;
+  ///
+  /// let parser = take_while_in_set(ByteSet::range(b'a', b'z').union(ByteSet::from_bytes(b"-_")));
+  ///
+  /// assert_eq!(parser.parse(b"oni-comb_rs!").success().unwrap(), b"oni-comb_rs");
+  /// assert_eq!(take_while_in_set(b'0'..=b'9').parse(b"42px").success().unwrap(), b"42");
+  /// ```
+  pub fn take_while_in_set<'a, S>(set: S) -> Parser<'a, u8, &'a [u8]>
+  where
+    S: Into<ByteSet>, {
+    ParsersImpl::take_while_in_set(set.into())
+  }
+
   // --- Offset Control Parsers ---
 
   /// Returns a [Parser] that skips the specified number of elements.
@@ -1345,6 +1547,87 @@ pub mod prelude {
     ParsersImpl::surround(lp, parser, rp)
   }
 
+  /// Returns a [Parser] that parses all members of a tuple of [Parser]s in any order.
+  ///
+  /// Each member is matched at most once and the results are returned in declaration order.
+  /// Optional members can be written with `opt()`. If a required member is missing, the error names it.
+  ///
+  /// - members: a tuple of parsers
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use oni_comb_parser_rs::prelude::*;
+  ///
+  /// let text: &str = "cab";
+  /// let input = text.chars().collect::<Vec<_>>();
+  ///
+  /// let parser: Parser<char, (char, Option<char>, char)> = permutation((elm('a'), elm('b').opt(), elm('c')));
+  ///
+  /// let result: ParseResult<char, (char, Option<char>, char)> = parser.parse(&input);
+  ///
+  /// assert!(result.is_success());
+  /// assert_eq!(result.success().unwrap(), ('a', Some('b'), 'c'));
+  /// ```
+  pub fn permutation<'a, I, M>(members: M) -> Parser<'a, I, M::Output>
+  where
+    M: PermutationMembers<'a, I> + 'a,
+    I: 'a,
+    M::Output: 'a, {
+    ParsersImpl::permutation(members)
+  }
+
+  // --- Concrete Syntax Tree Parsers ---
+
+  /// Returns a [Parser] that records a node of the given kind in the concrete syntax tree, see [ParserRunner::parse_cst].
+  ///
+  /// The tokens and nodes recorded by the parser become the children of the node, and the input it consumed
+  /// outside of them is kept as [SyntaxKind::TEXT] tokens.
+  ///
+  /// - kind: kind of the node
+  /// - parser: parser
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use oni_comb_parser_rs::prelude::*;
+  ///
+  /// const WORD: SyntaxKind = SyntaxKind(0);
+  /// const SPACE: SyntaxKind = SyntaxKind(1);
+  /// const LIST: SyntaxKind = SyntaxKind(2);
+  /// const ROOT: SyntaxKind = SyntaxKind(3);
+  ///
+  /// let word = token(WORD, elm_alpha().of_many1());
+  /// let space = token(SPACE, elm_space().of_many0());
+  /// let parser = node(LIST, elm(b'[') * (space.clone() * word - space).of_many0() - elm(b']'));
+  ///
+  /// let input = b"[ a  bc ]";
+  /// let (_, tree) = parser.parse_cst(input, ROOT).unwrap();
+  /// assert_eq!(tree.text(), input.to_vec());
+  ///
+  /// let list = &tree.descendants(LIST)[0];
+  /// let kinds = list.tokens().iter().map(|token| token.kind()).collect::<Vec<_>>();
+  /// assert_eq!(kinds, vec![SyntaxKind::TEXT, SPACE, WORD, SPACE, WORD, SPACE, SyntaxKind::TEXT]);
+  /// ```
+  pub fn node<'a, I, A>(kind: SyntaxKind, parser: Parser<'a, I, A>) -> Parser<'a, I, A>
+  where
+    A: 'a, {
+    ParsersImpl::node(kind, parser)
+  }
+
+  /// Returns a [Parser] that records the input it consumed as a token of the given kind in the concrete syntax tree,
+  /// see [ParserRunner::parse_cst].
+  ///
+  /// Use it for trivia such as whitespace and comments as well, to give them their own kind.
+  ///
+  /// - kind: kind of the token
+  /// - parser: parser
+  pub fn token<'a, I, A>(kind: SyntaxKind, parser: Parser<'a, I, A>) -> Parser<'a, I, A>
+  where
+    A: 'a, {
+    ParsersImpl::token(kind, parser)
+  }
+
   /// Returns a [Parser] that lazily evaluates the specified [Parser].
   ///
   /// - f: Function to generate parser
@@ -1378,14 +1661,16 @@ pub mod prelude {
 
 #[cfg(test)]
 mod tests {
+  use std::cell::Cell;
   use std::env;
   use std::iter::FromIterator;
+  use std::rc::Rc;
 
   use crate::core::{ParserFunctor, ParserMonad, ParserRunner};
 
   use crate::extension::parser::{
-    CollectParser, ConversionParser, DiscardParser, LoggingParser, OffsetParser, OperatorParser, PeekParser,
-    RepeatParser,
+    CacheParser, CollectParser, ConversionParser, DiscardParser, LoggingParser, OffsetParser, OperatorParser,
+    PeekParser, RepeatParser,
   };
 
   use super::prelude::*;
@@ -1461,6 +1746,124 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_map_res_with_cause() {
+    init();
+    #[derive(Debug, PartialEq)]
+    enum RangeError {
+      Reversed { from: u8, to: u8 },
+    }
+    impl std::fmt::Display for RangeError {
+      fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+          RangeError::Reversed { from, to } => write!(f, "invalid range {}-{}", from, to),
+        }
+      }
+    }
+    impl std::error::Error for RangeError {}
+
+    let input1 = "30-10".chars().collect::<Vec<_>>();
+    let input2 = "300-310".chars().collect::<Vec<_>>();
+    let number = || {
+      elm_digit()
+        .of_many1()
+        .map(String::from_iter)
+        .map_res_with_cause(|s| s.parse::<u8>())
+    };
+    let range = (number() - elm('-') + number())
+      .map_res_with_cause(|(from, to)| {
+        if from <= to {
+          Ok((from, to))
+        } else {
+          Err(RangeError::Reversed { from, to })
+        }
+      })
+      .name("range");
+
+    let error = range.parse(&input1).failure().unwrap();
+    assert_eq!(
+      error.downcast_ref::<RangeError>(),
+      Some(&RangeError::Reversed { from: 30, to: 10 })
+    );
+    assert_eq!(
+      std::error::Error::source(&error).map(|e| e.to_string()),
+      Some("invalid range 30-10".to_string())
+    );
+
+    let error = range.parse(&input2).failure().unwrap();
+    assert!(error.downcast_ref::<RangeError>().is_none());
+    assert_eq!(
+      error.downcast_ref::<std::num::ParseIntError>().map(|e| *e.kind()),
+      Some(std::num::IntErrorKind::PosOverflow)
+    );
+
+    let error = elm_any().map_res(|c: char| c.to_string().parse::<u8>()).parse(&['x']);
+    assert!(error.failure().unwrap().cause().is_none());
+
+    let cause = ErrorCause::new(RangeError::Reversed { from: 2, to: 1 });
+    let error = ParseError::<char>::of_custom_with_cause(0, None, cause.clone());
+    assert_eq!(error.to_string(), "invalid range 2-1 at 0");
+    assert_eq!(error.cause(), Some(&cause));
+    assert!(error.downcast_ref::<RangeError>().is_some());
+  }
+
+  #[test]
+  fn test_parse_error_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<ParseError<'static, u8>>();
+  }
+
+  #[test]
+  fn test_try_regex() {
+    init();
+    assert!(try_regex(r"[abc").is_err());
+
+    let input = "日本語abc".chars().collect::<Vec<char>>();
+    let p = try_regex(r"\p{Han}+").unwrap() + elm('a');
+    let r = p.parse_as_result(&input);
+    assert_eq!(r.unwrap(), ("日本語".to_string(), 'a'));
+  }
+
+  #[test]
+  fn test_error_input() {
+    init();
+    let input = b"abc";
+    let p = seq(b"ab") * end();
+    let r = p.parse(input);
+    let error = r.failure().unwrap();
+    assert_eq!(error.input(), Some(&b"c"[..]));
+    assert_eq!(error.input_string(), Some("c".to_string()));
+    assert_eq!(error.to_string(), "Mismatch at 2: expect end of input, found: 99");
+
+    // The input of an error is the offending part of the whole input, wherever the parser starts.
+    let input = b"abcd";
+    let error = (seq(b"ab") * elm(b'x')).parse(input).failure().unwrap();
+    assert_eq!(error.input(), Some(&b"c"[..]));
+    let error = (seq(b"ab") * elm_of(b"xy")).parse(input).failure().unwrap();
+    assert_eq!(error.input(), Some(&b"c"[..]));
+    let error = (elm(b'a') * seq(b"bx")).parse(input).failure().unwrap();
+    assert_eq!(error.input(), Some(&b"c"[..]));
+    let error = (elm(b'a') * elm_alpha().of_many_n_m(4, 5))
+      .parse(input)
+      .failure()
+      .unwrap();
+    assert_eq!(error.input(), Some(&b"bcd"[..]));
+    let error = (elm(b'a') * seq(b"bc").not()).parse(input).failure().unwrap();
+    assert_eq!(error.input(), Some(&b"bc"[..]));
+    let p = elm(b'a') * take(2).map_res(std::str::from_utf8).map_res(str::parse::<u8>);
+    let error = p.parse(input).failure().unwrap();
+    assert_eq!(error.input(), Some(&b"bc"[..]));
+  }
+
+  #[test]
+  fn test_elm_oct_digit() {
+    init();
+    let p = elm_oct_digit().of_many1().collect();
+    let r = p.parse_as_result(b"01234567").unwrap();
+    assert_eq!(r, b"01234567");
+    assert!(elm_oct_digit().parse_as_result(b"8").is_err());
+  }
+
   #[test]
   fn test_elm_of() {
     init();
@@ -1590,6 +1993,341 @@ mod tests {
     assert_eq!(r, vec![b'a', b'a', b'a']);
   }
 
+  #[test]
+  fn test_many0_sep_trailing() {
+    init();
+    let p = elm(b'a').of_many0_sep_trailing(elm_ref(b',')) - end();
+
+    let r = p.parse_as_result(b"").unwrap();
+    assert!(r.is_empty());
+
+    let r = p.parse_as_result(b"a,a").unwrap();
+    assert_eq!(r, vec![b'a', b'a']);
+
+    let r = p.parse_as_result(b"a,a,").unwrap();
+    assert_eq!(r, vec![b'a', b'a']);
+
+    let r = p.parse_as_result(b",");
+    assert!(r.is_err());
+
+    let r = p.parse_as_result(b"a,,");
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn test_many0_sep_trailing_committed() {
+    init();
+    let item = seq(b"ab");
+    let p = item.of_many0_sep_trailing(elm_ref(b','));
+
+    let r = p.parse(b"ab,ax");
+    assert!(r.is_failure());
+    assert_eq!(r.committed_status(), Some(CommittedStatus::Committed));
+
+    let r = p.parse_as_result(b"ab,x").unwrap();
+    assert_eq!(r, vec![b"ab".to_vec()]);
+  }
+
+  #[test]
+  fn test_sep_end_by1() {
+    init();
+    let p = elm(b'a').of_sep_end_by1(elm_ref(b',')) - end();
+
+    let r = p.parse(b"");
+    assert!(r.is_failure());
+    assert_eq!(r.committed_status(), Some(CommittedStatus::Uncommitted));
+
+    let r = p.parse_as_result(b"a").unwrap();
+    assert_eq!(r, vec![b'a']);
+
+    let r = p.parse_as_result(b"a,a,").unwrap();
+    assert_eq!(r, vec![b'a', b'a']);
+  }
+
+  #[test]
+  fn test_many_till() {
+    init();
+    let p = elm_any_ref().of_many_till(seq(b"*/"));
+
+    let (items, end_value) = p.parse_as_result(b"abc*/").unwrap();
+    assert_eq!(items, vec![&b'a', &b'b', &b'c']);
+    assert_eq!(end_value, b"*/");
+
+    let (items, _) = p.parse_as_result(b"*/").unwrap();
+    assert!(items.is_empty());
+
+    let r = p.parse(b"abc");
+    assert!(r.is_failure());
+    assert_eq!(r.committed_status(), Some(CommittedStatus::Committed));
+
+    let r = p.parse(b"");
+    assert!(r.is_failure());
+    assert_eq!(r.committed_status(), Some(CommittedStatus::Uncommitted));
+  }
+
+  #[test]
+  fn test_fold_many0() {
+    init();
+    let p = elm_digit_ref()
+      .map(|e: &u8| (*e - b'0') as u32)
+      .of_fold_many0(0, |acc, n| acc * 10 + n);
+
+    let r = p.parse_as_result(b"123x").unwrap();
+    assert_eq!(r, 123);
+
+    let r = p.parse_as_result(b"x").unwrap();
+    assert_eq!(r, 0);
+  }
+
+  #[test]
+  fn test_fold_many_committed() {
+    init();
+    let item = seq(b"ab");
+
+    let r = item.clone().of_fold_many0(0, |n, _| n + 1).parse(b"abax");
+    assert_eq!(r.success(), Some(1));
+
+    let r = item.clone().of_fold_many(1.., 0, |n, _| n + 1).parse(b"abax");
+    assert_eq!(r.success(), Some(1));
+
+    let r = item.of_many0().parse(b"abax");
+    assert_eq!(r.success(), Some(vec![b"ab".to_vec()]));
+  }
+
+  #[test]
+  fn test_fold_many() {
+    init();
+    let p = elm_ref(b'a').of_fold_many(1..=2, 0, |n, _| n + 1);
+
+    let r = p.parse_as_result(b"");
+    assert!(r.is_err());
+
+    let r = p.parse_as_result(b"aaa").unwrap();
+    assert_eq!(r, 2);
+  }
+
+  #[test]
+  fn test_skip_many() {
+    init();
+    let p = elm_ref(b' ').of_skip_many0() * elm_ref(b'a');
+    let r = p.parse_as_result(b"   a").unwrap();
+    assert_eq!(*r, b'a');
+
+    let p = elm_ref(b' ').of_skip_many0().collect();
+    let r = p.parse_as_result(b"  a").unwrap();
+    assert_eq!(r, b"  ");
+
+    let p = elm_ref(b' ').of_skip_many1();
+    let r = p.parse_as_result(b"a");
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn test_parse_iter() {
+    init();
+    let p = elm_digit_ref() - elm_ref(b',').opt();
+
+    let mut iter = p.parse_iter(b"1,2,3");
+    assert_eq!(iter.next(), Some(&b'1'));
+    assert_eq!(iter.offset(), 2);
+    let rest = iter.collect::<Vec<_>>();
+    assert_eq!(rest, vec![&b'2', &b'3']);
+
+    let mut iter = p.parse_iter(b"1,x");
+    assert_eq!(iter.by_ref().count(), 1);
+    assert_eq!(iter.rest(), b"x");
+    assert!(iter.error().is_some());
+  }
+
+  #[test]
+  fn test_parse_prefix_and_parse_all() {
+    init();
+    let p = elm_digit().of_many1().collect();
+
+    let (value, rest) = p.parse_prefix(b"123abc").unwrap();
+    assert_eq!(value, b"123");
+    assert_eq!(rest, b"abc");
+
+    assert_eq!(p.parse_all(b"123").unwrap(), b"123");
+    let error = p.parse_all(b"123abc").err().unwrap();
+    assert!(error.is_mismatch());
+    assert_eq!(error.input(), Some(&b"abc"[..]));
+    assert_eq!(error.to_string(), "Mismatch at 3: unexpected trailing input");
+  }
+
+  #[test]
+  fn test_parse_many_from() {
+    init();
+    let record = elm_alpha().of_many1().collect().map_res(std::str::from_utf8) - elm(b'\n');
+
+    let values = record.parse_many_from(b"foo\nbar\nbaz\n").unwrap();
+    assert_eq!(values, vec!["foo", "bar", "baz"]);
+
+    assert!(record.parse_many_from(b"").unwrap().is_empty());
+    assert!(record.parse_many_from(b"foo\nbar").is_err());
+  }
+
+  #[test]
+  fn test_parse_with_limits() {
+    init();
+    fn nested<'a>() -> Parser<'a, u8, usize> {
+      (elm(b'[') * lazy(nested) - elm(b']')).map(|n| n + 1).attempt() | unit().map(|_| 0)
+    }
+    let deep = [b"[".repeat(10_000), b"]".repeat(10_000)].concat();
+
+    let r = nested().parse_with_limits(b"[[[]]]", ParseLimits::new().with_max_depth(3));
+    assert_eq!(r.success(), Some(3));
+
+    let r = nested().parse_with_limits(&deep, ParseLimits::new().with_max_depth(64));
+    let error = r.failure().unwrap();
+    assert_eq!(error, ParseError::of_limit_exceeded(65, LimitKind::Depth, 64));
+
+    let r = nested().parse_with_limits(&deep, ParseLimits::new().with_max_steps(100));
+    assert!(r.failure().unwrap().is_limit_exceeded());
+
+    let p = elm(b'a').of_many0() - end();
+    let r = p.parse_with_limits(b"aaaa", ParseLimits::new().with_max_repetitions(4));
+    assert_eq!(r.success(), Some(vec![b'a'; 4]));
+    let r = p.parse_with_limits(b"aaaaa", ParseLimits::new().with_max_repetitions(4));
+    assert_eq!(
+      r.failure(),
+      Some(ParseError::of_limit_exceeded(4, LimitKind::Repetitions, 4))
+    );
+
+    // The limits combine with the other modes of a parse.
+    let limits = ParseLimits::new().with_max_depth(64);
+    let (value, tree) = nested().parse_cst_with_limits(b"[[]]", SyntaxKind(0), limits).unwrap();
+    assert_eq!((value, tree.text()), (2, b"[[]]".to_vec()));
+    let error = nested()
+      .parse_cst_with_limits(&deep, SyntaxKind(0), limits)
+      .err()
+      .unwrap();
+    assert!(error.is_limit_exceeded());
+
+    let open = b"[".repeat(100);
+    assert_eq!(nested().parse_partial(&open), PartialResult::Incomplete);
+    match nested().parse_partial_with_limits(&open, limits) {
+      PartialResult::Failure(error) => assert!(error.is_limit_exceeded()),
+      result => panic!("unexpected result: {:?}", result),
+    }
+    assert!(matches!(
+      nested().parse_partial_with_limits(b"[[]]", limits),
+      PartialResult::Complete { value: 2, length: 4 }
+    ));
+
+    let session = ParseSession::new(deep.clone()).with_limits(limits);
+    assert!(session.parse(|| nested().memo()).failure().unwrap().is_limit_exceeded());
+    let parse_state = ParseState::with_options(&deep, 0, ParseOptions::new().with_limits(limits).with_cst());
+    assert!(nested().run(&parse_state).is_failure());
+    assert!(parse_state.limit_error().is_some());
+  }
+
+  #[test]
+  fn test_cut() {
+    init();
+    let p = (elm(b'a') + elm(b'b')) | (elm(b'a') + elm(b'c'));
+    assert_eq!(p.parse_as_result(b"ac").unwrap(), (b'a', b'c'));
+
+    let p = (elm(b'a') + elm(b'b').cut()) | (elm(b'a') + elm(b'c'));
+    let r = p.parse(b"ac");
+    assert_eq!(r.committed_status(), Some(CommittedStatus::Committed));
+  }
+
+  #[test]
+  fn test_parse_with_diagnostics() {
+    init();
+    let p = seq(b"s") | seq(b"seconds");
+    let (r, diagnostics) = p.parse_with_diagnostics(b"seconds");
+    assert_eq!(r.success(), Some(b"s".to_vec()));
+    match diagnostics.as_slice() {
+      [Diagnostic::ShadowedAlternative {
+        location,
+        offset: 0,
+        length: 7,
+        shadowing_length: 1,
+      }] => assert!(location.file().ends_with("lib.rs")),
+      _ => panic!("unexpected diagnostics: {:?}", diagnostics),
+    }
+
+    let p = elm(b'a').attempt() | elm(b'b');
+    let (_, diagnostics) = p.parse_with_diagnostics(b"b");
+    assert!(matches!(
+      diagnostics.as_slice(),
+      [Diagnostic::RedundantAttempt { calls: 1, .. }]
+    ));
+
+    let p = seq(b"ab").attempt() | seq(b"ac");
+    let (r, diagnostics) = p.parse_with_diagnostics(b"ac");
+    assert!(r.is_success());
+    assert!(diagnostics.is_empty());
+  }
+
+  #[test]
+  fn test_permutation() {
+    init();
+    let p = permutation((elm(b'a'), elm(b'b').opt(), elm(b'c'))) - end();
+
+    let r = p.parse_as_result(b"abc").unwrap();
+    assert_eq!(r, (b'a', Some(b'b'), b'c'));
+
+    let r = p.parse_as_result(b"cba").unwrap();
+    assert_eq!(r, (b'a', Some(b'b'), b'c'));
+
+    let r = p.parse_as_result(b"ca").unwrap();
+    assert_eq!(r, (b'a', None, b'c'));
+
+    let r = p.parse_as_result(b"aa");
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn test_permutation_missing_members() {
+    init();
+    let p = permutation((elm(b'a').name("a"), elm(b'b'), elm(b'c').name("c")));
+
+    let r = p.parse(b"b");
+    assert_eq!(r.committed_status(), Some(CommittedStatus::Committed));
+    let message = r.failure().unwrap().to_string();
+    assert!(message.contains("#0 (failed to parse a"), "{}", message);
+    assert!(message.contains("#2 (failed to parse c"), "{}", message);
+    assert!(!message.contains("#1"), "{}", message);
+  }
+
+  #[test]
+  fn test_grammar_fuzzer() {
+    init();
+    let grammar = tree_syntax().grammar().unwrap();
+    let mut fuzzer = GrammarFuzzer::new(grammar.clone(), 42).with_max_depth(6);
+    let result = fuzzer.check_roundtrip(100, |s| {
+      let syntax = tree_syntax();
+      let parser = syntax.parser() - end();
+      let tree = parser.parse_as_result(s.as_bytes()).ok()?;
+      syntax.print(&tree).and_then(|bytes| String::from_utf8(bytes).ok())
+    });
+    assert_eq!(result, Ok(()));
+
+    let result = fuzzer.check_never_panics(100, |s| {
+      let _ = (tree_syntax().parser() - end()).parse(s.as_bytes());
+    });
+    assert_eq!(result, Ok(()));
+
+    let mut rng1 = GrammarRng::new(7);
+    let mut rng2 = GrammarRng::new(7);
+    assert_eq!(grammar.generate(&mut rng1), grammar.generate(&mut rng2));
+
+    // A syntax created from a parser has no grammar unless one is given.
+    let digit = Syntax::new(elm_digit(), |b, out| {
+      out.push(*b);
+      true
+    });
+    assert!(digit.grammar().is_none());
+    assert!((digit.clone() + Syntax::literal(b"!")).grammar().is_none());
+    let digit = digit.with_grammar(Grammar::char_range('0', '9'));
+    let mut rng = GrammarRng::new(1);
+    let input = (digit + Syntax::literal(b"!")).grammar().unwrap().generate(&mut rng);
+    assert!(matches!(input.as_bytes(), [b'0'..=b'9', b'!']), "{}", input);
+  }
+
   #[test]
   fn test_seq() {
     init();
@@ -1916,4 +2654,424 @@ mod tests {
       assert!(result.is_err());
     }
   }
+
+  #[derive(Debug, Clone, PartialEq)]
+  enum Tree {
+    Leaf(String),
+    Node(Vec<Tree>),
+  }
+
+  fn tree_syntax<'a>() -> Syntax<'a, u8, Tree> {
+    let leaf = Syntax::elm_pred(|b: &u8| b.is_ascii_alphabetic()).many1().partial_iso(
+      |letters| String::from_utf8(letters).ok().map(Tree::Leaf),
+      |tree| match tree {
+        Tree::Leaf(name) => Some(name.clone().into_bytes()),
+        Tree::Node(_) => None,
+      },
+    );
+    let node = (Syntax::literal(b"(") * Syntax::lazy(tree_syntax).many0_sep(Syntax::literal(b" "))
+      - Syntax::literal(b")"))
+    .partial_iso(
+      |children| Some(Tree::Node(children)),
+      |tree| match tree {
+        Tree::Node(children) => Some(children.clone()),
+        Tree::Leaf(_) => None,
+      },
+    );
+    leaf | node
+  }
+
+  #[test]
+  fn test_syntax() {
+    init();
+    let syntax = tree_syntax();
+    for input in [&b"a"[..], b"()", b"(a (bc d) ())"] {
+      let tree = syntax.parse(input).success().unwrap();
+      assert_eq!(syntax.print(&tree), Some(input.to_vec()));
+    }
+
+    let tree = Tree::Node(vec![Tree::Leaf("x".to_string()), Tree::Node(vec![])]);
+    assert_eq!(syntax.print(&tree), Some(b"(x ())".to_vec()));
+    assert_eq!(syntax.print(&Tree::Leaf("1".to_string())), None);
+    assert_eq!(syntax.print(&Tree::Leaf(String::new())), None);
+
+    let flag = Syntax::literal(b"!").opt() + Syntax::pure(1u8);
+    assert_eq!(flag.parse(b"!").success(), Some((Some(()), 1)));
+    assert_eq!(flag.print(&(None, 1)), Some(vec![]));
+    assert_eq!(flag.print(&(Some(()), 2)), None);
+  }
+
+  fn memo_lines<'a>(runs: Rc<Cell<usize>>) -> Parser<'a, u8, Vec<String>> {
+    let line = (elm_alpha().of_many1().collect().map_res(std::str::from_utf8) - elm(b'\n')).map(move |s| {
+      runs.set(runs.get() + 1);
+      s.to_string()
+    });
+    line.memo().of_many0()
+  }
+
+  #[test]
+  fn test_parse_session() {
+    init();
+    let runs = Rc::new(Cell::new(0));
+    let mut session = ParseSession::new(b"aa\nbb\ncc\n".to_vec());
+    assert_eq!(
+      session.parse(|| memo_lines(runs.clone())).success(),
+      Some(vec!["aa".to_string(), "bb".to_string(), "cc".to_string()])
+    );
+    assert_eq!((runs.get(), session.memo_len()), (3, 3));
+
+    let result = session.reparse(TextEdit::insert(4, b"x".to_vec()), || memo_lines(runs.clone()));
+    assert_eq!(
+      result.success(),
+      Some(vec!["aa".to_string(), "bxb".to_string(), "cc".to_string()])
+    );
+    assert_eq!((runs.get(), session.memo_hits()), (4, 2));
+
+    let result = session.reparse(TextEdit::delete(0..3), || memo_lines(runs.clone()));
+    assert_eq!(result.success(), Some(vec!["bxb".to_string(), "cc".to_string()]));
+    assert_eq!((runs.get(), session.memo_hits()), (4, 4));
+
+    let result = session.reparse(TextEdit::new(4..6, b"d".to_vec()), || memo_lines(runs.clone()));
+    assert_eq!(result.success(), Some(vec!["bxb".to_string(), "d".to_string()]));
+    assert_eq!(session.input(), b"bxb\nd\n");
+
+    // Outside a session, memo() parses as is.
+    assert_eq!(memo_lines(runs).parse(b"a\n").success(), Some(vec!["a".to_string()]));
+
+    // Parsers memoized by the same helper function are told apart.
+    fn memoized<'a>(parser: Parser<'a, u8, &'a [u8]>) -> Parser<'a, u8, Vec<u8>> {
+      parser.map(<[u8]>::to_vec).memo()
+    }
+    let session = ParseSession::new(b"ab".to_vec());
+    let result = session.parse(|| memoized(seq(b"ab").collect()).peek() * memoized(elm_ref(b'a').collect()));
+    assert_eq!(result.success(), Some(b"a".to_vec()));
+    assert_eq!(session.memo_len(), 2);
+  }
+
+  #[test]
+  fn test_parse_cst() {
+    init();
+    const A: SyntaxKind = SyntaxKind(0);
+    const B: SyntaxKind = SyntaxKind(1);
+    const ROOT: SyntaxKind = SyntaxKind(2);
+    let input = b"abc";
+
+    // The tokens of a failed alternative are rolled back.
+    let parser =
+      (token(A, elm(b'a')) + elm(b'x')).attempt().map(|_| ()) | (elm(b'a') + token(B, elm(b'b'))).map(|_| ());
+    let (_, tree) = parser.parse_cst(input, ROOT).unwrap();
+    let tokens = tree.tokens();
+    assert_eq!(tokens.len(), 2);
+    assert_eq!((tokens[0].kind(), tokens[0].text()), (SyntaxKind::TEXT, &b"a"[..]));
+    assert_eq!((tokens[1].kind(), tokens[1].text()), (B, &b"b"[..]));
+    assert_eq!(tree.text_len(), 2);
+
+    // The tokens of a failed node are rolled back.
+    let parser = node(B, token(A, elm(b'a')) + elm(b'x')).of_many0() * elm(b'a');
+    let (_, tree) = parser.parse_cst(input, ROOT).unwrap();
+    let kinds = tree.children().iter().map(|child| child.kind()).collect::<Vec<_>>();
+    assert_eq!(kinds, vec![SyntaxKind::TEXT]);
+
+    // The tokens of a failed iteration, option or alternative are rolled back.
+    let kinds = |tree: &GreenNode<u8>| tree.tokens().iter().map(|token| token.kind()).collect::<Vec<_>>();
+    let failed = || token(A, elm(b'a')) + elm(b'x');
+    let parser = failed().of_many0() * elm(b'a') * elm(b'b');
+    let (_, tree) = parser.parse_cst(b"ab", ROOT).unwrap();
+    assert_eq!(kinds(&tree), vec![SyntaxKind::TEXT]);
+    let parser = failed().of_many0_sep(elm(b',')) * elm(b'a') * elm(b'b');
+    assert!(!kinds(&parser.parse_cst(b"ab", ROOT).unwrap().1).contains(&A));
+    let parser = elm(b'a').of_many0_sep_trailing(token(B, elm(b',')) + elm(b'x')) * elm(b',');
+    assert!(!kinds(&parser.parse_cst(b"a,", ROOT).unwrap().1).contains(&B));
+    let parser = elm(b'a').of_many_till(token(A, elm_pred(|_| true)).with_filter(|c| *c == b'c'));
+    assert_eq!(
+      kinds(&parser.parse_cst(b"ac", ROOT).unwrap().1),
+      vec![SyntaxKind::TEXT, A]
+    );
+    let parser = failed().opt() * elm(b'a') * elm(b'b');
+    assert!(!kinds(&parser.parse_cst(b"ab", ROOT).unwrap().1).contains(&A));
+    let parser = (token(A, elm(b'a')).with_filter(|_| false) | elm(b'a')) * elm(b'b');
+    assert!(!kinds(&parser.parse_cst(b"ab", ROOT).unwrap().1).contains(&A));
+
+    // The tokens of a lookahead are not recorded.
+    let parser = token(A, elm(b'a')).peek() * elm(b'a') * node(B, token(A, elm(b'b')) + elm(b'c'));
+    let (_, tree) = parser.parse_cst(input, ROOT).unwrap();
+    assert_eq!(tree.text(), input.to_vec());
+    let kinds = tree.children().iter().map(|child| child.kind()).collect::<Vec<_>>();
+    assert_eq!(kinds, vec![SyntaxKind::TEXT, B]);
+    let node = tree.descendants(B)[0];
+    assert_eq!(
+      node.tokens().iter().map(|token| token.kind()).collect::<Vec<_>>(),
+      vec![A, SyntaxKind::TEXT]
+    );
+
+    // Without parse_cst, node and token parse as is.
+    assert!(parser.parse(input).is_success());
+  }
+
+  #[test]
+  fn test_take_until() {
+    init();
+    let input = b"key = value; # note\n";
+
+    let parser = seq(b"key") * take_while_in(b" =") * take_until_any(b";#\r\n");
+    assert_eq!(parser.parse(input).success(), Some(&b"value"[..]));
+
+    let parser = take_until_byte(b'#') * take_until_seq(b"\n");
+    assert_eq!(parser.parse(input).success(), Some(&b"# note"[..]));
+    assert_eq!(take_until_seq(b"##").parse(input).success(), Some(&input[..]));
+    assert_eq!(take_until_seq(b"").parse(input).success(), Some(&b""[..]));
+
+    assert_eq!(take_until_any(b"").parse(input).success(), Some(&input[..]));
+    assert_eq!(
+      take_until_any(b"xz;!").parse(input).success(),
+      Some(&b"key = value"[..])
+    );
+    assert_eq!(take_while_in(b"").parse(input).success(), Some(&b""[..]));
+    assert_eq!(take_while_in(b"aeky").parse(input).success(), Some(&b"key"[..]));
+    assert_eq!(take_until_byte(b'k').parse(b"").success(), Some(&b""[..]));
+  }
+
+  #[test]
+  fn test_set_ops() {
+    init();
+    let hex = ('0'..='9').union('a'..='f').union('A'..='F');
+    assert!(hex.contains(&'7') && hex.contains(&'F'));
+    assert!(!hex.contains(&'g'));
+    let vowels = "aeiou";
+    let consonants = ('a'..='z').difference(vowels);
+    assert!(consonants.contains(&'z') && !consonants.contains(&'u'));
+    let others = consonants.negate();
+    assert!(others.contains(&'u') && others.contains(&'0'));
+
+    let label = ByteSet::range(b'a', b'z').union(ByteSet::from_bytes(b"-_"));
+    assert_eq!(label.len(), 28);
+    assert_eq!(label.negate().len(), 256 - 28);
+    assert_eq!(
+      label.difference(ByteSet::from(b'a'..=b'y')),
+      ByteSet::from_bytes(b"z-_")
+    );
+    assert_eq!(ByteSet::from_set(&(b'a'..=b'z').union(&b"-_"[..])), label);
+    assert!(ByteSet::full().contains(&0xFF) && ByteSet::new().is_empty());
+    assert_eq!(
+      take_while_in_set(label).parse(b"oni-comb_rs!").success(),
+      Some(&b"oni-comb_rs"[..])
+    );
+
+    let input = "a1b2".chars().collect::<Vec<_>>();
+    let letters = elm_of_set(MajorCategory::Letter);
+    let digits = none_of_set(MajorCategory::Letter);
+    assert_eq!(
+      (letters + digits).of_many1().parse(&input).success(),
+      Some(vec![('a', '1'), ('b', '2')])
+    );
+  }
+
+  #[test]
+  fn test_unicode_sets() {
+    init();
+    assert_eq!(GeneralCategory::of('ǅ'), GeneralCategory::TitlecaseLetter);
+    assert_eq!(GeneralCategory::of('\u{2028}'), GeneralCategory::LineSeparator);
+    assert_eq!(GeneralCategory::of('\u{0378}'), GeneralCategory::Unassigned);
+    assert_eq!(GeneralCategory::of('\u{E000}'), GeneralCategory::PrivateUse);
+    assert_eq!(GeneralCategory::of('€').major(), MajorCategory::Symbol);
+    assert!(MajorCategory::Number.contains(&'Ⅻ'));
+
+    for c in ['a', 'Z', 'ª', 'é', '変', 'ᄀ'] {
+      assert!(XidStart.contains(&c), "{}", c);
+    }
+    for c in ['_', '1', '٣', '·', '\u{0301}'] {
+      assert!(!XidStart.contains(&c) && XidContinue.contains(&c), "{}", c);
+    }
+    for c in ['-', ' ', '$', '€'] {
+      assert!(!XidContinue.contains(&c), "{}", c);
+    }
+
+    let ident = utf8_char_of(XidStart.union("_")) * utf8_char_of(XidContinue).of_many0();
+    let input = "_été1 = 1".as_bytes();
+    assert_eq!(
+      (ident.collect() - elm(b' ')).parse(input).success(),
+      Some("_été1".as_bytes())
+    );
+    assert!(utf8_char_of(XidContinue).parse(b"\xFFa").is_failure());
+    assert!(utf8_char_of(XidContinue).parse(b"").is_failure());
+  }
+
+  fn line<'a>() -> Parser<'a, u8, &'a [u8]> {
+    take_until_byte(b'\n') - elm(b'\n')
+  }
+
+  #[test]
+  fn test_parse_partial() {
+    init();
+    assert_eq!(
+      line().parse_partial(b"ab\ncd"),
+      PartialResult::Complete {
+        value: &b"ab"[..],
+        length: 3
+      }
+    );
+    assert!(line().parse_partial(b"ab").is_incomplete());
+    assert!(line().parse_partial(b"").is_incomplete());
+    assert!(seq(b"GET").parse_partial(b"GE").is_incomplete());
+    assert!(seq(b"GET").parse_partial(b"PUT").is_failure());
+    assert!(take_while1(|b: &u8| b.is_ascii_digit())
+      .parse_partial(b"12")
+      .is_incomplete());
+    assert!(take_while1(|b: &u8| b.is_ascii_digit())
+      .parse_partial(b"12;")
+      .is_complete());
+    assert!((elm(b'a') - end()).parse_partial(b"a").is_incomplete());
+    let (digits, number) = (['1', '2'], ['1', '2', 'a']);
+    assert!(regex(r"[0-9]+").parse_partial(&digits).is_incomplete());
+    assert!(regex(r"[0-9]+").parse_partial(&number).is_complete());
+  }
+
+  #[test]
+  fn test_record_decoder() {
+    init();
+    let mut decoder = RecordDecoder::new(|_| line().map(<[u8]>::to_vec));
+    for chunk in [&b"on"[..], b"e\ntw", b"o\n", b"thr"] {
+      decoder.extend(chunk);
+    }
+    assert_eq!(decoder.decode(), Some(Ok(b"one".to_vec())));
+    assert_eq!(decoder.decode(), Some(Ok(b"two".to_vec())));
+    assert_eq!(decoder.decode(), None);
+    assert_eq!(decoder.position(), 8);
+    assert_eq!(decoder.buffered(), b"thr");
+
+    decoder.finish();
+    let error = decoder.decode().unwrap().unwrap_err();
+    assert_eq!(error.position(), 8);
+    assert_eq!(decoder.decode(), None);
+
+    let mut decoder = RecordDecoder::new(|_| take_while0(|b: &u8| b.is_ascii_digit()).map(<[u8]>::len));
+    decoder.extend(b";");
+    assert!(decoder.decode().unwrap().is_err());
+  }
+
+  #[test]
+  fn test_record_decoder_retries() {
+    init();
+    let reads = Rc::new(Cell::new(0));
+    let counter = reads.clone();
+    let mut decoder = RecordDecoder::new(move |_| {
+      let counter = counter.clone();
+      let char = elm_pred(move |b: &u8| {
+        counter.set(counter.get() + 1);
+        *b != b'\n'
+      });
+      char.of_many0().collect().map(<[u8]>::to_vec) - elm(b'\n')
+    })
+    .with_max_record_len(8);
+
+    // A record is retried only when a chunk extends it.
+    decoder.extend(b"abc");
+    assert_eq!(decoder.decode(), None);
+    let reads_after_chunk = reads.get();
+    assert_eq!(decoder.decode(), None);
+    assert_eq!(reads.get(), reads_after_chunk);
+    decoder.extend(b"\n");
+    assert_eq!(decoder.decode(), Some(Ok(b"abc".to_vec())));
+
+    // A record that does not end within the maximum length is an error, and each retry reads at most that length.
+    reads.set(0);
+    let mut position = None;
+    for _ in 0..1000 {
+      decoder.extend(b"x");
+      if let Some(record) = decoder.decode() {
+        position = Some(record.unwrap_err().position());
+        break;
+      }
+    }
+    assert_eq!(position, Some(4));
+    assert!(reads.get() <= 9 * 8, "{}", reads.get());
+    assert_eq!(decoder.decode(), None);
+  }
+
+  #[test]
+  fn test_record_decoder_many_records() {
+    init();
+    let input = (0..10_000).map(|n| format!("{}\n", n)).collect::<String>();
+    let mut decoder = RecordDecoder::new(|_| line().map(<[u8]>::to_vec));
+    assert_eq!(decoder.max_record_len(), DEFAULT_MAX_RECORD_LEN);
+    decoder.extend(input.as_bytes());
+    for n in 0..10_000 {
+      assert_eq!(decoder.decode(), Some(Ok(n.to_string().into_bytes())));
+    }
+    assert_eq!(decoder.decode(), None);
+    assert_eq!(decoder.position(), input.len());
+    assert!(decoder.buffered().is_empty());
+
+    // Records that were decoded before a compaction keep their positions.
+    decoder.extend(b"last\n");
+    assert_eq!(decoder.decode(), Some(Ok(b"last".to_vec())));
+    assert_eq!(decoder.position(), input.len() + 5);
+  }
+
+  #[test]
+  fn test_record_decoder_with_limits() {
+    init();
+    let mut decoder =
+      RecordDecoder::new(|_| elm_pred(|b: &u8| *b != b'\n').of_many0().collect().map(<[u8]>::to_vec) - elm(b'\n'))
+        .with_limits(ParseLimits::new().with_max_repetitions(4));
+    decoder.extend(b"abc\nabcdefgh\n");
+    assert_eq!(decoder.decode(), Some(Ok(b"abc".to_vec())));
+    let error = decoder.decode().unwrap().unwrap_err();
+    assert_eq!(error.position(), 4);
+    assert_eq!(decoder.decode(), None);
+
+    // The limits also apply to the last record once the input is finished.
+    let mut decoder = RecordDecoder::new(|_| elm_pred(|b: &u8| *b != b'\n').of_many0().collect().map(<[u8]>::len))
+      .with_limits(ParseLimits::new().with_max_repetitions(4));
+    decoder.extend(b"abcdefgh");
+    decoder.finish();
+    assert!(decoder.decode().unwrap().is_err());
+  }
+
+  #[cfg(feature = "async")]
+  #[test]
+  fn test_record_stream() {
+    use futures_util::{stream, StreamExt};
+    init();
+    let chunks = stream::iter([Ok(&b"a\nb"[..]), Ok(b"\n"), Err("broken pipe"), Ok(b"c\n")]);
+    let records = RecordStream::new(chunks, |_| line().map(<[u8]>::to_vec));
+    let records = futures_executor::block_on(records.collect::<Vec<_>>());
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[0].as_ref().unwrap(), b"a");
+    assert_eq!(records[1].as_ref().unwrap(), b"b");
+    assert!(matches!(records[2], Err(StreamError::Read("broken pipe"))));
+  }
+
+  #[cfg(feature = "tokio")]
+  #[tokio::test]
+  async fn test_record_stream_tokio() {
+    use futures_util::StreamExt;
+    use tokio::io::AsyncWriteExt;
+    init();
+    let (mut writer, reader) = tokio::io::duplex(4);
+    let write = async move {
+      for chunk in [&b"GET /a\nGET"[..], b" /b", b"\nGET /c\n"] {
+        writer.write_all(chunk).await.unwrap();
+      }
+    };
+    let records = RecordStream::from_tokio(reader, |_| line().map(<[u8]>::to_vec));
+    let (_, records) = tokio::join!(write, records.map(Result::unwrap).collect::<Vec<_>>());
+    assert_eq!(
+      records,
+      vec![b"GET /a".to_vec(), b"GET /b".to_vec(), b"GET /c".to_vec()]
+    );
+  }
+
+  #[cfg(feature = "futures-io")]
+  #[test]
+  fn test_record_stream_futures_io() {
+    use futures_util::StreamExt;
+    init();
+    let reader = futures_util::io::Cursor::new(b"1,22,333,4".to_vec());
+    let records = RecordStream::from_futures_io(reader, |_| {
+      take_while_in_set(b'0'..=b'9').map(<[u8]>::len) - elm(b',').opt()
+    });
+    let records = futures_executor::block_on(records.map(Result::unwrap).collect::<Vec<_>>());
+    assert_eq!(records, vec![1, 2, 3, 1]);
+  }
 }
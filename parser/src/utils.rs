@@ -1,5 +1,15 @@
+pub use byte_set::*;
+pub use grammar::*;
 pub use range::*;
 pub use set::*;
+pub use syntax::*;
+pub use unicode::*;
 
+mod byte_set;
+mod grammar;
 mod range;
 mod set;
+mod syntax;
+mod unicode;
+#[rustfmt::skip]
+mod unicode_tables;
@@ -0,0 +1,164 @@
+use std::fmt;
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+use crate::utils::Set;
+
+/// A set of bytes backed by a 256-bit bitmap, so that lookups take constant time.<br/>
+/// 256ビットのビットマップによるバイトの集合。要素の検索は定数時間で行われる。
+///
+/// Any set of bytes can be turned into a `ByteSet` with [ByteSet::from_set], e.g. a composition of [crate::utils::SetOps]
+/// that would otherwise be evaluated element by element.
+///
+/// # Example
+///
+/// ```rust
+/// # use oni_comb_parser_rs::prelude::*;
+///
+/// const HEX: ByteSet = ByteSet::range(b'0', b'9').union(ByteSet::range(b'a', b'f'));
+///
+/// assert!(HEX.contains(&b'c'));
+/// assert!(!HEX.contains(&b'g'));
+/// assert_eq!(HEX.len(), 16);
+///
+/// let parser = elm_of_set(HEX).of_many1().collect();
+/// assert_eq!(parser.parse(b"c0ffee!").success().unwrap(), b"c0ffee");
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ByteSet {
+  bits: [u64; 4],
+}
+
+impl ByteSet {
+  /// Returns the empty set.
+  pub const fn new() -> Self {
+    Self { bits: [0; 4] }
+  }
+
+  /// Returns the set of all bytes.
+  pub const fn full() -> Self {
+    Self { bits: [u64::MAX; 4] }
+  }
+
+  /// Returns the set of the given bytes.
+  pub const fn from_bytes(bytes: &[u8]) -> Self {
+    let mut set = Self::new();
+    let mut index = 0;
+    while index < bytes.len() {
+      set = set.with(bytes[index]);
+      index += 1;
+    }
+    set
+  }
+
+  /// Returns the set of the bytes from `start` to `end`, inclusive.
+  pub const fn range(start: u8, end: u8) -> Self {
+    let mut set = Self::new();
+    let mut byte = start as usize;
+    while byte <= end as usize {
+      set = set.with(byte as u8);
+      byte += 1;
+    }
+    set
+  }
+
+  /// Returns the set of the bytes contained in the given set.
+  pub fn from_set<S: Set<u8> + ?Sized>(set: &S) -> Self {
+    (0..=u8::MAX).filter(|b| set.contains(b)).collect()
+  }
+
+  /// Returns this set with the byte added.
+  pub const fn with(mut self, byte: u8) -> Self {
+    self.bits[(byte >> 6) as usize] |= 1 << (byte & 63);
+    self
+  }
+
+  /// Adds the byte to the set.
+  pub fn insert(&mut self, byte: u8) {
+    *self = self.with(byte);
+  }
+
+  pub const fn contains(&self, byte: &u8) -> bool {
+    self.bits[(*byte >> 6) as usize] & (1 << (*byte & 63)) != 0
+  }
+
+  /// Returns the number of bytes in the set.
+  pub const fn len(&self) -> usize {
+    (self.bits[0].count_ones() + self.bits[1].count_ones() + self.bits[2].count_ones() + self.bits[3].count_ones())
+      as usize
+  }
+
+  pub const fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Returns the set of the bytes contained in either of the sets.
+  pub const fn union(self, other: ByteSet) -> Self {
+    let [a0, a1, a2, a3] = self.bits;
+    let [b0, b1, b2, b3] = other.bits;
+    Self {
+      bits: [a0 | b0, a1 | b1, a2 | b2, a3 | b3],
+    }
+  }
+
+  /// Returns the set of the bytes contained in this set but not in the other.
+  pub const fn difference(self, other: ByteSet) -> Self {
+    let [a0, a1, a2, a3] = self.bits;
+    let [b0, b1, b2, b3] = other.bits;
+    Self {
+      bits: [a0 & !b0, a1 & !b1, a2 & !b2, a3 & !b3],
+    }
+  }
+
+  /// Returns the set of the bytes not contained in this set.
+  pub const fn negate(self) -> Self {
+    Self::full().difference(self)
+  }
+
+  /// Returns the bytes of the set in ascending order.
+  pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+    (0..=u8::MAX).filter(move |b| self.contains(b))
+  }
+}
+
+impl Debug for ByteSet {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_set().entries(self.iter()).finish()
+  }
+}
+
+impl Set<u8> for ByteSet {
+  fn contains(&self, elem: &u8) -> bool {
+    ByteSet::contains(self, elem)
+  }
+
+  fn to_str(&self) -> &str {
+    "<byte set>"
+  }
+}
+
+impl FromIterator<u8> for ByteSet {
+  fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Self {
+    let mut set = Self::new();
+    iter.into_iter().for_each(|byte| set.insert(byte));
+    set
+  }
+}
+
+impl From<&[u8]> for ByteSet {
+  fn from(bytes: &[u8]) -> Self {
+    Self::from_bytes(bytes)
+  }
+}
+
+impl<const N: usize> From<&[u8; N]> for ByteSet {
+  fn from(bytes: &[u8; N]) -> Self {
+    Self::from_bytes(bytes)
+  }
+}
+
+impl From<RangeInclusive<u8>> for ByteSet {
+  fn from(range: RangeInclusive<u8>) -> Self {
+    Self::range(*range.start(), *range.end())
+  }
+}
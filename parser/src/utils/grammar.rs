@@ -0,0 +1,300 @@
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+
+const DEFAULT_MAX_DEPTH: usize = 16;
+
+/// A small deterministic random number generator (SplitMix64) used to generate inputs from a [Grammar].<br/>
+/// [Grammar]から入力を生成するための決定的な乱数生成器(SplitMix64)。
+#[derive(Debug, Clone)]
+pub struct GrammarRng {
+  state: u64,
+}
+
+impl GrammarRng {
+  /// Creates a new generator from the given seed.
+  pub fn new(seed: u64) -> Self {
+    Self { state: seed }
+  }
+
+  /// Returns the next random value.
+  pub fn next_u64(&mut self) -> u64 {
+    self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = self.state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+  }
+
+  /// Returns a random value between `min` and `max` (inclusive).
+  pub fn next_usize(&mut self, min: usize, max: usize) -> usize {
+    if max <= min {
+      return min;
+    }
+    min + (self.next_u64() % (max - min + 1) as u64) as usize
+  }
+}
+
+/// A description of a grammar that generates inputs for fuzzing a parser.<br/>
+/// パーサーをファジングする入力を生成する文法の記述。
+///
+/// A [crate::prelude::Syntax] derives the grammar of the inputs it accepts, see `Syntax::grammar`, so the generated
+/// inputs follow the parser as it changes. A grammar can also be written by hand with the constructors below, for a
+/// parser without a syntax.
+///
+/// When the nesting exceeds the maximum depth, [Grammar::Choice] always picks its first alternative and
+/// [Grammar::Repeat] repeats the minimum number of times, so recursive grammars should list a terminating
+/// alternative first.
+#[derive(Clone)]
+pub enum Grammar {
+  /// A fixed string.
+  Literal(String),
+  /// A character in the range (inclusive).
+  CharRange(char, char),
+  /// One of the characters.
+  OneOf(Vec<char>),
+  /// A sequence of grammars.
+  Seq(Vec<Grammar>),
+  /// One of the alternatives.
+  Choice(Vec<Grammar>),
+  /// A repetition between `min` and `max` times (inclusive).
+  Repeat {
+    grammar: Box<Grammar>,
+    min: usize,
+    max: usize,
+  },
+  /// A lazily constructed grammar, for recursive definitions.
+  Lazy(Rc<dyn Fn() -> Grammar>),
+}
+
+impl fmt::Debug for Grammar {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Grammar::Literal(s) => f.debug_tuple("Literal").field(s).finish(),
+      Grammar::CharRange(start, end) => f.debug_tuple("CharRange").field(start).field(end).finish(),
+      Grammar::OneOf(chars) => f.debug_tuple("OneOf").field(chars).finish(),
+      Grammar::Seq(grammars) => f.debug_tuple("Seq").field(grammars).finish(),
+      Grammar::Choice(grammars) => f.debug_tuple("Choice").field(grammars).finish(),
+      Grammar::Repeat { grammar, min, max } => f
+        .debug_struct("Repeat")
+        .field("grammar", grammar)
+        .field("min", min)
+        .field("max", max)
+        .finish(),
+      Grammar::Lazy(_) => write!(f, "Lazy"),
+    }
+  }
+}
+
+impl Grammar {
+  pub fn literal(s: &str) -> Self {
+    Grammar::Literal(s.to_string())
+  }
+
+  pub fn char_range(start: char, end: char) -> Self {
+    Grammar::CharRange(start, end)
+  }
+
+  pub fn one_of(chars: &str) -> Self {
+    Grammar::OneOf(chars.chars().collect())
+  }
+
+  pub fn seq(grammars: Vec<Grammar>) -> Self {
+    Grammar::Seq(grammars)
+  }
+
+  pub fn choice(grammars: Vec<Grammar>) -> Self {
+    Grammar::Choice(grammars)
+  }
+
+  pub fn repeat(grammar: Grammar, min: usize, max: usize) -> Self {
+    Grammar::Repeat {
+      grammar: Box::new(grammar),
+      min,
+      max,
+    }
+  }
+
+  pub fn opt(grammar: Grammar) -> Self {
+    Self::repeat(grammar, 0, 1)
+  }
+
+  pub fn lazy<F>(f: F) -> Self
+  where
+    F: Fn() -> Grammar + 'static, {
+    Grammar::Lazy(Rc::new(f))
+  }
+
+  /// Generates a random input that matches the grammar.
+  pub fn generate(&self, rng: &mut GrammarRng) -> String {
+    self.generate_with_depth(rng, DEFAULT_MAX_DEPTH)
+  }
+
+  /// Generates a random input that matches the grammar, limiting the nesting to `max_depth`.
+  pub fn generate_with_depth(&self, rng: &mut GrammarRng, max_depth: usize) -> String {
+    let mut out = String::new();
+    self.generate_into(rng, max_depth, &mut out);
+    out
+  }
+
+  fn generate_into(&self, rng: &mut GrammarRng, depth: usize, out: &mut String) {
+    match self {
+      Grammar::Literal(s) => out.push_str(s),
+      Grammar::CharRange(start, end) => {
+        let n = rng.next_usize(*start as usize, *end as usize) as u32;
+        // Surrogate code points are not chars, fall back to the start of the range.
+        out.push(char::from_u32(n).unwrap_or(*start));
+      }
+      Grammar::OneOf(chars) => {
+        if !chars.is_empty() {
+          out.push(chars[rng.next_usize(0, chars.len() - 1)]);
+        }
+      }
+      Grammar::Seq(grammars) => {
+        for grammar in grammars {
+          grammar.generate_into(rng, depth, out);
+        }
+      }
+      Grammar::Choice(grammars) => {
+        if grammars.is_empty() {
+          return;
+        }
+        let index = if depth == 0 {
+          0
+        } else {
+          rng.next_usize(0, grammars.len() - 1)
+        };
+        grammars[index].generate_into(rng, depth.saturating_sub(1), out);
+      }
+      Grammar::Repeat { grammar, min, max } => {
+        let n = if depth == 0 { *min } else { rng.next_usize(*min, *max) };
+        for _ in 0..n {
+          grammar.generate_into(rng, depth.saturating_sub(1), out);
+        }
+      }
+      Grammar::Lazy(f) => f().generate_into(rng, depth.saturating_sub(1), out),
+    }
+  }
+
+  /// Generates a (most likely) invalid input by mutating a valid input of the grammar.
+  ///
+  /// The mutation deletes, inserts, duplicates, swaps or truncates characters.
+  pub fn generate_invalid(&self, rng: &mut GrammarRng) -> String {
+    let valid = self.generate(rng);
+    Self::mutate(&valid, rng)
+  }
+
+  /// Mutates an input by deleting, inserting, duplicating, swapping or truncating characters.
+  pub fn mutate(input: &str, rng: &mut GrammarRng) -> String {
+    const NOISE: &[char] = &[
+      ' ', '\0', '\n', '"', '\'', '\\', '/', ':', '.', '-', '%', '{', '[', 'é', '\u{FFFD}',
+    ];
+    let mut chars = input.chars().collect::<Vec<_>>();
+    let mutations = rng.next_usize(1, 3);
+    for _ in 0..mutations {
+      let len = chars.len();
+      match rng.next_usize(0, 4) {
+        0 if len > 0 => {
+          chars.remove(rng.next_usize(0, len - 1));
+        }
+        1 if len > 0 => {
+          let c = chars[rng.next_usize(0, len - 1)];
+          chars.insert(rng.next_usize(0, len), c);
+        }
+        2 if len > 1 => {
+          let i = rng.next_usize(0, len - 2);
+          chars.swap(i, i + 1);
+        }
+        3 if len > 0 => chars.truncate(rng.next_usize(0, len - 1)),
+        _ => {
+          let c = NOISE[rng.next_usize(0, NOISE.len() - 1)];
+          chars.insert(rng.next_usize(0, len), c);
+        }
+      }
+    }
+    chars.into_iter().collect()
+  }
+
+  /// Converts the grammar into a `prop_check_rs` generator of valid inputs.
+  #[cfg(feature = "prop-check")]
+  pub fn to_gen(&self) -> prop_check_rs::gen::Gen<String> {
+    let grammar = self.clone();
+    prop_check_rs::gen::Gens::one_u64().map(move |seed| grammar.generate(&mut GrammarRng::new(seed)))
+  }
+
+  /// Converts the grammar into a `prop_check_rs` generator of mutated, most likely invalid, inputs.
+  #[cfg(feature = "prop-check")]
+  pub fn to_invalid_gen(&self) -> prop_check_rs::gen::Gen<String> {
+    let grammar = self.clone();
+    prop_check_rs::gen::Gens::one_u64().map(move |seed| grammar.generate_invalid(&mut GrammarRng::new(seed)))
+  }
+}
+
+/// Checks properties of a parser against inputs generated from a [Grammar].<br/>
+/// [Grammar]から生成した入力でパーサーの性質を検査する。
+#[derive(Debug, Clone)]
+pub struct GrammarFuzzer {
+  grammar: Grammar,
+  rng: GrammarRng,
+  max_depth: usize,
+}
+
+impl GrammarFuzzer {
+  pub fn new(grammar: Grammar, seed: u64) -> Self {
+    Self {
+      grammar,
+      rng: GrammarRng::new(seed),
+      max_depth: DEFAULT_MAX_DEPTH,
+    }
+  }
+
+  pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+    self.max_depth = max_depth;
+    self
+  }
+
+  /// Returns a random valid input.
+  pub fn valid_input(&mut self) -> String {
+    self.grammar.generate_with_depth(&mut self.rng, self.max_depth)
+  }
+
+  /// Returns a random mutated, most likely invalid, input.
+  pub fn invalid_input(&mut self) -> String {
+    let valid = self.valid_input();
+    Grammar::mutate(&valid, &mut self.rng)
+  }
+
+  /// Checks that `f` never panics, for both valid and mutated inputs.
+  ///
+  /// Returns the first input that caused a panic as the error.
+  pub fn check_never_panics<F>(&mut self, count: usize, f: F) -> Result<(), String>
+  where
+    F: Fn(&str), {
+    for i in 0..count {
+      let input = if i % 2 == 0 {
+        self.valid_input()
+      } else {
+        self.invalid_input()
+      };
+      if panic::catch_unwind(AssertUnwindSafe(|| f(&input))).is_err() {
+        return Err(input);
+      }
+    }
+    Ok(())
+  }
+
+  /// Checks that `f` returns the input itself for every valid input, e.g. `print(parse(x)) == x`.
+  ///
+  /// `f` returns `None` when the input could not be parsed. Returns the first failing input as the error.
+  pub fn check_roundtrip<F>(&mut self, count: usize, f: F) -> Result<(), String>
+  where
+    F: Fn(&str) -> Option<String>, {
+    for _ in 0..count {
+      let input = self.valid_input();
+      if f(&input).as_deref() != Some(input.as_str()) {
+        return Err(input);
+      }
+    }
+    Ok(())
+  }
+}
@@ -96,3 +96,79 @@ impl_set_for_array!(
   32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60,
   61, 62, 63, 64
 );
+
+impl<T, S: Set<T> + ?Sized> Set<T> for &S {
+  fn contains(&self, elem: &T) -> bool {
+    (**self).contains(elem)
+  }
+
+  fn to_str(&self) -> &str {
+    (**self).to_str()
+  }
+}
+
+/// Operations composing sets into new sets.<br/>
+/// 集合を組み合わせて新しい集合を作る操作。
+///
+/// # Example
+///
+/// ```rust
+/// # use oni_comb_parser_rs::prelude::*;
+///
+/// let hex = ('0'..='9').union('a'..='f').union('A'..='F');
+/// assert!(hex.contains(&'b'));
+/// assert!(!hex.contains(&'g'));
+///
+/// let consonants = ('a'..='z').difference("aeiou");
+/// assert!(consonants.contains(&'x'));
+/// assert!(!consonants.contains(&'e'));
+/// assert!(consonants.negate().contains(&'e'));
+/// ```
+pub trait SetOps<T>: Set<T> + Sized {
+  /// Returns the set of the elements contained in either of the sets.
+  fn union<S: Set<T>>(self, other: S) -> Union<Self, S> {
+    Union(self, other)
+  }
+
+  /// Returns the set of the elements contained in this set but not in the other.
+  fn difference<S: Set<T>>(self, other: S) -> Difference<Self, S> {
+    Difference(self, other)
+  }
+
+  /// Returns the set of the elements not contained in this set.
+  fn negate(self) -> Negate<Self> {
+    Negate(self)
+  }
+}
+
+impl<T, S: Set<T>> SetOps<T> for S {}
+
+/// The union of two sets, see [SetOps::union].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Union<A, B>(A, B);
+
+impl<T, A: Set<T>, B: Set<T>> Set<T> for Union<A, B> {
+  fn contains(&self, elem: &T) -> bool {
+    self.0.contains(elem) || self.1.contains(elem)
+  }
+}
+
+/// The difference of two sets, see [SetOps::difference].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Difference<A, B>(A, B);
+
+impl<T, A: Set<T>, B: Set<T>> Set<T> for Difference<A, B> {
+  fn contains(&self, elem: &T) -> bool {
+    self.0.contains(elem) && !self.1.contains(elem)
+  }
+}
+
+/// The complement of a set, see [SetOps::negate].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Negate<A>(A);
+
+impl<T, A: Set<T>> Set<T> for Negate<A> {
+  fn contains(&self, elem: &T) -> bool {
+    !self.0.contains(elem)
+  }
+}
@@ -0,0 +1,401 @@
+use std::fmt::Debug;
+use std::ops::{Add, BitOr, Mul, Sub};
+use std::rc::Rc;
+
+use crate::extension::parsers::ConversionParsers;
+use crate::internal::ParsersImpl;
+use crate::prelude::*;
+
+type Printer<'a, I, A> = Rc<dyn Fn(&A, &mut Vec<I>) -> bool + 'a>;
+
+/// A syntax describes both how to parse a value and how to print it back, from one definition.<br/>
+/// 値の解析と出力を一つの定義で記述する構文。
+///
+/// A value is mapped with [Syntax::iso] or [Syntax::partial_iso] instead of `map`, giving the conversion in both
+/// directions, so that `print(parse(s)) == s` holds for every input `s` the syntax accepts, as long as every part
+/// prints the text it parsed. The combinators below do, but a printer given to [Syntax::new] may normalize the value,
+/// e.g. print it with `Display`, and then only `parse(print(v)) == v` holds.
+///
+/// The combinators also derive a [Grammar] of the inputs the syntax accepts, see [Syntax::grammar].
+///
+/// # Example
+///
+/// ```rust
+/// # use oni_comb_parser_rs::prelude::*;
+///
+/// let digit = Syntax::elm_pred(|b: &u8| b.is_ascii_digit());
+/// let number = digit
+///   .many1()
+///   .partial_iso(|digits| String::from_utf8(digits).ok(), |s: &String| Some(s.clone().into_bytes()));
+/// let pair = Syntax::literal(b"(") * number.clone() - Syntax::literal(b",") + number - Syntax::literal(b")");
+///
+/// let value = pair.parse(b"(12,345)").success().unwrap();
+/// assert_eq!(value, ("12".to_string(), "345".to_string()));
+/// assert_eq!(pair.print(&value), Some(b"(12,345)".to_vec()));
+/// assert_eq!(pair.print(&("1".to_string(), "x".to_string())), None);
+/// ```
+pub struct Syntax<'a, I, A> {
+  parser: Parser<'a, I, A>,
+  printer: Printer<'a, I, A>,
+  grammar: Option<Grammar>,
+}
+
+impl<'a, I, A> Clone for Syntax<'a, I, A> {
+  fn clone(&self) -> Self {
+    Self {
+      parser: self.parser.clone(),
+      printer: self.printer.clone(),
+      grammar: self.grammar.clone(),
+    }
+  }
+}
+
+/// The maximum number of repetitions of `many0` and the like in a derived [Grammar].
+const GRAMMAR_MAX_REPEAT: usize = 4;
+
+impl<'a, I, A> Syntax<'a, I, A> {
+  /// Creates a syntax from a parser and a printer.
+  ///
+  /// The printer appends the representation of the value to the output and returns false if the value cannot be
+  /// printed. It must print only what the parser accepts.
+  ///
+  /// The syntax has no [Grammar] unless one is given with [Syntax::with_grammar].
+  pub fn new<F>(parser: Parser<'a, I, A>, printer: F) -> Self
+  where
+    F: Fn(&A, &mut Vec<I>) -> bool + 'a, {
+    Self::with_parts(parser, printer, None)
+  }
+
+  fn with_parts<F>(parser: Parser<'a, I, A>, printer: F, grammar: Option<Grammar>) -> Self
+  where
+    F: Fn(&A, &mut Vec<I>) -> bool + 'a, {
+    Self {
+      parser,
+      printer: Rc::new(printer),
+      grammar,
+    }
+  }
+
+  /// Returns the syntax with the given grammar of the inputs it accepts, for a syntax created with [Syntax::new].
+  pub fn with_grammar(mut self, grammar: Grammar) -> Self {
+    self.grammar = Some(grammar);
+    self
+  }
+
+  /// Returns the grammar of the inputs the syntax accepts, derived from the syntaxes it is built from.
+  ///
+  /// Returns `None` if a part of the syntax has no grammar, i.e. was created with [Syntax::new] without
+  /// [Syntax::with_grammar], or is an element predicate no ASCII character satisfies. The grammar generates ASCII
+  /// inputs, and may generate inputs a greedy repetition of the parser rejects.
+  pub fn grammar(&self) -> Option<Grammar> {
+    self.grammar.clone()
+  }
+
+  /// Returns the parser of the syntax.
+  pub fn parser(&self) -> Parser<'a, I, A> {
+    self.parser.clone()
+  }
+
+  /// Parses the input.
+  pub fn parse(&self, input: &'a [I]) -> ParseResult<'a, I, A> {
+    self.parser.parse(input)
+  }
+
+  /// Prints the value, or returns `None` if the value cannot be printed by the syntax.
+  pub fn print(&self, value: &A) -> Option<Vec<I>> {
+    let mut out = Vec::new();
+    if self.print_to(value, &mut out) {
+      Some(out)
+    } else {
+      None
+    }
+  }
+
+  /// Appends the printed value to the output, returning false if the value cannot be printed.
+  ///
+  /// The output is left unchanged on failure.
+  pub fn print_to(&self, value: &A, out: &mut Vec<I>) -> bool {
+    let len = out.len();
+    let printed = (self.printer)(value, out);
+    if !printed {
+      out.truncate(len);
+    }
+    printed
+  }
+}
+
+impl<'a, I: Element + 'a> Syntax<'a, I, I> {
+  /// Returns a syntax that parses and prints an element that satisfies the predicate.
+  pub fn elm_pred<F>(f: F) -> Self
+  where
+    F: Fn(&I) -> bool + Clone + 'static, {
+    let chars = (0..0x80u8)
+      .filter_map(I::from_ascii)
+      .filter(|elm| f(elm))
+      .map(Element::to_char)
+      .collect::<Vec<_>>();
+    let grammar = (!chars.is_empty()).then_some(Grammar::OneOf(chars));
+    let predicate = f.clone();
+    Syntax::with_parts(
+      elm_pred(f),
+      move |value, out| {
+        if predicate(value) {
+          out.push(*value);
+          true
+        } else {
+          false
+        }
+      },
+      grammar,
+    )
+  }
+}
+
+impl<'a, I: Element + 'a> Syntax<'a, I, ()> {
+  /// Returns a syntax that parses and prints the given sequence, without a value.
+  pub fn literal(tag: &'static [I]) -> Self {
+    let grammar = tag
+      .iter()
+      .all(Element::is_ascii)
+      .then(|| Grammar::Literal(tag.iter().map(|elm| elm.to_char()).collect()));
+    Syntax::with_parts(
+      seq(tag).map(|_| ()),
+      move |_, out| {
+        out.extend_from_slice(tag);
+        true
+      },
+      grammar,
+    )
+  }
+}
+
+impl<'a, I, A> Syntax<'a, I, A>
+where
+  I: Element + 'a,
+  A: Clone + Debug + 'a,
+{
+  /// Returns a syntax that consumes and prints nothing, with the given value.
+  ///
+  /// Printing succeeds only for values equal to the given one.
+  pub fn pure(value: A) -> Syntax<'a, I, A>
+  where
+    A: PartialEq, {
+    let expected = value.clone();
+    Syntax::with_parts(
+      successful(value),
+      move |value, _| *value == expected,
+      Some(Grammar::Seq(vec![])),
+    )
+  }
+
+  /// Returns a syntax that is constructed lazily, for recursive definitions.
+  ///
+  /// Its grammar assumes that the constructed syntax has a grammar.
+  pub fn lazy<F>(f: F) -> Syntax<'a, I, A>
+  where
+    F: Fn() -> Syntax<'a, I, A> + Clone + 'static, {
+    let (g, h) = (f.clone(), f.clone());
+    let grammar = Grammar::lazy(move || h().grammar.expect("the lazy syntax has no grammar"));
+    Syntax::with_parts(
+      lazy(move || f().parser),
+      move |value, out| g().print_to(value, out),
+      Some(grammar),
+    )
+  }
+
+  /// Maps the value with a pair of functions that are inverse to each other.
+  pub fn iso<B, F, G>(self, forward: F, backward: G) -> Syntax<'a, I, B>
+  where
+    F: Fn(A) -> B + 'a,
+    G: Fn(&B) -> A + 'a,
+    B: Clone + Debug + 'a, {
+    self.partial_iso(move |a| Some(forward(a)), move |b| Some(backward(b)))
+  }
+
+  /// Maps the value with a pair of partial functions that are inverse to each other where both are defined.
+  ///
+  /// Parsing fails when `forward` returns `None`, printing fails when `backward` returns `None`.
+  pub fn partial_iso<B, F, G>(self, forward: F, backward: G) -> Syntax<'a, I, B>
+  where
+    F: Fn(A) -> Option<B> + 'a,
+    G: Fn(&B) -> Option<A> + 'a,
+    B: Clone + Debug + 'a, {
+    let printer = self.printer.clone();
+    Syntax::with_parts(
+      ParsersImpl::map_opt::<I, A, B, (), F>(self.parser, forward),
+      move |value, out| backward(value).is_some_and(|a| printer(&a, out)),
+      self.grammar,
+    )
+  }
+
+  /// Returns a syntax that parses and prints the two syntaxes in sequence.
+  pub fn and_then<B>(self, other: Syntax<'a, I, B>) -> Syntax<'a, I, (A, B)>
+  where
+    B: Clone + Debug + 'a, {
+    let (printer1, printer2) = (self.printer.clone(), other.printer.clone());
+    let grammar = self
+      .grammar
+      .zip(other.grammar)
+      .map(|(g1, g2)| Grammar::seq(vec![g1, g2]));
+    Syntax::with_parts(
+      self.parser.and_then(other.parser),
+      move |(a, b), out| printer1(a, out) && printer2(b, out),
+      grammar,
+    )
+  }
+
+  /// Returns a syntax that tries the alternatives in order, for both parsing and printing.
+  pub fn or(self, other: Syntax<'a, I, A>) -> Syntax<'a, I, A> {
+    let (printer1, printer2) = (self.printer.clone(), other.printer.clone());
+    let grammar = self
+      .grammar
+      .zip(other.grammar)
+      .map(|(g1, g2)| Grammar::choice(vec![g1, g2]));
+    Syntax::with_parts(
+      self.parser.or(other.parser),
+      move |value, out| {
+        let len = out.len();
+        printer1(value, out) || {
+          out.truncate(len);
+          printer2(value, out)
+        }
+      },
+      grammar,
+    )
+  }
+
+  /// Returns a syntax that does not commit to this syntax when it fails, see [OperatorParser::attempt].
+  pub fn attempt(self) -> Syntax<'a, I, A> {
+    Syntax {
+      parser: self.parser.attempt(),
+      printer: self.printer,
+      grammar: self.grammar,
+    }
+  }
+
+  /// Returns a syntax that is optional.
+  pub fn opt(self) -> Syntax<'a, I, Option<A>> {
+    let printer = self.printer.clone();
+    Syntax::with_parts(
+      self.parser.opt(),
+      move |value, out| match value {
+        Some(a) => printer(a, out),
+        None => true,
+      },
+      self.grammar.map(Grammar::opt),
+    )
+  }
+
+  /// Returns a syntax that repeats this syntax zero or more times.
+  pub fn many0(self) -> Syntax<'a, I, Vec<A>> {
+    let printer = self.printer.clone();
+    Syntax::with_parts(
+      self.parser.of_many0(),
+      move |values, out| values.iter().all(|value| printer(value, out)),
+      self.grammar.map(|g| Grammar::repeat(g, 0, GRAMMAR_MAX_REPEAT)),
+    )
+  }
+
+  /// Returns a syntax that repeats this syntax one or more times.
+  pub fn many1(self) -> Syntax<'a, I, Vec<A>> {
+    let printer = self.printer.clone();
+    Syntax::with_parts(
+      self.parser.of_many1(),
+      move |values, out| !values.is_empty() && values.iter().all(|value| printer(value, out)),
+      self.grammar.map(|g| Grammar::repeat(g, 1, GRAMMAR_MAX_REPEAT)),
+    )
+  }
+
+  /// Returns a syntax that repeats this syntax zero or more times, separated by `separator`.
+  pub fn many0_sep(self, separator: Syntax<'a, I, ()>) -> Syntax<'a, I, Vec<A>> {
+    let (printer, sep_printer) = (self.printer.clone(), separator.printer.clone());
+    let grammar = sep_grammar(self.grammar, separator.grammar).map(Grammar::opt);
+    Syntax::with_parts(
+      self.parser.of_many0_sep(separator.parser),
+      move |values, out| print_sep(values, out, &printer, &sep_printer),
+      grammar,
+    )
+  }
+
+  /// Returns a syntax that repeats this syntax one or more times, separated by `separator`.
+  pub fn many1_sep(self, separator: Syntax<'a, I, ()>) -> Syntax<'a, I, Vec<A>> {
+    let (printer, sep_printer) = (self.printer.clone(), separator.printer.clone());
+    let grammar = sep_grammar(self.grammar, separator.grammar);
+    Syntax::with_parts(
+      self.parser.of_many1_sep(separator.parser),
+      move |values, out| !values.is_empty() && print_sep(values, out, &printer, &sep_printer),
+      grammar,
+    )
+  }
+}
+
+/// Returns the grammar of one or more `grammar`s separated by `separator`.
+fn sep_grammar(grammar: Option<Grammar>, separator: Option<Grammar>) -> Option<Grammar> {
+  let (grammar, separator) = (grammar?, separator?);
+  let rest = Grammar::seq(vec![separator, grammar.clone()]);
+  Some(Grammar::seq(vec![
+    grammar,
+    Grammar::repeat(rest, 0, GRAMMAR_MAX_REPEAT - 1),
+  ]))
+}
+
+fn print_sep<'a, I, A>(
+  values: &[A],
+  out: &mut Vec<I>,
+  printer: &Printer<'a, I, A>,
+  sep_printer: &Printer<'a, I, ()>,
+) -> bool {
+  values
+    .iter()
+    .enumerate()
+    .all(|(index, value)| (index == 0 || sep_printer(&(), out)) && printer(value, out))
+}
+
+impl<'a, I, A, B> Add<Syntax<'a, I, B>> for Syntax<'a, I, A>
+where
+  I: Element + 'a,
+  A: Clone + Debug + 'a,
+  B: Clone + Debug + 'a,
+{
+  type Output = Syntax<'a, I, (A, B)>;
+
+  fn add(self, rhs: Syntax<'a, I, B>) -> Self::Output {
+    self.and_then(rhs)
+  }
+}
+
+impl<'a, I, B> Mul<Syntax<'a, I, B>> for Syntax<'a, I, ()>
+where
+  I: Element + 'a,
+  B: Clone + Debug + 'a,
+{
+  type Output = Syntax<'a, I, B>;
+
+  fn mul(self, rhs: Syntax<'a, I, B>) -> Self::Output {
+    self.and_then(rhs).iso(|(_, b)| b, |b| ((), b.clone()))
+  }
+}
+
+impl<'a, I, A> Sub<Syntax<'a, I, ()>> for Syntax<'a, I, A>
+where
+  I: Element + 'a,
+  A: Clone + Debug + 'a,
+{
+  type Output = Syntax<'a, I, A>;
+
+  fn sub(self, rhs: Syntax<'a, I, ()>) -> Self::Output {
+    self.and_then(rhs).iso(|(a, _)| a, |a| (a.clone(), ()))
+  }
+}
+
+impl<'a, I, A> BitOr for Syntax<'a, I, A>
+where
+  I: Element + 'a,
+  A: Clone + Debug + 'a,
+{
+  type Output = Syntax<'a, I, A>;
+
+  fn bitor(self, rhs: Syntax<'a, I, A>) -> Self::Output {
+    self.or(rhs)
+  }
+}
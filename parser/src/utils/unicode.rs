@@ -0,0 +1,197 @@
+use crate::utils::unicode_tables::{GENERAL_CATEGORY, UNICODE_VERSION, XID_CONTINUE, XID_START};
+use crate::utils::Set;
+
+/// The version of Unicode of the tables of [GeneralCategory], [XidStart] and [XidContinue].
+pub const UNICODE_TABLES_VERSION: (u8, u8, u8) = UNICODE_VERSION;
+
+/// The general category of a character, as defined by Unicode.<br/>
+/// Unicodeで定義された文字の一般カテゴリ。
+///
+/// A category is also the [Set] of the characters in it.
+///
+/// # Example
+///
+/// ```rust
+/// # use oni_comb_parser_rs::prelude::*;
+///
+/// assert_eq!(GeneralCategory::of('A'), GeneralCategory::UppercaseLetter);
+/// assert_eq!(GeneralCategory::of('Ж'), GeneralCategory::UppercaseLetter);
+/// assert_eq!(GeneralCategory::of('٣'), GeneralCategory::DecimalNumber);
+///
+/// let input = "٣4५x".chars().collect::<Vec<_>>();
+/// let parser = elm_of_set(GeneralCategory::DecimalNumber).of_many1().map(String::from_iter);
+/// assert_eq!(parser.parse(&input).success().unwrap(), "٣4५");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GeneralCategory {
+  /// `Lu`
+  UppercaseLetter,
+  /// `Ll`
+  LowercaseLetter,
+  /// `Lt`
+  TitlecaseLetter,
+  /// `Lm`
+  ModifierLetter,
+  /// `Lo`
+  OtherLetter,
+  /// `Mn`
+  NonspacingMark,
+  /// `Mc`
+  SpacingMark,
+  /// `Me`
+  EnclosingMark,
+  /// `Nd`
+  DecimalNumber,
+  /// `Nl`
+  LetterNumber,
+  /// `No`
+  OtherNumber,
+  /// `Pc`
+  ConnectorPunctuation,
+  /// `Pd`
+  DashPunctuation,
+  /// `Ps`
+  OpenPunctuation,
+  /// `Pe`
+  ClosePunctuation,
+  /// `Pi`
+  InitialPunctuation,
+  /// `Pf`
+  FinalPunctuation,
+  /// `Po`
+  OtherPunctuation,
+  /// `Sm`
+  MathSymbol,
+  /// `Sc`
+  CurrencySymbol,
+  /// `Sk`
+  ModifierSymbol,
+  /// `So`
+  OtherSymbol,
+  /// `Zs`
+  SpaceSeparator,
+  /// `Zl`
+  LineSeparator,
+  /// `Zp`
+  ParagraphSeparator,
+  /// `Cc`
+  Control,
+  /// `Cf`
+  Format,
+  /// `Co`
+  PrivateUse,
+  /// `Cn`
+  Unassigned,
+}
+
+impl GeneralCategory {
+  /// Returns the general category of the character.
+  pub fn of(c: char) -> GeneralCategory {
+    let c = c as u32;
+    let index = GENERAL_CATEGORY.partition_point(|&(_, end, _)| end < c);
+    match GENERAL_CATEGORY.get(index) {
+      Some(&(start, _, category)) if start <= c => category,
+      _ => GeneralCategory::Unassigned,
+    }
+  }
+
+  /// Returns the major class the category belongs to.
+  pub fn major(self) -> MajorCategory {
+    use GeneralCategory::*;
+    match self {
+      UppercaseLetter | LowercaseLetter | TitlecaseLetter | ModifierLetter | OtherLetter => MajorCategory::Letter,
+      NonspacingMark | SpacingMark | EnclosingMark => MajorCategory::Mark,
+      DecimalNumber | LetterNumber | OtherNumber => MajorCategory::Number,
+      ConnectorPunctuation | DashPunctuation | OpenPunctuation | ClosePunctuation | InitialPunctuation
+      | FinalPunctuation | OtherPunctuation => MajorCategory::Punctuation,
+      MathSymbol | CurrencySymbol | ModifierSymbol | OtherSymbol => MajorCategory::Symbol,
+      SpaceSeparator | LineSeparator | ParagraphSeparator => MajorCategory::Separator,
+      Control | Format | PrivateUse | Unassigned => MajorCategory::Other,
+    }
+  }
+}
+
+impl Set<char> for GeneralCategory {
+  fn contains(&self, elem: &char) -> bool {
+    GeneralCategory::of(*elem) == *self
+  }
+}
+
+/// The major class of [GeneralCategory], e.g. `L` for all the letters.<br/>
+/// [GeneralCategory]の大分類。例えば`L`はすべての文字種の字母を表す。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MajorCategory {
+  /// `L`
+  Letter,
+  /// `M`
+  Mark,
+  /// `N`
+  Number,
+  /// `P`
+  Punctuation,
+  /// `S`
+  Symbol,
+  /// `Z`
+  Separator,
+  /// `C`
+  Other,
+}
+
+impl Set<char> for MajorCategory {
+  fn contains(&self, elem: &char) -> bool {
+    GeneralCategory::of(*elem).major() == *self
+  }
+}
+
+/// The set of the characters with the `XID_Start` property, which can start an identifier (UAX #31).<br/>
+/// 識別子の先頭に使える`XID_Start`プロパティを持つ文字の集合(UAX #31)。
+///
+/// `_` is not included, see [XidContinue].
+///
+/// # Example
+///
+/// ```rust
+/// # use oni_comb_parser_rs::prelude::*;
+///
+/// let input = "変数_1 = 0".chars().collect::<Vec<_>>();
+/// let digits = ['1', '2'];
+///
+/// let ident = (elm_of_set(XidStart.union("_")) + elm_of_set(XidContinue).of_many0())
+///   .map(|(head, tail)| std::iter::once(head).chain(tail).collect::<String>());
+///
+/// assert_eq!(ident.parse(&input).success().unwrap(), "変数_1");
+/// assert!(ident.parse(&digits).is_failure());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct XidStart;
+
+impl Set<char> for XidStart {
+  fn contains(&self, elem: &char) -> bool {
+    elem.is_ascii_alphabetic() || (!elem.is_ascii() && in_ranges(XID_START, *elem))
+  }
+
+  fn to_str(&self) -> &str {
+    "<XID_Start>"
+  }
+}
+
+/// The set of the characters with the `XID_Continue` property, which can continue an identifier (UAX #31).<br/>
+/// 識別子の2文字目以降に使える`XID_Continue`プロパティを持つ文字の集合(UAX #31)。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct XidContinue;
+
+impl Set<char> for XidContinue {
+  fn contains(&self, elem: &char) -> bool {
+    elem.is_ascii_alphanumeric() || *elem == '_' || (!elem.is_ascii() && in_ranges(XID_CONTINUE, *elem))
+  }
+
+  fn to_str(&self) -> &str {
+    "<XID_Continue>"
+  }
+}
+
+fn in_ranges(ranges: &[(u32, u32)], c: char) -> bool {
+  let c = c as u32;
+  let index = ranges.partition_point(|&(_, end)| end < c);
+  ranges.get(index).is_some_and(|&(start, _)| start <= c)
+}
@@ -0,0 +1,1111 @@
+// Generated by scripts/gen-unicode-tables.py from Unicode 15.1.0. Do not edit.
+
+use super::GeneralCategory;
+
+pub(crate) const UNICODE_VERSION: (u8, u8, u8) = (15, 1, 0);
+
+const LU: GeneralCategory = GeneralCategory::UppercaseLetter;
+const LL: GeneralCategory = GeneralCategory::LowercaseLetter;
+const LT: GeneralCategory = GeneralCategory::TitlecaseLetter;
+const LM: GeneralCategory = GeneralCategory::ModifierLetter;
+const LO: GeneralCategory = GeneralCategory::OtherLetter;
+const MN: GeneralCategory = GeneralCategory::NonspacingMark;
+const MC: GeneralCategory = GeneralCategory::SpacingMark;
+const ME: GeneralCategory = GeneralCategory::EnclosingMark;
+const ND: GeneralCategory = GeneralCategory::DecimalNumber;
+const NL: GeneralCategory = GeneralCategory::LetterNumber;
+const NO: GeneralCategory = GeneralCategory::OtherNumber;
+const PC: GeneralCategory = GeneralCategory::ConnectorPunctuation;
+const PD: GeneralCategory = GeneralCategory::DashPunctuation;
+const PS: GeneralCategory = GeneralCategory::OpenPunctuation;
+const PE: GeneralCategory = GeneralCategory::ClosePunctuation;
+const PI: GeneralCategory = GeneralCategory::InitialPunctuation;
+const PF: GeneralCategory = GeneralCategory::FinalPunctuation;
+const PO: GeneralCategory = GeneralCategory::OtherPunctuation;
+const SM: GeneralCategory = GeneralCategory::MathSymbol;
+const SC: GeneralCategory = GeneralCategory::CurrencySymbol;
+const SK: GeneralCategory = GeneralCategory::ModifierSymbol;
+const SO: GeneralCategory = GeneralCategory::OtherSymbol;
+const ZS: GeneralCategory = GeneralCategory::SpaceSeparator;
+const ZL: GeneralCategory = GeneralCategory::LineSeparator;
+const ZP: GeneralCategory = GeneralCategory::ParagraphSeparator;
+const CC: GeneralCategory = GeneralCategory::Control;
+const CF: GeneralCategory = GeneralCategory::Format;
+const CO: GeneralCategory = GeneralCategory::PrivateUse;
+
+pub(crate) const GENERAL_CATEGORY: &[(u32, u32, GeneralCategory)] = &[
+  (0x0000, 0x001F, CC), (0x0020, 0x0020, ZS), (0x0021, 0x0023, PO), (0x0024, 0x0024, SC),
+  (0x0025, 0x0027, PO), (0x0028, 0x0028, PS), (0x0029, 0x0029, PE), (0x002A, 0x002A, PO),
+  (0x002B, 0x002B, SM), (0x002C, 0x002C, PO), (0x002D, 0x002D, PD), (0x002E, 0x002F, PO),
+  (0x0030, 0x0039, ND), (0x003A, 0x003B, PO), (0x003C, 0x003E, SM), (0x003F, 0x0040, PO),
+  (0x0041, 0x005A, LU), (0x005B, 0x005B, PS), (0x005C, 0x005C, PO), (0x005D, 0x005D, PE),
+  (0x005E, 0x005E, SK), (0x005F, 0x005F, PC), (0x0060, 0x0060, SK), (0x0061, 0x007A, LL),
+  (0x007B, 0x007B, PS), (0x007C, 0x007C, SM), (0x007D, 0x007D, PE), (0x007E, 0x007E, SM),
+  (0x007F, 0x009F, CC), (0x00A0, 0x00A0, ZS), (0x00A1, 0x00A1, PO), (0x00A2, 0x00A5, SC),
+  (0x00A6, 0x00A6, SO), (0x00A7, 0x00A7, PO), (0x00A8, 0x00A8, SK), (0x00A9, 0x00A9, SO),
+  (0x00AA, 0x00AA, LO), (0x00AB, 0x00AB, PI), (0x00AC, 0x00AC, SM), (0x00AD, 0x00AD, CF),
+  (0x00AE, 0x00AE, SO), (0x00AF, 0x00AF, SK), (0x00B0, 0x00B0, SO), (0x00B1, 0x00B1, SM),
+  (0x00B2, 0x00B3, NO), (0x00B4, 0x00B4, SK), (0x00B5, 0x00B5, LL), (0x00B6, 0x00B7, PO),
+  (0x00B8, 0x00B8, SK), (0x00B9, 0x00B9, NO), (0x00BA, 0x00BA, LO), (0x00BB, 0x00BB, PF),
+  (0x00BC, 0x00BE, NO), (0x00BF, 0x00BF, PO), (0x00C0, 0x00D6, LU), (0x00D7, 0x00D7, SM),
+  (0x00D8, 0x00DE, LU), (0x00DF, 0x00F6, LL), (0x00F7, 0x00F7, SM), (0x00F8, 0x00FF, LL),
+  (0x0100, 0x0100, LU), (0x0101, 0x0101, LL), (0x0102, 0x0102, LU), (0x0103, 0x0103, LL),
+  (0x0104, 0x0104, LU), (0x0105, 0x0105, LL), (0x0106, 0x0106, LU), (0x0107, 0x0107, LL),
+  (0x0108, 0x0108, LU), (0x0109, 0x0109, LL), (0x010A, 0x010A, LU), (0x010B, 0x010B, LL),
+  (0x010C, 0x010C, LU), (0x010D, 0x010D, LL), (0x010E, 0x010E, LU), (0x010F, 0x010F, LL),
+  (0x0110, 0x0110, LU), (0x0111, 0x0111, LL), (0x0112, 0x0112, LU), (0x0113, 0x0113, LL),
+  (0x0114, 0x0114, LU), (0x0115, 0x0115, LL), (0x0116, 0x0116, LU), (0x0117, 0x0117, LL),
+  (0x0118, 0x0118, LU), (0x0119, 0x0119, LL), (0x011A, 0x011A, LU), (0x011B, 0x011B, LL),
+  (0x011C, 0x011C, LU), (0x011D, 0x011D, LL), (0x011E, 0x011E, LU), (0x011F, 0x011F, LL),
+  (0x0120, 0x0120, LU), (0x0121, 0x0121, LL), (0x0122, 0x0122, LU), (0x0123, 0x0123, LL),
+  (0x0124, 0x0124, LU), (0x0125, 0x0125, LL), (0x0126, 0x0126, LU), (0x0127, 0x0127, LL),
+  (0x0128, 0x0128, LU), (0x0129, 0x0129, LL), (0x012A, 0x012A, LU), (0x012B, 0x012B, LL),
+  (0x012C, 0x012C, LU), (0x012D, 0x012D, LL), (0x012E, 0x012E, LU), (0x012F, 0x012F, LL),
+  (0x0130, 0x0130, LU), (0x0131, 0x0131, LL), (0x0132, 0x0132, LU), (0x0133, 0x0133, LL),
+  (0x0134, 0x0134, LU), (0x0135, 0x0135, LL), (0x0136, 0x0136, LU), (0x0137, 0x0138, LL),
+  (0x0139, 0x0139, LU), (0x013A, 0x013A, LL), (0x013B, 0x013B, LU), (0x013C, 0x013C, LL),
+  (0x013D, 0x013D, LU), (0x013E, 0x013E, LL), (0x013F, 0x013F, LU), (0x0140, 0x0140, LL),
+  (0x0141, 0x0141, LU), (0x0142, 0x0142, LL), (0x0143, 0x0143, LU), (0x0144, 0x0144, LL),
+  (0x0145, 0x0145, LU), (0x0146, 0x0146, LL), (0x0147, 0x0147, LU), (0x0148, 0x0149, LL),
+  (0x014A, 0x014A, LU), (0x014B, 0x014B, LL), (0x014C, 0x014C, LU), (0x014D, 0x014D, LL),
+  (0x014E, 0x014E, LU), (0x014F, 0x014F, LL), (0x0150, 0x0150, LU), (0x0151, 0x0151, LL),
+  (0x0152, 0x0152, LU), (0x0153, 0x0153, LL), (0x0154, 0x0154, LU), (0x0155, 0x0155, LL),
+  (0x0156, 0x0156, LU), (0x0157, 0x0157, LL), (0x0158, 0x0158, LU), (0x0159, 0x0159, LL),
+  (0x015A, 0x015A, LU), (0x015B, 0x015B, LL), (0x015C, 0x015C, LU), (0x015D, 0x015D, LL),
+  (0x015E, 0x015E, LU), (0x015F, 0x015F, LL), (0x0160, 0x0160, LU), (0x0161, 0x0161, LL),
+  (0x0162, 0x0162, LU), (0x0163, 0x0163, LL), (0x0164, 0x0164, LU), (0x0165, 0x0165, LL),
+  (0x0166, 0x0166, LU), (0x0167, 0x0167, LL), (0x0168, 0x0168, LU), (0x0169, 0x0169, LL),
+  (0x016A, 0x016A, LU), (0x016B, 0x016B, LL), (0x016C, 0x016C, LU), (0x016D, 0x016D, LL),
+  (0x016E, 0x016E, LU), (0x016F, 0x016F, LL), (0x0170, 0x0170, LU), (0x0171, 0x0171, LL),
+  (0x0172, 0x0172, LU), (0x0173, 0x0173, LL), (0x0174, 0x0174, LU), (0x0175, 0x0175, LL),
+  (0x0176, 0x0176, LU), (0x0177, 0x0177, LL), (0x0178, 0x0179, LU), (0x017A, 0x017A, LL),
+  (0x017B, 0x017B, LU), (0x017C, 0x017C, LL), (0x017D, 0x017D, LU), (0x017E, 0x0180, LL),
+  (0x0181, 0x0182, LU), (0x0183, 0x0183, LL), (0x0184, 0x0184, LU), (0x0185, 0x0185, LL),
+  (0x0186, 0x0187, LU), (0x0188, 0x0188, LL), (0x0189, 0x018B, LU), (0x018C, 0x018D, LL),
+  (0x018E, 0x0191, LU), (0x0192, 0x0192, LL), (0x0193, 0x0194, LU), (0x0195, 0x0195, LL),
+  (0x0196, 0x0198, LU), (0x0199, 0x019B, LL), (0x019C, 0x019D, LU), (0x019E, 0x019E, LL),
+  (0x019F, 0x01A0, LU), (0x01A1, 0x01A1, LL), (0x01A2, 0x01A2, LU), (0x01A3, 0x01A3, LL),
+  (0x01A4, 0x01A4, LU), (0x01A5, 0x01A5, LL), (0x01A6, 0x01A7, LU), (0x01A8, 0x01A8, LL),
+  (0x01A9, 0x01A9, LU), (0x01AA, 0x01AB, LL), (0x01AC, 0x01AC, LU), (0x01AD, 0x01AD, LL),
+  (0x01AE, 0x01AF, LU), (0x01B0, 0x01B0, LL), (0x01B1, 0x01B3, LU), (0x01B4, 0x01B4, LL),
+  (0x01B5, 0x01B5, LU), (0x01B6, 0x01B6, LL), (0x01B7, 0x01B8, LU), (0x01B9, 0x01BA, LL),
+  (0x01BB, 0x01BB, LO), (0x01BC, 0x01BC, LU), (0x01BD, 0x01BF, LL), (0x01C0, 0x01C3, LO),
+  (0x01C4, 0x01C4, LU), (0x01C5, 0x01C5, LT), (0x01C6, 0x01C6, LL), (0x01C7, 0x01C7, LU),
+  (0x01C8, 0x01C8, LT), (0x01C9, 0x01C9, LL), (0x01CA, 0x01CA, LU), (0x01CB, 0x01CB, LT),
+  (0x01CC, 0x01CC, LL), (0x01CD, 0x01CD, LU), (0x01CE, 0x01CE, LL), (0x01CF, 0x01CF, LU),
+  (0x01D0, 0x01D0, LL), (0x01D1, 0x01D1, LU), (0x01D2, 0x01D2, LL), (0x01D3, 0x01D3, LU),
+  (0x01D4, 0x01D4, LL), (0x01D5, 0x01D5, LU), (0x01D6, 0x01D6, LL), (0x01D7, 0x01D7, LU),
+  (0x01D8, 0x01D8, LL), (0x01D9, 0x01D9, LU), (0x01DA, 0x01DA, LL), (0x01DB, 0x01DB, LU),
+  (0x01DC, 0x01DD, LL), (0x01DE, 0x01DE, LU), (0x01DF, 0x01DF, LL), (0x01E0, 0x01E0, LU),
+  (0x01E1, 0x01E1, LL), (0x01E2, 0x01E2, LU), (0x01E3, 0x01E3, LL), (0x01E4, 0x01E4, LU),
+  (0x01E5, 0x01E5, LL), (0x01E6, 0x01E6, LU), (0x01E7, 0x01E7, LL), (0x01E8, 0x01E8, LU),
+  (0x01E9, 0x01E9, LL), (0x01EA, 0x01EA, LU), (0x01EB, 0x01EB, LL), (0x01EC, 0x01EC, LU),
+  (0x01ED, 0x01ED, LL), (0x01EE, 0x01EE, LU), (0x01EF, 0x01F0, LL), (0x01F1, 0x01F1, LU),
+  (0x01F2, 0x01F2, LT), (0x01F3, 0x01F3, LL), (0x01F4, 0x01F4, LU), (0x01F5, 0x01F5, LL),
+  (0x01F6, 0x01F8, LU), (0x01F9, 0x01F9, LL), (0x01FA, 0x01FA, LU), (0x01FB, 0x01FB, LL),
+  (0x01FC, 0x01FC, LU), (0x01FD, 0x01FD, LL), (0x01FE, 0x01FE, LU), (0x01FF, 0x01FF, LL),
+  (0x0200, 0x0200, LU), (0x0201, 0x0201, LL), (0x0202, 0x0202, LU), (0x0203, 0x0203, LL),
+  (0x0204, 0x0204, LU), (0x0205, 0x0205, LL), (0x0206, 0x0206, LU), (0x0207, 0x0207, LL),
+  (0x0208, 0x0208, LU), (0x0209, 0x0209, LL), (0x020A, 0x020A, LU), (0x020B, 0x020B, LL),
+  (0x020C, 0x020C, LU), (0x020D, 0x020D, LL), (0x020E, 0x020E, LU), (0x020F, 0x020F, LL),
+  (0x0210, 0x0210, LU), (0x0211, 0x0211, LL), (0x0212, 0x0212, LU), (0x0213, 0x0213, LL),
+  (0x0214, 0x0214, LU), (0x0215, 0x0215, LL), (0x0216, 0x0216, LU), (0x0217, 0x0217, LL),
+  (0x0218, 0x0218, LU), (0x0219, 0x0219, LL), (0x021A, 0x021A, LU), (0x021B, 0x021B, LL),
+  (0x021C, 0x021C, LU), (0x021D, 0x021D, LL), (0x021E, 0x021E, LU), (0x021F, 0x021F, LL),
+  (0x0220, 0x0220, LU), (0x0221, 0x0221, LL), (0x0222, 0x0222, LU), (0x0223, 0x0223, LL),
+  (0x0224, 0x0224, LU), (0x0225, 0x0225, LL), (0x0226, 0x0226, LU), (0x0227, 0x0227, LL),
+  (0x0228, 0x0228, LU), (0x0229, 0x0229, LL), (0x022A, 0x022A, LU), (0x022B, 0x022B, LL),
+  (0x022C, 0x022C, LU), (0x022D, 0x022D, LL), (0x022E, 0x022E, LU), (0x022F, 0x022F, LL),
+  (0x0230, 0x0230, LU), (0x0231, 0x0231, LL), (0x0232, 0x0232, LU), (0x0233, 0x0239, LL),
+  (0x023A, 0x023B, LU), (0x023C, 0x023C, LL), (0x023D, 0x023E, LU), (0x023F, 0x0240, LL),
+  (0x0241, 0x0241, LU), (0x0242, 0x0242, LL), (0x0243, 0x0246, LU), (0x0247, 0x0247, LL),
+  (0x0248, 0x0248, LU), (0x0249, 0x0249, LL), (0x024A, 0x024A, LU), (0x024B, 0x024B, LL),
+  (0x024C, 0x024C, LU), (0x024D, 0x024D, LL), (0x024E, 0x024E, LU), (0x024F, 0x0293, LL),
+  (0x0294, 0x0294, LO), (0x0295, 0x02AF, LL), (0x02B0, 0x02C1, LM), (0x02C2, 0x02C5, SK),
+  (0x02C6, 0x02D1, LM), (0x02D2, 0x02DF, SK), (0x02E0, 0x02E4, LM), (0x02E5, 0x02EB, SK),
+  (0x02EC, 0x02EC, LM), (0x02ED, 0x02ED, SK), (0x02EE, 0x02EE, LM), (0x02EF, 0x02FF, SK),
+  (0x0300, 0x036F, MN), (0x0370, 0x0370, LU), (0x0371, 0x0371, LL), (0x0372, 0x0372, LU),
+  (0x0373, 0x0373, LL), (0x0374, 0x0374, LM), (0x0375, 0x0375, SK), (0x0376, 0x0376, LU),
+  (0x0377, 0x0377, LL), (0x037A, 0x037A, LM), (0x037B, 0x037D, LL), (0x037E, 0x037E, PO),
+  (0x037F, 0x037F, LU), (0x0384, 0x0385, SK), (0x0386, 0x0386, LU), (0x0387, 0x0387, PO),
+  (0x0388, 0x038A, LU), (0x038C, 0x038C, LU), (0x038E, 0x038F, LU), (0x0390, 0x0390, LL),
+  (0x0391, 0x03A1, LU), (0x03A3, 0x03AB, LU), (0x03AC, 0x03CE, LL), (0x03CF, 0x03CF, LU),
+  (0x03D0, 0x03D1, LL), (0x03D2, 0x03D4, LU), (0x03D5, 0x03D7, LL), (0x03D8, 0x03D8, LU),
+  (0x03D9, 0x03D9, LL), (0x03DA, 0x03DA, LU), (0x03DB, 0x03DB, LL), (0x03DC, 0x03DC, LU),
+  (0x03DD, 0x03DD, LL), (0x03DE, 0x03DE, LU), (0x03DF, 0x03DF, LL), (0x03E0, 0x03E0, LU),
+  (0x03E1, 0x03E1, LL), (0x03E2, 0x03E2, LU), (0x03E3, 0x03E3, LL), (0x03E4, 0x03E4, LU),
+  (0x03E5, 0x03E5, LL), (0x03E6, 0x03E6, LU), (0x03E7, 0x03E7, LL), (0x03E8, 0x03E8, LU),
+  (0x03E9, 0x03E9, LL), (0x03EA, 0x03EA, LU), (0x03EB, 0x03EB, LL), (0x03EC, 0x03EC, LU),
+  (0x03ED, 0x03ED, LL), (0x03EE, 0x03EE, LU), (0x03EF, 0x03F3, LL), (0x03F4, 0x03F4, LU),
+  (0x03F5, 0x03F5, LL), (0x03F6, 0x03F6, SM), (0x03F7, 0x03F7, LU), (0x03F8, 0x03F8, LL),
+  (0x03F9, 0x03FA, LU), (0x03FB, 0x03FC, LL), (0x03FD, 0x042F, LU), (0x0430, 0x045F, LL),
+  (0x0460, 0x0460, LU), (0x0461, 0x0461, LL), (0x0462, 0x0462, LU), (0x0463, 0x0463, LL),
+  (0x0464, 0x0464, LU), (0x0465, 0x0465, LL), (0x0466, 0x0466, LU), (0x0467, 0x0467, LL),
+  (0x0468, 0x0468, LU), (0x0469, 0x0469, LL), (0x046A, 0x046A, LU), (0x046B, 0x046B, LL),
+  (0x046C, 0x046C, LU), (0x046D, 0x046D, LL), (0x046E, 0x046E, LU), (0x046F, 0x046F, LL),
+  (0x0470, 0x0470, LU), (0x0471, 0x0471, LL), (0x0472, 0x0472, LU), (0x0473, 0x0473, LL),
+  (0x0474, 0x0474, LU), (0x0475, 0x0475, LL), (0x0476, 0x0476, LU), (0x0477, 0x0477, LL),
+  (0x0478, 0x0478, LU), (0x0479, 0x0479, LL), (0x047A, 0x047A, LU), (0x047B, 0x047B, LL),
+  (0x047C, 0x047C, LU), (0x047D, 0x047D, LL), (0x047E, 0x047E, LU), (0x047F, 0x047F, LL),
+  (0x0480, 0x0480, LU), (0x0481, 0x0481, LL), (0x0482, 0x0482, SO), (0x0483, 0x0487, MN),
+  (0x0488, 0x0489, ME), (0x048A, 0x048A, LU), (0x048B, 0x048B, LL), (0x048C, 0x048C, LU),
+  (0x048D, 0x048D, LL), (0x048E, 0x048E, LU), (0x048F, 0x048F, LL), (0x0490, 0x0490, LU),
+  (0x0491, 0x0491, LL), (0x0492, 0x0492, LU), (0x0493, 0x0493, LL), (0x0494, 0x0494, LU),
+  (0x0495, 0x0495, LL), (0x0496, 0x0496, LU), (0x0497, 0x0497, LL), (0x0498, 0x0498, LU),
+  (0x0499, 0x0499, LL), (0x049A, 0x049A, LU), (0x049B, 0x049B, LL), (0x049C, 0x049C, LU),
+  (0x049D, 0x049D, LL), (0x049E, 0x049E, LU), (0x049F, 0x049F, LL), (0x04A0, 0x04A0, LU),
+  (0x04A1, 0x04A1, LL), (0x04A2, 0x04A2, LU), (0x04A3, 0x04A3, LL), (0x04A4, 0x04A4, LU),
+  (0x04A5, 0x04A5, LL), (0x04A6, 0x04A6, LU), (0x04A7, 0x04A7, LL), (0x04A8, 0x04A8, LU),
+  (0x04A9, 0x04A9, LL), (0x04AA, 0x04AA, LU), (0x04AB, 0x04AB, LL), (0x04AC, 0x04AC, LU),
+  (0x04AD, 0x04AD, LL), (0x04AE, 0x04AE, LU), (0x04AF, 0x04AF, LL), (0x04B0, 0x04B0, LU),
+  (0x04B1, 0x04B1, LL), (0x04B2, 0x04B2, LU), (0x04B3, 0x04B3, LL), (0x04B4, 0x04B4, LU),
+  (0x04B5, 0x04B5, LL), (0x04B6, 0x04B6, LU), (0x04B7, 0x04B7, LL), (0x04B8, 0x04B8, LU),
+  (0x04B9, 0x04B9, LL), (0x04BA, 0x04BA, LU), (0x04BB, 0x04BB, LL), (0x04BC, 0x04BC, LU),
+  (0x04BD, 0x04BD, LL), (0x04BE, 0x04BE, LU), (0x04BF, 0x04BF, LL), (0x04C0, 0x04C1, LU),
+  (0x04C2, 0x04C2, LL), (0x04C3, 0x04C3, LU), (0x04C4, 0x04C4, LL), (0x04C5, 0x04C5, LU),
+  (0x04C6, 0x04C6, LL), (0x04C7, 0x04C7, LU), (0x04C8, 0x04C8, LL), (0x04C9, 0x04C9, LU),
+  (0x04CA, 0x04CA, LL), (0x04CB, 0x04CB, LU), (0x04CC, 0x04CC, LL), (0x04CD, 0x04CD, LU),
+  (0x04CE, 0x04CF, LL), (0x04D0, 0x04D0, LU), (0x04D1, 0x04D1, LL), (0x04D2, 0x04D2, LU),
+  (0x04D3, 0x04D3, LL), (0x04D4, 0x04D4, LU), (0x04D5, 0x04D5, LL), (0x04D6, 0x04D6, LU),
+  (0x04D7, 0x04D7, LL), (0x04D8, 0x04D8, LU), (0x04D9, 0x04D9, LL), (0x04DA, 0x04DA, LU),
+  (0x04DB, 0x04DB, LL), (0x04DC, 0x04DC, LU), (0x04DD, 0x04DD, LL), (0x04DE, 0x04DE, LU),
+  (0x04DF, 0x04DF, LL), (0x04E0, 0x04E0, LU), (0x04E1, 0x04E1, LL), (0x04E2, 0x04E2, LU),
+  (0x04E3, 0x04E3, LL), (0x04E4, 0x04E4, LU), (0x04E5, 0x04E5, LL), (0x04E6, 0x04E6, LU),
+  (0x04E7, 0x04E7, LL), (0x04E8, 0x04E8, LU), (0x04E9, 0x04E9, LL), (0x04EA, 0x04EA, LU),
+  (0x04EB, 0x04EB, LL), (0x04EC, 0x04EC, LU), (0x04ED, 0x04ED, LL), (0x04EE, 0x04EE, LU),
+  (0x04EF, 0x04EF, LL), (0x04F0, 0x04F0, LU), (0x04F1, 0x04F1, LL), (0x04F2, 0x04F2, LU),
+  (0x04F3, 0x04F3, LL), (0x04F4, 0x04F4, LU), (0x04F5, 0x04F5, LL), (0x04F6, 0x04F6, LU),
+  (0x04F7, 0x04F7, LL), (0x04F8, 0x04F8, LU), (0x04F9, 0x04F9, LL), (0x04FA, 0x04FA, LU),
+  (0x04FB, 0x04FB, LL), (0x04FC, 0x04FC, LU), (0x04FD, 0x04FD, LL), (0x04FE, 0x04FE, LU),
+  (0x04FF, 0x04FF, LL), (0x0500, 0x0500, LU), (0x0501, 0x0501, LL), (0x0502, 0x0502, LU),
+  (0x0503, 0x0503, LL), (0x0504, 0x0504, LU), (0x0505, 0x0505, LL), (0x0506, 0x0506, LU),
+  (0x0507, 0x0507, LL), (0x0508, 0x0508, LU), (0x0509, 0x0509, LL), (0x050A, 0x050A, LU),
+  (0x050B, 0x050B, LL), (0x050C, 0x050C, LU), (0x050D, 0x050D, LL), (0x050E, 0x050E, LU),
+  (0x050F, 0x050F, LL), (0x0510, 0x0510, LU), (0x0511, 0x0511, LL), (0x0512, 0x0512, LU),
+  (0x0513, 0x0513, LL), (0x0514, 0x0514, LU), (0x0515, 0x0515, LL), (0x0516, 0x0516, LU),
+  (0x0517, 0x0517, LL), (0x0518, 0x0518, LU), (0x0519, 0x0519, LL), (0x051A, 0x051A, LU),
+  (0x051B, 0x051B, LL), (0x051C, 0x051C, LU), (0x051D, 0x051D, LL), (0x051E, 0x051E, LU),
+  (0x051F, 0x051F, LL), (0x0520, 0x0520, LU), (0x0521, 0x0521, LL), (0x0522, 0x0522, LU),
+  (0x0523, 0x0523, LL), (0x0524, 0x0524, LU), (0x0525, 0x0525, LL), (0x0526, 0x0526, LU),
+  (0x0527, 0x0527, LL), (0x0528, 0x0528, LU), (0x0529, 0x0529, LL), (0x052A, 0x052A, LU),
+  (0x052B, 0x052B, LL), (0x052C, 0x052C, LU), (0x052D, 0x052D, LL), (0x052E, 0x052E, LU),
+  (0x052F, 0x052F, LL), (0x0531, 0x0556, LU), (0x0559, 0x0559, LM), (0x055A, 0x055F, PO),
+  (0x0560, 0x0588, LL), (0x0589, 0x0589, PO), (0x058A, 0x058A, PD), (0x058D, 0x058E, SO),
+  (0x058F, 0x058F, SC), (0x0591, 0x05BD, MN), (0x05BE, 0x05BE, PD), (0x05BF, 0x05BF, MN),
+  (0x05C0, 0x05C0, PO), (0x05C1, 0x05C2, MN), (0x05C3, 0x05C3, PO), (0x05C4, 0x05C5, MN),
+  (0x05C6, 0x05C6, PO), (0x05C7, 0x05C7, MN), (0x05D0, 0x05EA, LO), (0x05EF, 0x05F2, LO),
+  (0x05F3, 0x05F4, PO), (0x0600, 0x0605, CF), (0x0606, 0x0608, SM), (0x0609, 0x060A, PO),
+  (0x060B, 0x060B, SC), (0x060C, 0x060D, PO), (0x060E, 0x060F, SO), (0x0610, 0x061A, MN),
+  (0x061B, 0x061B, PO), (0x061C, 0x061C, CF), (0x061D, 0x061F, PO), (0x0620, 0x063F, LO),
+  (0x0640, 0x0640, LM), (0x0641, 0x064A, LO), (0x064B, 0x065F, MN), (0x0660, 0x0669, ND),
+  (0x066A, 0x066D, PO), (0x066E, 0x066F, LO), (0x0670, 0x0670, MN), (0x0671, 0x06D3, LO),
+  (0x06D4, 0x06D4, PO), (0x06D5, 0x06D5, LO), (0x06D6, 0x06DC, MN), (0x06DD, 0x06DD, CF),
+  (0x06DE, 0x06DE, SO), (0x06DF, 0x06E4, MN), (0x06E5, 0x06E6, LM), (0x06E7, 0x06E8, MN),
+  (0x06E9, 0x06E9, SO), (0x06EA, 0x06ED, MN), (0x06EE, 0x06EF, LO), (0x06F0, 0x06F9, ND),
+  (0x06FA, 0x06FC, LO), (0x06FD, 0x06FE, SO), (0x06FF, 0x06FF, LO), (0x0700, 0x070D, PO),
+  (0x070F, 0x070F, CF), (0x0710, 0x0710, LO), (0x0711, 0x0711, MN), (0x0712, 0x072F, LO),
+  (0x0730, 0x074A, MN), (0x074D, 0x07A5, LO), (0x07A6, 0x07B0, MN), (0x07B1, 0x07B1, LO),
+  (0x07C0, 0x07C9, ND), (0x07CA, 0x07EA, LO), (0x07EB, 0x07F3, MN), (0x07F4, 0x07F5, LM),
+  (0x07F6, 0x07F6, SO), (0x07F7, 0x07F9, PO), (0x07FA, 0x07FA, LM), (0x07FD, 0x07FD, MN),
+  (0x07FE, 0x07FF, SC), (0x0800, 0x0815, LO), (0x0816, 0x0819, MN), (0x081A, 0x081A, LM),
+  (0x081B, 0x0823, MN), (0x0824, 0x0824, LM), (0x0825, 0x0827, MN), (0x0828, 0x0828, LM),
+  (0x0829, 0x082D, MN), (0x0830, 0x083E, PO), (0x0840, 0x0858, LO), (0x0859, 0x085B, MN),
+  (0x085E, 0x085E, PO), (0x0860, 0x086A, LO), (0x0870, 0x0887, LO), (0x0888, 0x0888, SK),
+  (0x0889, 0x088E, LO), (0x0890, 0x0891, CF), (0x0898, 0x089F, MN), (0x08A0, 0x08C8, LO),
+  (0x08C9, 0x08C9, LM), (0x08CA, 0x08E1, MN), (0x08E2, 0x08E2, CF), (0x08E3, 0x0902, MN),
+  (0x0903, 0x0903, MC), (0x0904, 0x0939, LO), (0x093A, 0x093A, MN), (0x093B, 0x093B, MC),
+  (0x093C, 0x093C, MN), (0x093D, 0x093D, LO), (0x093E, 0x0940, MC), (0x0941, 0x0948, MN),
+  (0x0949, 0x094C, MC), (0x094D, 0x094D, MN), (0x094E, 0x094F, MC), (0x0950, 0x0950, LO),
+  (0x0951, 0x0957, MN), (0x0958, 0x0961, LO), (0x0962, 0x0963, MN), (0x0964, 0x0965, PO),
+  (0x0966, 0x096F, ND), (0x0970, 0x0970, PO), (0x0971, 0x0971, LM), (0x0972, 0x0980, LO),
+  (0x0981, 0x0981, MN), (0x0982, 0x0983, MC), (0x0985, 0x098C, LO), (0x098F, 0x0990, LO),
+  (0x0993, 0x09A8, LO), (0x09AA, 0x09B0, LO), (0x09B2, 0x09B2, LO), (0x09B6, 0x09B9, LO),
+  (0x09BC, 0x09BC, MN), (0x09BD, 0x09BD, LO), (0x09BE, 0x09C0, MC), (0x09C1, 0x09C4, MN),
+  (0x09C7, 0x09C8, MC), (0x09CB, 0x09CC, MC), (0x09CD, 0x09CD, MN), (0x09CE, 0x09CE, LO),
+  (0x09D7, 0x09D7, MC), (0x09DC, 0x09DD, LO), (0x09DF, 0x09E1, LO), (0x09E2, 0x09E3, MN),
+  (0x09E6, 0x09EF, ND), (0x09F0, 0x09F1, LO), (0x09F2, 0x09F3, SC), (0x09F4, 0x09F9, NO),
+  (0x09FA, 0x09FA, SO), (0x09FB, 0x09FB, SC), (0x09FC, 0x09FC, LO), (0x09FD, 0x09FD, PO),
+  (0x09FE, 0x09FE, MN), (0x0A01, 0x0A02, MN), (0x0A03, 0x0A03, MC), (0x0A05, 0x0A0A, LO),
+  (0x0A0F, 0x0A10, LO), (0x0A13, 0x0A28, LO), (0x0A2A, 0x0A30, LO), (0x0A32, 0x0A33, LO),
+  (0x0A35, 0x0A36, LO), (0x0A38, 0x0A39, LO), (0x0A3C, 0x0A3C, MN), (0x0A3E, 0x0A40, MC),
+  (0x0A41, 0x0A42, MN), (0x0A47, 0x0A48, MN), (0x0A4B, 0x0A4D, MN), (0x0A51, 0x0A51, MN),
+  (0x0A59, 0x0A5C, LO), (0x0A5E, 0x0A5E, LO), (0x0A66, 0x0A6F, ND), (0x0A70, 0x0A71, MN),
+  (0x0A72, 0x0A74, LO), (0x0A75, 0x0A75, MN), (0x0A76, 0x0A76, PO), (0x0A81, 0x0A82, MN),
+  (0x0A83, 0x0A83, MC), (0x0A85, 0x0A8D, LO), (0x0A8F, 0x0A91, LO), (0x0A93, 0x0AA8, LO),
+  (0x0AAA, 0x0AB0, LO), (0x0AB2, 0x0AB3, LO), (0x0AB5, 0x0AB9, LO), (0x0ABC, 0x0ABC, MN),
+  (0x0ABD, 0x0ABD, LO), (0x0ABE, 0x0AC0, MC), (0x0AC1, 0x0AC5, MN), (0x0AC7, 0x0AC8, MN),
+  (0x0AC9, 0x0AC9, MC), (0x0ACB, 0x0ACC, MC), (0x0ACD, 0x0ACD, MN), (0x0AD0, 0x0AD0, LO),
+  (0x0AE0, 0x0AE1, LO), (0x0AE2, 0x0AE3, MN), (0x0AE6, 0x0AEF, ND), (0x0AF0, 0x0AF0, PO),
+  (0x0AF1, 0x0AF1, SC), (0x0AF9, 0x0AF9, LO), (0x0AFA, 0x0AFF, MN), (0x0B01, 0x0B01, MN),
+  (0x0B02, 0x0B03, MC), (0x0B05, 0x0B0C, LO), (0x0B0F, 0x0B10, LO), (0x0B13, 0x0B28, LO),
+  (0x0B2A, 0x0B30, LO), (0x0B32, 0x0B33, LO), (0x0B35, 0x0B39, LO), (0x0B3C, 0x0B3C, MN),
+  (0x0B3D, 0x0B3D, LO), (0x0B3E, 0x0B3E, MC), (0x0B3F, 0x0B3F, MN), (0x0B40, 0x0B40, MC),
+  (0x0B41, 0x0B44, MN), (0x0B47, 0x0B48, MC), (0x0B4B, 0x0B4C, MC), (0x0B4D, 0x0B4D, MN),
+  (0x0B55, 0x0B56, MN), (0x0B57, 0x0B57, MC), (0x0B5C, 0x0B5D, LO), (0x0B5F, 0x0B61, LO),
+  (0x0B62, 0x0B63, MN), (0x0B66, 0x0B6F, ND), (0x0B70, 0x0B70, SO), (0x0B71, 0x0B71, LO),
+  (0x0B72, 0x0B77, NO), (0x0B82, 0x0B82, MN), (0x0B83, 0x0B83, LO), (0x0B85, 0x0B8A, LO),
+  (0x0B8E, 0x0B90, LO), (0x0B92, 0x0B95, LO), (0x0B99, 0x0B9A, LO), (0x0B9C, 0x0B9C, LO),
+  (0x0B9E, 0x0B9F, LO), (0x0BA3, 0x0BA4, LO), (0x0BA8, 0x0BAA, LO), (0x0BAE, 0x0BB9, LO),
+  (0x0BBE, 0x0BBF, MC), (0x0BC0, 0x0BC0, MN), (0x0BC1, 0x0BC2, MC), (0x0BC6, 0x0BC8, MC),
+  (0x0BCA, 0x0BCC, MC), (0x0BCD, 0x0BCD, MN), (0x0BD0, 0x0BD0, LO), (0x0BD7, 0x0BD7, MC),
+  (0x0BE6, 0x0BEF, ND), (0x0BF0, 0x0BF2, NO), (0x0BF3, 0x0BF8, SO), (0x0BF9, 0x0BF9, SC),
+  (0x0BFA, 0x0BFA, SO), (0x0C00, 0x0C00, MN), (0x0C01, 0x0C03, MC), (0x0C04, 0x0C04, MN),
+  (0x0C05, 0x0C0C, LO), (0x0C0E, 0x0C10, LO), (0x0C12, 0x0C28, LO), (0x0C2A, 0x0C39, LO),
+  (0x0C3C, 0x0C3C, MN), (0x0C3D, 0x0C3D, LO), (0x0C3E, 0x0C40, MN), (0x0C41, 0x0C44, MC),
+  (0x0C46, 0x0C48, MN), (0x0C4A, 0x0C4D, MN), (0x0C55, 0x0C56, MN), (0x0C58, 0x0C5A, LO),
+  (0x0C5D, 0x0C5D, LO), (0x0C60, 0x0C61, LO), (0x0C62, 0x0C63, MN), (0x0C66, 0x0C6F, ND),
+  (0x0C77, 0x0C77, PO), (0x0C78, 0x0C7E, NO), (0x0C7F, 0x0C7F, SO), (0x0C80, 0x0C80, LO),
+  (0x0C81, 0x0C81, MN), (0x0C82, 0x0C83, MC), (0x0C84, 0x0C84, PO), (0x0C85, 0x0C8C, LO),
+  (0x0C8E, 0x0C90, LO), (0x0C92, 0x0CA8, LO), (0x0CAA, 0x0CB3, LO), (0x0CB5, 0x0CB9, LO),
+  (0x0CBC, 0x0CBC, MN), (0x0CBD, 0x0CBD, LO), (0x0CBE, 0x0CBE, MC), (0x0CBF, 0x0CBF, MN),
+  (0x0CC0, 0x0CC4, MC), (0x0CC6, 0x0CC6, MN), (0x0CC7, 0x0CC8, MC), (0x0CCA, 0x0CCB, MC),
+  (0x0CCC, 0x0CCD, MN), (0x0CD5, 0x0CD6, MC), (0x0CDD, 0x0CDE, LO), (0x0CE0, 0x0CE1, LO),
+  (0x0CE2, 0x0CE3, MN), (0x0CE6, 0x0CEF, ND), (0x0CF1, 0x0CF2, LO), (0x0CF3, 0x0CF3, MC),
+  (0x0D00, 0x0D01, MN), (0x0D02, 0x0D03, MC), (0x0D04, 0x0D0C, LO), (0x0D0E, 0x0D10, LO),
+  (0x0D12, 0x0D3A, LO), (0x0D3B, 0x0D3C, MN), (0x0D3D, 0x0D3D, LO), (0x0D3E, 0x0D40, MC),
+  (0x0D41, 0x0D44, MN), (0x0D46, 0x0D48, MC), (0x0D4A, 0x0D4C, MC), (0x0D4D, 0x0D4D, MN),
+  (0x0D4E, 0x0D4E, LO), (0x0D4F, 0x0D4F, SO), (0x0D54, 0x0D56, LO), (0x0D57, 0x0D57, MC),
+  (0x0D58, 0x0D5E, NO), (0x0D5F, 0x0D61, LO), (0x0D62, 0x0D63, MN), (0x0D66, 0x0D6F, ND),
+  (0x0D70, 0x0D78, NO), (0x0D79, 0x0D79, SO), (0x0D7A, 0x0D7F, LO), (0x0D81, 0x0D81, MN),
+  (0x0D82, 0x0D83, MC), (0x0D85, 0x0D96, LO), (0x0D9A, 0x0DB1, LO), (0x0DB3, 0x0DBB, LO),
+  (0x0DBD, 0x0DBD, LO), (0x0DC0, 0x0DC6, LO), (0x0DCA, 0x0DCA, MN), (0x0DCF, 0x0DD1, MC),
+  (0x0DD2, 0x0DD4, MN), (0x0DD6, 0x0DD6, MN), (0x0DD8, 0x0DDF, MC), (0x0DE6, 0x0DEF, ND),
+  (0x0DF2, 0x0DF3, MC), (0x0DF4, 0x0DF4, PO), (0x0E01, 0x0E30, LO), (0x0E31, 0x0E31, MN),
+  (0x0E32, 0x0E33, LO), (0x0E34, 0x0E3A, MN), (0x0E3F, 0x0E3F, SC), (0x0E40, 0x0E45, LO),
+  (0x0E46, 0x0E46, LM), (0x0E47, 0x0E4E, MN), (0x0E4F, 0x0E4F, PO), (0x0E50, 0x0E59, ND),
+  (0x0E5A, 0x0E5B, PO), (0x0E81, 0x0E82, LO), (0x0E84, 0x0E84, LO), (0x0E86, 0x0E8A, LO),
+  (0x0E8C, 0x0EA3, LO), (0x0EA5, 0x0EA5, LO), (0x0EA7, 0x0EB0, LO), (0x0EB1, 0x0EB1, MN),
+  (0x0EB2, 0x0EB3, LO), (0x0EB4, 0x0EBC, MN), (0x0EBD, 0x0EBD, LO), (0x0EC0, 0x0EC4, LO),
+  (0x0EC6, 0x0EC6, LM), (0x0EC8, 0x0ECE, MN), (0x0ED0, 0x0ED9, ND), (0x0EDC, 0x0EDF, LO),
+  (0x0F00, 0x0F00, LO), (0x0F01, 0x0F03, SO), (0x0F04, 0x0F12, PO), (0x0F13, 0x0F13, SO),
+  (0x0F14, 0x0F14, PO), (0x0F15, 0x0F17, SO), (0x0F18, 0x0F19, MN), (0x0F1A, 0x0F1F, SO),
+  (0x0F20, 0x0F29, ND), (0x0F2A, 0x0F33, NO), (0x0F34, 0x0F34, SO), (0x0F35, 0x0F35, MN),
+  (0x0F36, 0x0F36, SO), (0x0F37, 0x0F37, MN), (0x0F38, 0x0F38, SO), (0x0F39, 0x0F39, MN),
+  (0x0F3A, 0x0F3A, PS), (0x0F3B, 0x0F3B, PE), (0x0F3C, 0x0F3C, PS), (0x0F3D, 0x0F3D, PE),
+  (0x0F3E, 0x0F3F, MC), (0x0F40, 0x0F47, LO), (0x0F49, 0x0F6C, LO), (0x0F71, 0x0F7E, MN),
+  (0x0F7F, 0x0F7F, MC), (0x0F80, 0x0F84, MN), (0x0F85, 0x0F85, PO), (0x0F86, 0x0F87, MN),
+  (0x0F88, 0x0F8C, LO), (0x0F8D, 0x0F97, MN), (0x0F99, 0x0FBC, MN), (0x0FBE, 0x0FC5, SO),
+  (0x0FC6, 0x0FC6, MN), (0x0FC7, 0x0FCC, SO), (0x0FCE, 0x0FCF, SO), (0x0FD0, 0x0FD4, PO),
+  (0x0FD5, 0x0FD8, SO), (0x0FD9, 0x0FDA, PO), (0x1000, 0x102A, LO), (0x102B, 0x102C, MC),
+  (0x102D, 0x1030, MN), (0x1031, 0x1031, MC), (0x1032, 0x1037, MN), (0x1038, 0x1038, MC),
+  (0x1039, 0x103A, MN), (0x103B, 0x103C, MC), (0x103D, 0x103E, MN), (0x103F, 0x103F, LO),
+  (0x1040, 0x1049, ND), (0x104A, 0x104F, PO), (0x1050, 0x1055, LO), (0x1056, 0x1057, MC),
+  (0x1058, 0x1059, MN), (0x105A, 0x105D, LO), (0x105E, 0x1060, MN), (0x1061, 0x1061, LO),
+  (0x1062, 0x1064, MC), (0x1065, 0x1066, LO), (0x1067, 0x106D, MC), (0x106E, 0x1070, LO),
+  (0x1071, 0x1074, MN), (0x1075, 0x1081, LO), (0x1082, 0x1082, MN), (0x1083, 0x1084, MC),
+  (0x1085, 0x1086, MN), (0x1087, 0x108C, MC), (0x108D, 0x108D, MN), (0x108E, 0x108E, LO),
+  (0x108F, 0x108F, MC), (0x1090, 0x1099, ND), (0x109A, 0x109C, MC), (0x109D, 0x109D, MN),
+  (0x109E, 0x109F, SO), (0x10A0, 0x10C5, LU), (0x10C7, 0x10C7, LU), (0x10CD, 0x10CD, LU),
+  (0x10D0, 0x10FA, LL), (0x10FB, 0x10FB, PO), (0x10FC, 0x10FC, LM), (0x10FD, 0x10FF, LL),
+  (0x1100, 0x1248, LO), (0x124A, 0x124D, LO), (0x1250, 0x1256, LO), (0x1258, 0x1258, LO),
+  (0x125A, 0x125D, LO), (0x1260, 0x1288, LO), (0x128A, 0x128D, LO), (0x1290, 0x12B0, LO),
+  (0x12B2, 0x12B5, LO), (0x12B8, 0x12BE, LO), (0x12C0, 0x12C0, LO), (0x12C2, 0x12C5, LO),
+  (0x12C8, 0x12D6, LO), (0x12D8, 0x1310, LO), (0x1312, 0x1315, LO), (0x1318, 0x135A, LO),
+  (0x135D, 0x135F, MN), (0x1360, 0x1368, PO), (0x1369, 0x137C, NO), (0x1380, 0x138F, LO),
+  (0x1390, 0x1399, SO), (0x13A0, 0x13F5, LU), (0x13F8, 0x13FD, LL), (0x1400, 0x1400, PD),
+  (0x1401, 0x166C, LO), (0x166D, 0x166D, SO), (0x166E, 0x166E, PO), (0x166F, 0x167F, LO),
+  (0x1680, 0x1680, ZS), (0x1681, 0x169A, LO), (0x169B, 0x169B, PS), (0x169C, 0x169C, PE),
+  (0x16A0, 0x16EA, LO), (0x16EB, 0x16ED, PO), (0x16EE, 0x16F0, NL), (0x16F1, 0x16F8, LO),
+  (0x1700, 0x1711, LO), (0x1712, 0x1714, MN), (0x1715, 0x1715, MC), (0x171F, 0x1731, LO),
+  (0x1732, 0x1733, MN), (0x1734, 0x1734, MC), (0x1735, 0x1736, PO), (0x1740, 0x1751, LO),
+  (0x1752, 0x1753, MN), (0x1760, 0x176C, LO), (0x176E, 0x1770, LO), (0x1772, 0x1773, MN),
+  (0x1780, 0x17B3, LO), (0x17B4, 0x17B5, MN), (0x17B6, 0x17B6, MC), (0x17B7, 0x17BD, MN),
+  (0x17BE, 0x17C5, MC), (0x17C6, 0x17C6, MN), (0x17C7, 0x17C8, MC), (0x17C9, 0x17D3, MN),
+  (0x17D4, 0x17D6, PO), (0x17D7, 0x17D7, LM), (0x17D8, 0x17DA, PO), (0x17DB, 0x17DB, SC),
+  (0x17DC, 0x17DC, LO), (0x17DD, 0x17DD, MN), (0x17E0, 0x17E9, ND), (0x17F0, 0x17F9, NO),
+  (0x1800, 0x1805, PO), (0x1806, 0x1806, PD), (0x1807, 0x180A, PO), (0x180B, 0x180D, MN),
+  (0x180E, 0x180E, CF), (0x180F, 0x180F, MN), (0x1810, 0x1819, ND), (0x1820, 0x1842, LO),
+  (0x1843, 0x1843, LM), (0x1844, 0x1878, LO), (0x1880, 0x1884, LO), (0x1885, 0x1886, MN),
+  (0x1887, 0x18A8, LO), (0x18A9, 0x18A9, MN), (0x18AA, 0x18AA, LO), (0x18B0, 0x18F5, LO),
+  (0x1900, 0x191E, LO), (0x1920, 0x1922, MN), (0x1923, 0x1926, MC), (0x1927, 0x1928, MN),
+  (0x1929, 0x192B, MC), (0x1930, 0x1931, MC), (0x1932, 0x1932, MN), (0x1933, 0x1938, MC),
+  (0x1939, 0x193B, MN), (0x1940, 0x1940, SO), (0x1944, 0x1945, PO), (0x1946, 0x194F, ND),
+  (0x1950, 0x196D, LO), (0x1970, 0x1974, LO), (0x1980, 0x19AB, LO), (0x19B0, 0x19C9, LO),
+  (0x19D0, 0x19D9, ND), (0x19DA, 0x19DA, NO), (0x19DE, 0x19FF, SO), (0x1A00, 0x1A16, LO),
+  (0x1A17, 0x1A18, MN), (0x1A19, 0x1A1A, MC), (0x1A1B, 0x1A1B, MN), (0x1A1E, 0x1A1F, PO),
+  (0x1A20, 0x1A54, LO), (0x1A55, 0x1A55, MC), (0x1A56, 0x1A56, MN), (0x1A57, 0x1A57, MC),
+  (0x1A58, 0x1A5E, MN), (0x1A60, 0x1A60, MN), (0x1A61, 0x1A61, MC), (0x1A62, 0x1A62, MN),
+  (0x1A63, 0x1A64, MC), (0x1A65, 0x1A6C, MN), (0x1A6D, 0x1A72, MC), (0x1A73, 0x1A7C, MN),
+  (0x1A7F, 0x1A7F, MN), (0x1A80, 0x1A89, ND), (0x1A90, 0x1A99, ND), (0x1AA0, 0x1AA6, PO),
+  (0x1AA7, 0x1AA7, LM), (0x1AA8, 0x1AAD, PO), (0x1AB0, 0x1ABD, MN), (0x1ABE, 0x1ABE, ME),
+  (0x1ABF, 0x1ACE, MN), (0x1B00, 0x1B03, MN), (0x1B04, 0x1B04, MC), (0x1B05, 0x1B33, LO),
+  (0x1B34, 0x1B34, MN), (0x1B35, 0x1B35, MC), (0x1B36, 0x1B3A, MN), (0x1B3B, 0x1B3B, MC),
+  (0x1B3C, 0x1B3C, MN), (0x1B3D, 0x1B41, MC), (0x1B42, 0x1B42, MN), (0x1B43, 0x1B44, MC),
+  (0x1B45, 0x1B4C, LO), (0x1B50, 0x1B59, ND), (0x1B5A, 0x1B60, PO), (0x1B61, 0x1B6A, SO),
+  (0x1B6B, 0x1B73, MN), (0x1B74, 0x1B7C, SO), (0x1B7D, 0x1B7E, PO), (0x1B80, 0x1B81, MN),
+  (0x1B82, 0x1B82, MC), (0x1B83, 0x1BA0, LO), (0x1BA1, 0x1BA1, MC), (0x1BA2, 0x1BA5, MN),
+  (0x1BA6, 0x1BA7, MC), (0x1BA8, 0x1BA9, MN), (0x1BAA, 0x1BAA, MC), (0x1BAB, 0x1BAD, MN),
+  (0x1BAE, 0x1BAF, LO), (0x1BB0, 0x1BB9, ND), (0x1BBA, 0x1BE5, LO), (0x1BE6, 0x1BE6, MN),
+  (0x1BE7, 0x1BE7, MC), (0x1BE8, 0x1BE9, MN), (0x1BEA, 0x1BEC, MC), (0x1BED, 0x1BED, MN),
+  (0x1BEE, 0x1BEE, MC), (0x1BEF, 0x1BF1, MN), (0x1BF2, 0x1BF3, MC), (0x1BFC, 0x1BFF, PO),
+  (0x1C00, 0x1C23, LO), (0x1C24, 0x1C2B, MC), (0x1C2C, 0x1C33, MN), (0x1C34, 0x1C35, MC),
+  (0x1C36, 0x1C37, MN), (0x1C3B, 0x1C3F, PO), (0x1C40, 0x1C49, ND), (0x1C4D, 0x1C4F, LO),
+  (0x1C50, 0x1C59, ND), (0x1C5A, 0x1C77, LO), (0x1C78, 0x1C7D, LM), (0x1C7E, 0x1C7F, PO),
+  (0x1C80, 0x1C88, LL), (0x1C90, 0x1CBA, LU), (0x1CBD, 0x1CBF, LU), (0x1CC0, 0x1CC7, PO),
+  (0x1CD0, 0x1CD2, MN), (0x1CD3, 0x1CD3, PO), (0x1CD4, 0x1CE0, MN), (0x1CE1, 0x1CE1, MC),
+  (0x1CE2, 0x1CE8, MN), (0x1CE9, 0x1CEC, LO), (0x1CED, 0x1CED, MN), (0x1CEE, 0x1CF3, LO),
+  (0x1CF4, 0x1CF4, MN), (0x1CF5, 0x1CF6, LO), (0x1CF7, 0x1CF7, MC), (0x1CF8, 0x1CF9, MN),
+  (0x1CFA, 0x1CFA, LO), (0x1D00, 0x1D2B, LL), (0x1D2C, 0x1D6A, LM), (0x1D6B, 0x1D77, LL),
+  (0x1D78, 0x1D78, LM), (0x1D79, 0x1D9A, LL), (0x1D9B, 0x1DBF, LM), (0x1DC0, 0x1DFF, MN),
+  (0x1E00, 0x1E00, LU), (0x1E01, 0x1E01, LL), (0x1E02, 0x1E02, LU), (0x1E03, 0x1E03, LL),
+  (0x1E04, 0x1E04, LU), (0x1E05, 0x1E05, LL), (0x1E06, 0x1E06, LU), (0x1E07, 0x1E07, LL),
+  (0x1E08, 0x1E08, LU), (0x1E09, 0x1E09, LL), (0x1E0A, 0x1E0A, LU), (0x1E0B, 0x1E0B, LL),
+  (0x1E0C, 0x1E0C, LU), (0x1E0D, 0x1E0D, LL), (0x1E0E, 0x1E0E, LU), (0x1E0F, 0x1E0F, LL),
+  (0x1E10, 0x1E10, LU), (0x1E11, 0x1E11, LL), (0x1E12, 0x1E12, LU), (0x1E13, 0x1E13, LL),
+  (0x1E14, 0x1E14, LU), (0x1E15, 0x1E15, LL), (0x1E16, 0x1E16, LU), (0x1E17, 0x1E17, LL),
+  (0x1E18, 0x1E18, LU), (0x1E19, 0x1E19, LL), (0x1E1A, 0x1E1A, LU), (0x1E1B, 0x1E1B, LL),
+  (0x1E1C, 0x1E1C, LU), (0x1E1D, 0x1E1D, LL), (0x1E1E, 0x1E1E, LU), (0x1E1F, 0x1E1F, LL),
+  (0x1E20, 0x1E20, LU), (0x1E21, 0x1E21, LL), (0x1E22, 0x1E22, LU), (0x1E23, 0x1E23, LL),
+  (0x1E24, 0x1E24, LU), (0x1E25, 0x1E25, LL), (0x1E26, 0x1E26, LU), (0x1E27, 0x1E27, LL),
+  (0x1E28, 0x1E28, LU), (0x1E29, 0x1E29, LL), (0x1E2A, 0x1E2A, LU), (0x1E2B, 0x1E2B, LL),
+  (0x1E2C, 0x1E2C, LU), (0x1E2D, 0x1E2D, LL), (0x1E2E, 0x1E2E, LU), (0x1E2F, 0x1E2F, LL),
+  (0x1E30, 0x1E30, LU), (0x1E31, 0x1E31, LL), (0x1E32, 0x1E32, LU), (0x1E33, 0x1E33, LL),
+  (0x1E34, 0x1E34, LU), (0x1E35, 0x1E35, LL), (0x1E36, 0x1E36, LU), (0x1E37, 0x1E37, LL),
+  (0x1E38, 0x1E38, LU), (0x1E39, 0x1E39, LL), (0x1E3A, 0x1E3A, LU), (0x1E3B, 0x1E3B, LL),
+  (0x1E3C, 0x1E3C, LU), (0x1E3D, 0x1E3D, LL), (0x1E3E, 0x1E3E, LU), (0x1E3F, 0x1E3F, LL),
+  (0x1E40, 0x1E40, LU), (0x1E41, 0x1E41, LL), (0x1E42, 0x1E42, LU), (0x1E43, 0x1E43, LL),
+  (0x1E44, 0x1E44, LU), (0x1E45, 0x1E45, LL), (0x1E46, 0x1E46, LU), (0x1E47, 0x1E47, LL),
+  (0x1E48, 0x1E48, LU), (0x1E49, 0x1E49, LL), (0x1E4A, 0x1E4A, LU), (0x1E4B, 0x1E4B, LL),
+  (0x1E4C, 0x1E4C, LU), (0x1E4D, 0x1E4D, LL), (0x1E4E, 0x1E4E, LU), (0x1E4F, 0x1E4F, LL),
+  (0x1E50, 0x1E50, LU), (0x1E51, 0x1E51, LL), (0x1E52, 0x1E52, LU), (0x1E53, 0x1E53, LL),
+  (0x1E54, 0x1E54, LU), (0x1E55, 0x1E55, LL), (0x1E56, 0x1E56, LU), (0x1E57, 0x1E57, LL),
+  (0x1E58, 0x1E58, LU), (0x1E59, 0x1E59, LL), (0x1E5A, 0x1E5A, LU), (0x1E5B, 0x1E5B, LL),
+  (0x1E5C, 0x1E5C, LU), (0x1E5D, 0x1E5D, LL), (0x1E5E, 0x1E5E, LU), (0x1E5F, 0x1E5F, LL),
+  (0x1E60, 0x1E60, LU), (0x1E61, 0x1E61, LL), (0x1E62, 0x1E62, LU), (0x1E63, 0x1E63, LL),
+  (0x1E64, 0x1E64, LU), (0x1E65, 0x1E65, LL), (0x1E66, 0x1E66, LU), (0x1E67, 0x1E67, LL),
+  (0x1E68, 0x1E68, LU), (0x1E69, 0x1E69, LL), (0x1E6A, 0x1E6A, LU), (0x1E6B, 0x1E6B, LL),
+  (0x1E6C, 0x1E6C, LU), (0x1E6D, 0x1E6D, LL), (0x1E6E, 0x1E6E, LU), (0x1E6F, 0x1E6F, LL),
+  (0x1E70, 0x1E70, LU), (0x1E71, 0x1E71, LL), (0x1E72, 0x1E72, LU), (0x1E73, 0x1E73, LL),
+  (0x1E74, 0x1E74, LU), (0x1E75, 0x1E75, LL), (0x1E76, 0x1E76, LU), (0x1E77, 0x1E77, LL),
+  (0x1E78, 0x1E78, LU), (0x1E79, 0x1E79, LL), (0x1E7A, 0x1E7A, LU), (0x1E7B, 0x1E7B, LL),
+  (0x1E7C, 0x1E7C, LU), (0x1E7D, 0x1E7D, LL), (0x1E7E, 0x1E7E, LU), (0x1E7F, 0x1E7F, LL),
+  (0x1E80, 0x1E80, LU), (0x1E81, 0x1E81, LL), (0x1E82, 0x1E82, LU), (0x1E83, 0x1E83, LL),
+  (0x1E84, 0x1E84, LU), (0x1E85, 0x1E85, LL), (0x1E86, 0x1E86, LU), (0x1E87, 0x1E87, LL),
+  (0x1E88, 0x1E88, LU), (0x1E89, 0x1E89, LL), (0x1E8A, 0x1E8A, LU), (0x1E8B, 0x1E8B, LL),
+  (0x1E8C, 0x1E8C, LU), (0x1E8D, 0x1E8D, LL), (0x1E8E, 0x1E8E, LU), (0x1E8F, 0x1E8F, LL),
+  (0x1E90, 0x1E90, LU), (0x1E91, 0x1E91, LL), (0x1E92, 0x1E92, LU), (0x1E93, 0x1E93, LL),
+  (0x1E94, 0x1E94, LU), (0x1E95, 0x1E9D, LL), (0x1E9E, 0x1E9E, LU), (0x1E9F, 0x1E9F, LL),
+  (0x1EA0, 0x1EA0, LU), (0x1EA1, 0x1EA1, LL), (0x1EA2, 0x1EA2, LU), (0x1EA3, 0x1EA3, LL),
+  (0x1EA4, 0x1EA4, LU), (0x1EA5, 0x1EA5, LL), (0x1EA6, 0x1EA6, LU), (0x1EA7, 0x1EA7, LL),
+  (0x1EA8, 0x1EA8, LU), (0x1EA9, 0x1EA9, LL), (0x1EAA, 0x1EAA, LU), (0x1EAB, 0x1EAB, LL),
+  (0x1EAC, 0x1EAC, LU), (0x1EAD, 0x1EAD, LL), (0x1EAE, 0x1EAE, LU), (0x1EAF, 0x1EAF, LL),
+  (0x1EB0, 0x1EB0, LU), (0x1EB1, 0x1EB1, LL), (0x1EB2, 0x1EB2, LU), (0x1EB3, 0x1EB3, LL),
+  (0x1EB4, 0x1EB4, LU), (0x1EB5, 0x1EB5, LL), (0x1EB6, 0x1EB6, LU), (0x1EB7, 0x1EB7, LL),
+  (0x1EB8, 0x1EB8, LU), (0x1EB9, 0x1EB9, LL), (0x1EBA, 0x1EBA, LU), (0x1EBB, 0x1EBB, LL),
+  (0x1EBC, 0x1EBC, LU), (0x1EBD, 0x1EBD, LL), (0x1EBE, 0x1EBE, LU), (0x1EBF, 0x1EBF, LL),
+  (0x1EC0, 0x1EC0, LU), (0x1EC1, 0x1EC1, LL), (0x1EC2, 0x1EC2, LU), (0x1EC3, 0x1EC3, LL),
+  (0x1EC4, 0x1EC4, LU), (0x1EC5, 0x1EC5, LL), (0x1EC6, 0x1EC6, LU), (0x1EC7, 0x1EC7, LL),
+  (0x1EC8, 0x1EC8, LU), (0x1EC9, 0x1EC9, LL), (0x1ECA, 0x1ECA, LU), (0x1ECB, 0x1ECB, LL),
+  (0x1ECC, 0x1ECC, LU), (0x1ECD, 0x1ECD, LL), (0x1ECE, 0x1ECE, LU), (0x1ECF, 0x1ECF, LL),
+  (0x1ED0, 0x1ED0, LU), (0x1ED1, 0x1ED1, LL), (0x1ED2, 0x1ED2, LU), (0x1ED3, 0x1ED3, LL),
+  (0x1ED4, 0x1ED4, LU), (0x1ED5, 0x1ED5, LL), (0x1ED6, 0x1ED6, LU), (0x1ED7, 0x1ED7, LL),
+  (0x1ED8, 0x1ED8, LU), (0x1ED9, 0x1ED9, LL), (0x1EDA, 0x1EDA, LU), (0x1EDB, 0x1EDB, LL),
+  (0x1EDC, 0x1EDC, LU), (0x1EDD, 0x1EDD, LL), (0x1EDE, 0x1EDE, LU), (0x1EDF, 0x1EDF, LL),
+  (0x1EE0, 0x1EE0, LU), (0x1EE1, 0x1EE1, LL), (0x1EE2, 0x1EE2, LU), (0x1EE3, 0x1EE3, LL),
+  (0x1EE4, 0x1EE4, LU), (0x1EE5, 0x1EE5, LL), (0x1EE6, 0x1EE6, LU), (0x1EE7, 0x1EE7, LL),
+  (0x1EE8, 0x1EE8, LU), (0x1EE9, 0x1EE9, LL), (0x1EEA, 0x1EEA, LU), (0x1EEB, 0x1EEB, LL),
+  (0x1EEC, 0x1EEC, LU), (0x1EED, 0x1EED, LL), (0x1EEE, 0x1EEE, LU), (0x1EEF, 0x1EEF, LL),
+  (0x1EF0, 0x1EF0, LU), (0x1EF1, 0x1EF1, LL), (0x1EF2, 0x1EF2, LU), (0x1EF3, 0x1EF3, LL),
+  (0x1EF4, 0x1EF4, LU), (0x1EF5, 0x1EF5, LL), (0x1EF6, 0x1EF6, LU), (0x1EF7, 0x1EF7, LL),
+  (0x1EF8, 0x1EF8, LU), (0x1EF9, 0x1EF9, LL), (0x1EFA, 0x1EFA, LU), (0x1EFB, 0x1EFB, LL),
+  (0x1EFC, 0x1EFC, LU), (0x1EFD, 0x1EFD, LL), (0x1EFE, 0x1EFE, LU), (0x1EFF, 0x1F07, LL),
+  (0x1F08, 0x1F0F, LU), (0x1F10, 0x1F15, LL), (0x1F18, 0x1F1D, LU), (0x1F20, 0x1F27, LL),
+  (0x1F28, 0x1F2F, LU), (0x1F30, 0x1F37, LL), (0x1F38, 0x1F3F, LU), (0x1F40, 0x1F45, LL),
+  (0x1F48, 0x1F4D, LU), (0x1F50, 0x1F57, LL), (0x1F59, 0x1F59, LU), (0x1F5B, 0x1F5B, LU),
+  (0x1F5D, 0x1F5D, LU), (0x1F5F, 0x1F5F, LU), (0x1F60, 0x1F67, LL), (0x1F68, 0x1F6F, LU),
+  (0x1F70, 0x1F7D, LL), (0x1F80, 0x1F87, LL), (0x1F88, 0x1F8F, LT), (0x1F90, 0x1F97, LL),
+  (0x1F98, 0x1F9F, LT), (0x1FA0, 0x1FA7, LL), (0x1FA8, 0x1FAF, LT), (0x1FB0, 0x1FB4, LL),
+  (0x1FB6, 0x1FB7, LL), (0x1FB8, 0x1FBB, LU), (0x1FBC, 0x1FBC, LT), (0x1FBD, 0x1FBD, SK),
+  (0x1FBE, 0x1FBE, LL), (0x1FBF, 0x1FC1, SK), (0x1FC2, 0x1FC4, LL), (0x1FC6, 0x1FC7, LL),
+  (0x1FC8, 0x1FCB, LU), (0x1FCC, 0x1FCC, LT), (0x1FCD, 0x1FCF, SK), (0x1FD0, 0x1FD3, LL),
+  (0x1FD6, 0x1FD7, LL), (0x1FD8, 0x1FDB, LU), (0x1FDD, 0x1FDF, SK), (0x1FE0, 0x1FE7, LL),
+  (0x1FE8, 0x1FEC, LU), (0x1FED, 0x1FEF, SK), (0x1FF2, 0x1FF4, LL), (0x1FF6, 0x1FF7, LL),
+  (0x1FF8, 0x1FFB, LU), (0x1FFC, 0x1FFC, LT), (0x1FFD, 0x1FFE, SK), (0x2000, 0x200A, ZS),
+  (0x200B, 0x200F, CF), (0x2010, 0x2015, PD), (0x2016, 0x2017, PO), (0x2018, 0x2018, PI),
+  (0x2019, 0x2019, PF), (0x201A, 0x201A, PS), (0x201B, 0x201C, PI), (0x201D, 0x201D, PF),
+  (0x201E, 0x201E, PS), (0x201F, 0x201F, PI), (0x2020, 0x2027, PO), (0x2028, 0x2028, ZL),
+  (0x2029, 0x2029, ZP), (0x202A, 0x202E, CF), (0x202F, 0x202F, ZS), (0x2030, 0x2038, PO),
+  (0x2039, 0x2039, PI), (0x203A, 0x203A, PF), (0x203B, 0x203E, PO), (0x203F, 0x2040, PC),
+  (0x2041, 0x2043, PO), (0x2044, 0x2044, SM), (0x2045, 0x2045, PS), (0x2046, 0x2046, PE),
+  (0x2047, 0x2051, PO), (0x2052, 0x2052, SM), (0x2053, 0x2053, PO), (0x2054, 0x2054, PC),
+  (0x2055, 0x205E, PO), (0x205F, 0x205F, ZS), (0x2060, 0x2064, CF), (0x2066, 0x206F, CF),
+  (0x2070, 0x2070, NO), (0x2071, 0x2071, LM), (0x2074, 0x2079, NO), (0x207A, 0x207C, SM),
+  (0x207D, 0x207D, PS), (0x207E, 0x207E, PE), (0x207F, 0x207F, LM), (0x2080, 0x2089, NO),
+  (0x208A, 0x208C, SM), (0x208D, 0x208D, PS), (0x208E, 0x208E, PE), (0x2090, 0x209C, LM),
+  (0x20A0, 0x20C0, SC), (0x20D0, 0x20DC, MN), (0x20DD, 0x20E0, ME), (0x20E1, 0x20E1, MN),
+  (0x20E2, 0x20E4, ME), (0x20E5, 0x20F0, MN), (0x2100, 0x2101, SO), (0x2102, 0x2102, LU),
+  (0x2103, 0x2106, SO), (0x2107, 0x2107, LU), (0x2108, 0x2109, SO), (0x210A, 0x210A, LL),
+  (0x210B, 0x210D, LU), (0x210E, 0x210F, LL), (0x2110, 0x2112, LU), (0x2113, 0x2113, LL),
+  (0x2114, 0x2114, SO), (0x2115, 0x2115, LU), (0x2116, 0x2117, SO), (0x2118, 0x2118, SM),
+  (0x2119, 0x211D, LU), (0x211E, 0x2123, SO), (0x2124, 0x2124, LU), (0x2125, 0x2125, SO),
+  (0x2126, 0x2126, LU), (0x2127, 0x2127, SO), (0x2128, 0x2128, LU), (0x2129, 0x2129, SO),
+  (0x212A, 0x212D, LU), (0x212E, 0x212E, SO), (0x212F, 0x212F, LL), (0x2130, 0x2133, LU),
+  (0x2134, 0x2134, LL), (0x2135, 0x2138, LO), (0x2139, 0x2139, LL), (0x213A, 0x213B, SO),
+  (0x213C, 0x213D, LL), (0x213E, 0x213F, LU), (0x2140, 0x2144, SM), (0x2145, 0x2145, LU),
+  (0x2146, 0x2149, LL), (0x214A, 0x214A, SO), (0x214B, 0x214B, SM), (0x214C, 0x214D, SO),
+  (0x214E, 0x214E, LL), (0x214F, 0x214F, SO), (0x2150, 0x215F, NO), (0x2160, 0x2182, NL),
+  (0x2183, 0x2183, LU), (0x2184, 0x2184, LL), (0x2185, 0x2188, NL), (0x2189, 0x2189, NO),
+  (0x218A, 0x218B, SO), (0x2190, 0x2194, SM), (0x2195, 0x2199, SO), (0x219A, 0x219B, SM),
+  (0x219C, 0x219F, SO), (0x21A0, 0x21A0, SM), (0x21A1, 0x21A2, SO), (0x21A3, 0x21A3, SM),
+  (0x21A4, 0x21A5, SO), (0x21A6, 0x21A6, SM), (0x21A7, 0x21AD, SO), (0x21AE, 0x21AE, SM),
+  (0x21AF, 0x21CD, SO), (0x21CE, 0x21CF, SM), (0x21D0, 0x21D1, SO), (0x21D2, 0x21D2, SM),
+  (0x21D3, 0x21D3, SO), (0x21D4, 0x21D4, SM), (0x21D5, 0x21F3, SO), (0x21F4, 0x22FF, SM),
+  (0x2300, 0x2307, SO), (0x2308, 0x2308, PS), (0x2309, 0x2309, PE), (0x230A, 0x230A, PS),
+  (0x230B, 0x230B, PE), (0x230C, 0x231F, SO), (0x2320, 0x2321, SM), (0x2322, 0x2328, SO),
+  (0x2329, 0x2329, PS), (0x232A, 0x232A, PE), (0x232B, 0x237B, SO), (0x237C, 0x237C, SM),
+  (0x237D, 0x239A, SO), (0x239B, 0x23B3, SM), (0x23B4, 0x23DB, SO), (0x23DC, 0x23E1, SM),
+  (0x23E2, 0x2426, SO), (0x2440, 0x244A, SO), (0x2460, 0x249B, NO), (0x249C, 0x24E9, SO),
+  (0x24EA, 0x24FF, NO), (0x2500, 0x25B6, SO), (0x25B7, 0x25B7, SM), (0x25B8, 0x25C0, SO),
+  (0x25C1, 0x25C1, SM), (0x25C2, 0x25F7, SO), (0x25F8, 0x25FF, SM), (0x2600, 0x266E, SO),
+  (0x266F, 0x266F, SM), (0x2670, 0x2767, SO), (0x2768, 0x2768, PS), (0x2769, 0x2769, PE),
+  (0x276A, 0x276A, PS), (0x276B, 0x276B, PE), (0x276C, 0x276C, PS), (0x276D, 0x276D, PE),
+  (0x276E, 0x276E, PS), (0x276F, 0x276F, PE), (0x2770, 0x2770, PS), (0x2771, 0x2771, PE),
+  (0x2772, 0x2772, PS), (0x2773, 0x2773, PE), (0x2774, 0x2774, PS), (0x2775, 0x2775, PE),
+  (0x2776, 0x2793, NO), (0x2794, 0x27BF, SO), (0x27C0, 0x27C4, SM), (0x27C5, 0x27C5, PS),
+  (0x27C6, 0x27C6, PE), (0x27C7, 0x27E5, SM), (0x27E6, 0x27E6, PS), (0x27E7, 0x27E7, PE),
+  (0x27E8, 0x27E8, PS), (0x27E9, 0x27E9, PE), (0x27EA, 0x27EA, PS), (0x27EB, 0x27EB, PE),
+  (0x27EC, 0x27EC, PS), (0x27ED, 0x27ED, PE), (0x27EE, 0x27EE, PS), (0x27EF, 0x27EF, PE),
+  (0x27F0, 0x27FF, SM), (0x2800, 0x28FF, SO), (0x2900, 0x2982, SM), (0x2983, 0x2983, PS),
+  (0x2984, 0x2984, PE), (0x2985, 0x2985, PS), (0x2986, 0x2986, PE), (0x2987, 0x2987, PS),
+  (0x2988, 0x2988, PE), (0x2989, 0x2989, PS), (0x298A, 0x298A, PE), (0x298B, 0x298B, PS),
+  (0x298C, 0x298C, PE), (0x298D, 0x298D, PS), (0x298E, 0x298E, PE), (0x298F, 0x298F, PS),
+  (0x2990, 0x2990, PE), (0x2991, 0x2991, PS), (0x2992, 0x2992, PE), (0x2993, 0x2993, PS),
+  (0x2994, 0x2994, PE), (0x2995, 0x2995, PS), (0x2996, 0x2996, PE), (0x2997, 0x2997, PS),
+  (0x2998, 0x2998, PE), (0x2999, 0x29D7, SM), (0x29D8, 0x29D8, PS), (0x29D9, 0x29D9, PE),
+  (0x29DA, 0x29DA, PS), (0x29DB, 0x29DB, PE), (0x29DC, 0x29FB, SM), (0x29FC, 0x29FC, PS),
+  (0x29FD, 0x29FD, PE), (0x29FE, 0x2AFF, SM), (0x2B00, 0x2B2F, SO), (0x2B30, 0x2B44, SM),
+  (0x2B45, 0x2B46, SO), (0x2B47, 0x2B4C, SM), (0x2B4D, 0x2B73, SO), (0x2B76, 0x2B95, SO),
+  (0x2B97, 0x2BFF, SO), (0x2C00, 0x2C2F, LU), (0x2C30, 0x2C5F, LL), (0x2C60, 0x2C60, LU),
+  (0x2C61, 0x2C61, LL), (0x2C62, 0x2C64, LU), (0x2C65, 0x2C66, LL), (0x2C67, 0x2C67, LU),
+  (0x2C68, 0x2C68, LL), (0x2C69, 0x2C69, LU), (0x2C6A, 0x2C6A, LL), (0x2C6B, 0x2C6B, LU),
+  (0x2C6C, 0x2C6C, LL), (0x2C6D, 0x2C70, LU), (0x2C71, 0x2C71, LL), (0x2C72, 0x2C72, LU),
+  (0x2C73, 0x2C74, LL), (0x2C75, 0x2C75, LU), (0x2C76, 0x2C7B, LL), (0x2C7C, 0x2C7D, LM),
+  (0x2C7E, 0x2C80, LU), (0x2C81, 0x2C81, LL), (0x2C82, 0x2C82, LU), (0x2C83, 0x2C83, LL),
+  (0x2C84, 0x2C84, LU), (0x2C85, 0x2C85, LL), (0x2C86, 0x2C86, LU), (0x2C87, 0x2C87, LL),
+  (0x2C88, 0x2C88, LU), (0x2C89, 0x2C89, LL), (0x2C8A, 0x2C8A, LU), (0x2C8B, 0x2C8B, LL),
+  (0x2C8C, 0x2C8C, LU), (0x2C8D, 0x2C8D, LL), (0x2C8E, 0x2C8E, LU), (0x2C8F, 0x2C8F, LL),
+  (0x2C90, 0x2C90, LU), (0x2C91, 0x2C91, LL), (0x2C92, 0x2C92, LU), (0x2C93, 0x2C93, LL),
+  (0x2C94, 0x2C94, LU), (0x2C95, 0x2C95, LL), (0x2C96, 0x2C96, LU), (0x2C97, 0x2C97, LL),
+  (0x2C98, 0x2C98, LU), (0x2C99, 0x2C99, LL), (0x2C9A, 0x2C9A, LU), (0x2C9B, 0x2C9B, LL),
+  (0x2C9C, 0x2C9C, LU), (0x2C9D, 0x2C9D, LL), (0x2C9E, 0x2C9E, LU), (0x2C9F, 0x2C9F, LL),
+  (0x2CA0, 0x2CA0, LU), (0x2CA1, 0x2CA1, LL), (0x2CA2, 0x2CA2, LU), (0x2CA3, 0x2CA3, LL),
+  (0x2CA4, 0x2CA4, LU), (0x2CA5, 0x2CA5, LL), (0x2CA6, 0x2CA6, LU), (0x2CA7, 0x2CA7, LL),
+  (0x2CA8, 0x2CA8, LU), (0x2CA9, 0x2CA9, LL), (0x2CAA, 0x2CAA, LU), (0x2CAB, 0x2CAB, LL),
+  (0x2CAC, 0x2CAC, LU), (0x2CAD, 0x2CAD, LL), (0x2CAE, 0x2CAE, LU), (0x2CAF, 0x2CAF, LL),
+  (0x2CB0, 0x2CB0, LU), (0x2CB1, 0x2CB1, LL), (0x2CB2, 0x2CB2, LU), (0x2CB3, 0x2CB3, LL),
+  (0x2CB4, 0x2CB4, LU), (0x2CB5, 0x2CB5, LL), (0x2CB6, 0x2CB6, LU), (0x2CB7, 0x2CB7, LL),
+  (0x2CB8, 0x2CB8, LU), (0x2CB9, 0x2CB9, LL), (0x2CBA, 0x2CBA, LU), (0x2CBB, 0x2CBB, LL),
+  (0x2CBC, 0x2CBC, LU), (0x2CBD, 0x2CBD, LL), (0x2CBE, 0x2CBE, LU), (0x2CBF, 0x2CBF, LL),
+  (0x2CC0, 0x2CC0, LU), (0x2CC1, 0x2CC1, LL), (0x2CC2, 0x2CC2, LU), (0x2CC3, 0x2CC3, LL),
+  (0x2CC4, 0x2CC4, LU), (0x2CC5, 0x2CC5, LL), (0x2CC6, 0x2CC6, LU), (0x2CC7, 0x2CC7, LL),
+  (0x2CC8, 0x2CC8, LU), (0x2CC9, 0x2CC9, LL), (0x2CCA, 0x2CCA, LU), (0x2CCB, 0x2CCB, LL),
+  (0x2CCC, 0x2CCC, LU), (0x2CCD, 0x2CCD, LL), (0x2CCE, 0x2CCE, LU), (0x2CCF, 0x2CCF, LL),
+  (0x2CD0, 0x2CD0, LU), (0x2CD1, 0x2CD1, LL), (0x2CD2, 0x2CD2, LU), (0x2CD3, 0x2CD3, LL),
+  (0x2CD4, 0x2CD4, LU), (0x2CD5, 0x2CD5, LL), (0x2CD6, 0x2CD6, LU), (0x2CD7, 0x2CD7, LL),
+  (0x2CD8, 0x2CD8, LU), (0x2CD9, 0x2CD9, LL), (0x2CDA, 0x2CDA, LU), (0x2CDB, 0x2CDB, LL),
+  (0x2CDC, 0x2CDC, LU), (0x2CDD, 0x2CDD, LL), (0x2CDE, 0x2CDE, LU), (0x2CDF, 0x2CDF, LL),
+  (0x2CE0, 0x2CE0, LU), (0x2CE1, 0x2CE1, LL), (0x2CE2, 0x2CE2, LU), (0x2CE3, 0x2CE4, LL),
+  (0x2CE5, 0x2CEA, SO), (0x2CEB, 0x2CEB, LU), (0x2CEC, 0x2CEC, LL), (0x2CED, 0x2CED, LU),
+  (0x2CEE, 0x2CEE, LL), (0x2CEF, 0x2CF1, MN), (0x2CF2, 0x2CF2, LU), (0x2CF3, 0x2CF3, LL),
+  (0x2CF9, 0x2CFC, PO), (0x2CFD, 0x2CFD, NO), (0x2CFE, 0x2CFF, PO), (0x2D00, 0x2D25, LL),
+  (0x2D27, 0x2D27, LL), (0x2D2D, 0x2D2D, LL), (0x2D30, 0x2D67, LO), (0x2D6F, 0x2D6F, LM),
+  (0x2D70, 0x2D70, PO), (0x2D7F, 0x2D7F, MN), (0x2D80, 0x2D96, LO), (0x2DA0, 0x2DA6, LO),
+  (0x2DA8, 0x2DAE, LO), (0x2DB0, 0x2DB6, LO), (0x2DB8, 0x2DBE, LO), (0x2DC0, 0x2DC6, LO),
+  (0x2DC8, 0x2DCE, LO), (0x2DD0, 0x2DD6, LO), (0x2DD8, 0x2DDE, LO), (0x2DE0, 0x2DFF, MN),
+  (0x2E00, 0x2E01, PO), (0x2E02, 0x2E02, PI), (0x2E03, 0x2E03, PF), (0x2E04, 0x2E04, PI),
+  (0x2E05, 0x2E05, PF), (0x2E06, 0x2E08, PO), (0x2E09, 0x2E09, PI), (0x2E0A, 0x2E0A, PF),
+  (0x2E0B, 0x2E0B, PO), (0x2E0C, 0x2E0C, PI), (0x2E0D, 0x2E0D, PF), (0x2E0E, 0x2E16, PO),
+  (0x2E17, 0x2E17, PD), (0x2E18, 0x2E19, PO), (0x2E1A, 0x2E1A, PD), (0x2E1B, 0x2E1B, PO),
+  (0x2E1C, 0x2E1C, PI), (0x2E1D, 0x2E1D, PF), (0x2E1E, 0x2E1F, PO), (0x2E20, 0x2E20, PI),
+  (0x2E21, 0x2E21, PF), (0x2E22, 0x2E22, PS), (0x2E23, 0x2E23, PE), (0x2E24, 0x2E24, PS),
+  (0x2E25, 0x2E25, PE), (0x2E26, 0x2E26, PS), (0x2E27, 0x2E27, PE), (0x2E28, 0x2E28, PS),
+  (0x2E29, 0x2E29, PE), (0x2E2A, 0x2E2E, PO), (0x2E2F, 0x2E2F, LM), (0x2E30, 0x2E39, PO),
+  (0x2E3A, 0x2E3B, PD), (0x2E3C, 0x2E3F, PO), (0x2E40, 0x2E40, PD), (0x2E41, 0x2E41, PO),
+  (0x2E42, 0x2E42, PS), (0x2E43, 0x2E4F, PO), (0x2E50, 0x2E51, SO), (0x2E52, 0x2E54, PO),
+  (0x2E55, 0x2E55, PS), (0x2E56, 0x2E56, PE), (0x2E57, 0x2E57, PS), (0x2E58, 0x2E58, PE),
+  (0x2E59, 0x2E59, PS), (0x2E5A, 0x2E5A, PE), (0x2E5B, 0x2E5B, PS), (0x2E5C, 0x2E5C, PE),
+  (0x2E5D, 0x2E5D, PD), (0x2E80, 0x2E99, SO), (0x2E9B, 0x2EF3, SO), (0x2F00, 0x2FD5, SO),
+  (0x2FF0, 0x2FFF, SO), (0x3000, 0x3000, ZS), (0x3001, 0x3003, PO), (0x3004, 0x3004, SO),
+  (0x3005, 0x3005, LM), (0x3006, 0x3006, LO), (0x3007, 0x3007, NL), (0x3008, 0x3008, PS),
+  (0x3009, 0x3009, PE), (0x300A, 0x300A, PS), (0x300B, 0x300B, PE), (0x300C, 0x300C, PS),
+  (0x300D, 0x300D, PE), (0x300E, 0x300E, PS), (0x300F, 0x300F, PE), (0x3010, 0x3010, PS),
+  (0x3011, 0x3011, PE), (0x3012, 0x3013, SO), (0x3014, 0x3014, PS), (0x3015, 0x3015, PE),
+  (0x3016, 0x3016, PS), (0x3017, 0x3017, PE), (0x3018, 0x3018, PS), (0x3019, 0x3019, PE),
+  (0x301A, 0x301A, PS), (0x301B, 0x301B, PE), (0x301C, 0x301C, PD), (0x301D, 0x301D, PS),
+  (0x301E, 0x301F, PE), (0x3020, 0x3020, SO), (0x3021, 0x3029, NL), (0x302A, 0x302D, MN),
+  (0x302E, 0x302F, MC), (0x3030, 0x3030, PD), (0x3031, 0x3035, LM), (0x3036, 0x3037, SO),
+  (0x3038, 0x303A, NL), (0x303B, 0x303B, LM), (0x303C, 0x303C, LO), (0x303D, 0x303D, PO),
+  (0x303E, 0x303F, SO), (0x3041, 0x3096, LO), (0x3099, 0x309A, MN), (0x309B, 0x309C, SK),
+  (0x309D, 0x309E, LM), (0x309F, 0x309F, LO), (0x30A0, 0x30A0, PD), (0x30A1, 0x30FA, LO),
+  (0x30FB, 0x30FB, PO), (0x30FC, 0x30FE, LM), (0x30FF, 0x30FF, LO), (0x3105, 0x312F, LO),
+  (0x3131, 0x318E, LO), (0x3190, 0x3191, SO), (0x3192, 0x3195, NO), (0x3196, 0x319F, SO),
+  (0x31A0, 0x31BF, LO), (0x31C0, 0x31E3, SO), (0x31EF, 0x31EF, SO), (0x31F0, 0x31FF, LO),
+  (0x3200, 0x321E, SO), (0x3220, 0x3229, NO), (0x322A, 0x3247, SO), (0x3248, 0x324F, NO),
+  (0x3250, 0x3250, SO), (0x3251, 0x325F, NO), (0x3260, 0x327F, SO), (0x3280, 0x3289, NO),
+  (0x328A, 0x32B0, SO), (0x32B1, 0x32BF, NO), (0x32C0, 0x33FF, SO), (0x3400, 0x4DBF, LO),
+  (0x4DC0, 0x4DFF, SO), (0x4E00, 0xA014, LO), (0xA015, 0xA015, LM), (0xA016, 0xA48C, LO),
+  (0xA490, 0xA4C6, SO), (0xA4D0, 0xA4F7, LO), (0xA4F8, 0xA4FD, LM), (0xA4FE, 0xA4FF, PO),
+  (0xA500, 0xA60B, LO), (0xA60C, 0xA60C, LM), (0xA60D, 0xA60F, PO), (0xA610, 0xA61F, LO),
+  (0xA620, 0xA629, ND), (0xA62A, 0xA62B, LO), (0xA640, 0xA640, LU), (0xA641, 0xA641, LL),
+  (0xA642, 0xA642, LU), (0xA643, 0xA643, LL), (0xA644, 0xA644, LU), (0xA645, 0xA645, LL),
+  (0xA646, 0xA646, LU), (0xA647, 0xA647, LL), (0xA648, 0xA648, LU), (0xA649, 0xA649, LL),
+  (0xA64A, 0xA64A, LU), (0xA64B, 0xA64B, LL), (0xA64C, 0xA64C, LU), (0xA64D, 0xA64D, LL),
+  (0xA64E, 0xA64E, LU), (0xA64F, 0xA64F, LL), (0xA650, 0xA650, LU), (0xA651, 0xA651, LL),
+  (0xA652, 0xA652, LU), (0xA653, 0xA653, LL), (0xA654, 0xA654, LU), (0xA655, 0xA655, LL),
+  (0xA656, 0xA656, LU), (0xA657, 0xA657, LL), (0xA658, 0xA658, LU), (0xA659, 0xA659, LL),
+  (0xA65A, 0xA65A, LU), (0xA65B, 0xA65B, LL), (0xA65C, 0xA65C, LU), (0xA65D, 0xA65D, LL),
+  (0xA65E, 0xA65E, LU), (0xA65F, 0xA65F, LL), (0xA660, 0xA660, LU), (0xA661, 0xA661, LL),
+  (0xA662, 0xA662, LU), (0xA663, 0xA663, LL), (0xA664, 0xA664, LU), (0xA665, 0xA665, LL),
+  (0xA666, 0xA666, LU), (0xA667, 0xA667, LL), (0xA668, 0xA668, LU), (0xA669, 0xA669, LL),
+  (0xA66A, 0xA66A, LU), (0xA66B, 0xA66B, LL), (0xA66C, 0xA66C, LU), (0xA66D, 0xA66D, LL),
+  (0xA66E, 0xA66E, LO), (0xA66F, 0xA66F, MN), (0xA670, 0xA672, ME), (0xA673, 0xA673, PO),
+  (0xA674, 0xA67D, MN), (0xA67E, 0xA67E, PO), (0xA67F, 0xA67F, LM), (0xA680, 0xA680, LU),
+  (0xA681, 0xA681, LL), (0xA682, 0xA682, LU), (0xA683, 0xA683, LL), (0xA684, 0xA684, LU),
+  (0xA685, 0xA685, LL), (0xA686, 0xA686, LU), (0xA687, 0xA687, LL), (0xA688, 0xA688, LU),
+  (0xA689, 0xA689, LL), (0xA68A, 0xA68A, LU), (0xA68B, 0xA68B, LL), (0xA68C, 0xA68C, LU),
+  (0xA68D, 0xA68D, LL), (0xA68E, 0xA68E, LU), (0xA68F, 0xA68F, LL), (0xA690, 0xA690, LU),
+  (0xA691, 0xA691, LL), (0xA692, 0xA692, LU), (0xA693, 0xA693, LL), (0xA694, 0xA694, LU),
+  (0xA695, 0xA695, LL), (0xA696, 0xA696, LU), (0xA697, 0xA697, LL), (0xA698, 0xA698, LU),
+  (0xA699, 0xA699, LL), (0xA69A, 0xA69A, LU), (0xA69B, 0xA69B, LL), (0xA69C, 0xA69D, LM),
+  (0xA69E, 0xA69F, MN), (0xA6A0, 0xA6E5, LO), (0xA6E6, 0xA6EF, NL), (0xA6F0, 0xA6F1, MN),
+  (0xA6F2, 0xA6F7, PO), (0xA700, 0xA716, SK), (0xA717, 0xA71F, LM), (0xA720, 0xA721, SK),
+  (0xA722, 0xA722, LU), (0xA723, 0xA723, LL), (0xA724, 0xA724, LU), (0xA725, 0xA725, LL),
+  (0xA726, 0xA726, LU), (0xA727, 0xA727, LL), (0xA728, 0xA728, LU), (0xA729, 0xA729, LL),
+  (0xA72A, 0xA72A, LU), (0xA72B, 0xA72B, LL), (0xA72C, 0xA72C, LU), (0xA72D, 0xA72D, LL),
+  (0xA72E, 0xA72E, LU), (0xA72F, 0xA731, LL), (0xA732, 0xA732, LU), (0xA733, 0xA733, LL),
+  (0xA734, 0xA734, LU), (0xA735, 0xA735, LL), (0xA736, 0xA736, LU), (0xA737, 0xA737, LL),
+  (0xA738, 0xA738, LU), (0xA739, 0xA739, LL), (0xA73A, 0xA73A, LU), (0xA73B, 0xA73B, LL),
+  (0xA73C, 0xA73C, LU), (0xA73D, 0xA73D, LL), (0xA73E, 0xA73E, LU), (0xA73F, 0xA73F, LL),
+  (0xA740, 0xA740, LU), (0xA741, 0xA741, LL), (0xA742, 0xA742, LU), (0xA743, 0xA743, LL),
+  (0xA744, 0xA744, LU), (0xA745, 0xA745, LL), (0xA746, 0xA746, LU), (0xA747, 0xA747, LL),
+  (0xA748, 0xA748, LU), (0xA749, 0xA749, LL), (0xA74A, 0xA74A, LU), (0xA74B, 0xA74B, LL),
+  (0xA74C, 0xA74C, LU), (0xA74D, 0xA74D, LL), (0xA74E, 0xA74E, LU), (0xA74F, 0xA74F, LL),
+  (0xA750, 0xA750, LU), (0xA751, 0xA751, LL), (0xA752, 0xA752, LU), (0xA753, 0xA753, LL),
+  (0xA754, 0xA754, LU), (0xA755, 0xA755, LL), (0xA756, 0xA756, LU), (0xA757, 0xA757, LL),
+  (0xA758, 0xA758, LU), (0xA759, 0xA759, LL), (0xA75A, 0xA75A, LU), (0xA75B, 0xA75B, LL),
+  (0xA75C, 0xA75C, LU), (0xA75D, 0xA75D, LL), (0xA75E, 0xA75E, LU), (0xA75F, 0xA75F, LL),
+  (0xA760, 0xA760, LU), (0xA761, 0xA761, LL), (0xA762, 0xA762, LU), (0xA763, 0xA763, LL),
+  (0xA764, 0xA764, LU), (0xA765, 0xA765, LL), (0xA766, 0xA766, LU), (0xA767, 0xA767, LL),
+  (0xA768, 0xA768, LU), (0xA769, 0xA769, LL), (0xA76A, 0xA76A, LU), (0xA76B, 0xA76B, LL),
+  (0xA76C, 0xA76C, LU), (0xA76D, 0xA76D, LL), (0xA76E, 0xA76E, LU), (0xA76F, 0xA76F, LL),
+  (0xA770, 0xA770, LM), (0xA771, 0xA778, LL), (0xA779, 0xA779, LU), (0xA77A, 0xA77A, LL),
+  (0xA77B, 0xA77B, LU), (0xA77C, 0xA77C, LL), (0xA77D, 0xA77E, LU), (0xA77F, 0xA77F, LL),
+  (0xA780, 0xA780, LU), (0xA781, 0xA781, LL), (0xA782, 0xA782, LU), (0xA783, 0xA783, LL),
+  (0xA784, 0xA784, LU), (0xA785, 0xA785, LL), (0xA786, 0xA786, LU), (0xA787, 0xA787, LL),
+  (0xA788, 0xA788, LM), (0xA789, 0xA78A, SK), (0xA78B, 0xA78B, LU), (0xA78C, 0xA78C, LL),
+  (0xA78D, 0xA78D, LU), (0xA78E, 0xA78E, LL), (0xA78F, 0xA78F, LO), (0xA790, 0xA790, LU),
+  (0xA791, 0xA791, LL), (0xA792, 0xA792, LU), (0xA793, 0xA795, LL), (0xA796, 0xA796, LU),
+  (0xA797, 0xA797, LL), (0xA798, 0xA798, LU), (0xA799, 0xA799, LL), (0xA79A, 0xA79A, LU),
+  (0xA79B, 0xA79B, LL), (0xA79C, 0xA79C, LU), (0xA79D, 0xA79D, LL), (0xA79E, 0xA79E, LU),
+  (0xA79F, 0xA79F, LL), (0xA7A0, 0xA7A0, LU), (0xA7A1, 0xA7A1, LL), (0xA7A2, 0xA7A2, LU),
+  (0xA7A3, 0xA7A3, LL), (0xA7A4, 0xA7A4, LU), (0xA7A5, 0xA7A5, LL), (0xA7A6, 0xA7A6, LU),
+  (0xA7A7, 0xA7A7, LL), (0xA7A8, 0xA7A8, LU), (0xA7A9, 0xA7A9, LL), (0xA7AA, 0xA7AE, LU),
+  (0xA7AF, 0xA7AF, LL), (0xA7B0, 0xA7B4, LU), (0xA7B5, 0xA7B5, LL), (0xA7B6, 0xA7B6, LU),
+  (0xA7B7, 0xA7B7, LL), (0xA7B8, 0xA7B8, LU), (0xA7B9, 0xA7B9, LL), (0xA7BA, 0xA7BA, LU),
+  (0xA7BB, 0xA7BB, LL), (0xA7BC, 0xA7BC, LU), (0xA7BD, 0xA7BD, LL), (0xA7BE, 0xA7BE, LU),
+  (0xA7BF, 0xA7BF, LL), (0xA7C0, 0xA7C0, LU), (0xA7C1, 0xA7C1, LL), (0xA7C2, 0xA7C2, LU),
+  (0xA7C3, 0xA7C3, LL), (0xA7C4, 0xA7C7, LU), (0xA7C8, 0xA7C8, LL), (0xA7C9, 0xA7C9, LU),
+  (0xA7CA, 0xA7CA, LL), (0xA7D0, 0xA7D0, LU), (0xA7D1, 0xA7D1, LL), (0xA7D3, 0xA7D3, LL),
+  (0xA7D5, 0xA7D5, LL), (0xA7D6, 0xA7D6, LU), (0xA7D7, 0xA7D7, LL), (0xA7D8, 0xA7D8, LU),
+  (0xA7D9, 0xA7D9, LL), (0xA7F2, 0xA7F4, LM), (0xA7F5, 0xA7F5, LU), (0xA7F6, 0xA7F6, LL),
+  (0xA7F7, 0xA7F7, LO), (0xA7F8, 0xA7F9, LM), (0xA7FA, 0xA7FA, LL), (0xA7FB, 0xA801, LO),
+  (0xA802, 0xA802, MN), (0xA803, 0xA805, LO), (0xA806, 0xA806, MN), (0xA807, 0xA80A, LO),
+  (0xA80B, 0xA80B, MN), (0xA80C, 0xA822, LO), (0xA823, 0xA824, MC), (0xA825, 0xA826, MN),
+  (0xA827, 0xA827, MC), (0xA828, 0xA82B, SO), (0xA82C, 0xA82C, MN), (0xA830, 0xA835, NO),
+  (0xA836, 0xA837, SO), (0xA838, 0xA838, SC), (0xA839, 0xA839, SO), (0xA840, 0xA873, LO),
+  (0xA874, 0xA877, PO), (0xA880, 0xA881, MC), (0xA882, 0xA8B3, LO), (0xA8B4, 0xA8C3, MC),
+  (0xA8C4, 0xA8C5, MN), (0xA8CE, 0xA8CF, PO), (0xA8D0, 0xA8D9, ND), (0xA8E0, 0xA8F1, MN),
+  (0xA8F2, 0xA8F7, LO), (0xA8F8, 0xA8FA, PO), (0xA8FB, 0xA8FB, LO), (0xA8FC, 0xA8FC, PO),
+  (0xA8FD, 0xA8FE, LO), (0xA8FF, 0xA8FF, MN), (0xA900, 0xA909, ND), (0xA90A, 0xA925, LO),
+  (0xA926, 0xA92D, MN), (0xA92E, 0xA92F, PO), (0xA930, 0xA946, LO), (0xA947, 0xA951, MN),
+  (0xA952, 0xA953, MC), (0xA95F, 0xA95F, PO), (0xA960, 0xA97C, LO), (0xA980, 0xA982, MN),
+  (0xA983, 0xA983, MC), (0xA984, 0xA9B2, LO), (0xA9B3, 0xA9B3, MN), (0xA9B4, 0xA9B5, MC),
+  (0xA9B6, 0xA9B9, MN), (0xA9BA, 0xA9BB, MC), (0xA9BC, 0xA9BD, MN), (0xA9BE, 0xA9C0, MC),
+  (0xA9C1, 0xA9CD, PO), (0xA9CF, 0xA9CF, LM), (0xA9D0, 0xA9D9, ND), (0xA9DE, 0xA9DF, PO),
+  (0xA9E0, 0xA9E4, LO), (0xA9E5, 0xA9E5, MN), (0xA9E6, 0xA9E6, LM), (0xA9E7, 0xA9EF, LO),
+  (0xA9F0, 0xA9F9, ND), (0xA9FA, 0xA9FE, LO), (0xAA00, 0xAA28, LO), (0xAA29, 0xAA2E, MN),
+  (0xAA2F, 0xAA30, MC), (0xAA31, 0xAA32, MN), (0xAA33, 0xAA34, MC), (0xAA35, 0xAA36, MN),
+  (0xAA40, 0xAA42, LO), (0xAA43, 0xAA43, MN), (0xAA44, 0xAA4B, LO), (0xAA4C, 0xAA4C, MN),
+  (0xAA4D, 0xAA4D, MC), (0xAA50, 0xAA59, ND), (0xAA5C, 0xAA5F, PO), (0xAA60, 0xAA6F, LO),
+  (0xAA70, 0xAA70, LM), (0xAA71, 0xAA76, LO), (0xAA77, 0xAA79, SO), (0xAA7A, 0xAA7A, LO),
+  (0xAA7B, 0xAA7B, MC), (0xAA7C, 0xAA7C, MN), (0xAA7D, 0xAA7D, MC), (0xAA7E, 0xAAAF, LO),
+  (0xAAB0, 0xAAB0, MN), (0xAAB1, 0xAAB1, LO), (0xAAB2, 0xAAB4, MN), (0xAAB5, 0xAAB6, LO),
+  (0xAAB7, 0xAAB8, MN), (0xAAB9, 0xAABD, LO), (0xAABE, 0xAABF, MN), (0xAAC0, 0xAAC0, LO),
+  (0xAAC1, 0xAAC1, MN), (0xAAC2, 0xAAC2, LO), (0xAADB, 0xAADC, LO), (0xAADD, 0xAADD, LM),
+  (0xAADE, 0xAADF, PO), (0xAAE0, 0xAAEA, LO), (0xAAEB, 0xAAEB, MC), (0xAAEC, 0xAAED, MN),
+  (0xAAEE, 0xAAEF, MC), (0xAAF0, 0xAAF1, PO), (0xAAF2, 0xAAF2, LO), (0xAAF3, 0xAAF4, LM),
+  (0xAAF5, 0xAAF5, MC), (0xAAF6, 0xAAF6, MN), (0xAB01, 0xAB06, LO), (0xAB09, 0xAB0E, LO),
+  (0xAB11, 0xAB16, LO), (0xAB20, 0xAB26, LO), (0xAB28, 0xAB2E, LO), (0xAB30, 0xAB5A, LL),
+  (0xAB5B, 0xAB5B, SK), (0xAB5C, 0xAB5F, LM), (0xAB60, 0xAB68, LL), (0xAB69, 0xAB69, LM),
+  (0xAB6A, 0xAB6B, SK), (0xAB70, 0xABBF, LL), (0xABC0, 0xABE2, LO), (0xABE3, 0xABE4, MC),
+  (0xABE5, 0xABE5, MN), (0xABE6, 0xABE7, MC), (0xABE8, 0xABE8, MN), (0xABE9, 0xABEA, MC),
+  (0xABEB, 0xABEB, PO), (0xABEC, 0xABEC, MC), (0xABED, 0xABED, MN), (0xABF0, 0xABF9, ND),
+  (0xAC00, 0xD7A3, LO), (0xD7B0, 0xD7C6, LO), (0xD7CB, 0xD7FB, LO), (0xE000, 0xF8FF, CO),
+  (0xF900, 0xFA6D, LO), (0xFA70, 0xFAD9, LO), (0xFB00, 0xFB06, LL), (0xFB13, 0xFB17, LL),
+  (0xFB1D, 0xFB1D, LO), (0xFB1E, 0xFB1E, MN), (0xFB1F, 0xFB28, LO), (0xFB29, 0xFB29, SM),
+  (0xFB2A, 0xFB36, LO), (0xFB38, 0xFB3C, LO), (0xFB3E, 0xFB3E, LO), (0xFB40, 0xFB41, LO),
+  (0xFB43, 0xFB44, LO), (0xFB46, 0xFBB1, LO), (0xFBB2, 0xFBC2, SK), (0xFBD3, 0xFD3D, LO),
+  (0xFD3E, 0xFD3E, PE), (0xFD3F, 0xFD3F, PS), (0xFD40, 0xFD4F, SO), (0xFD50, 0xFD8F, LO),
+  (0xFD92, 0xFDC7, LO), (0xFDCF, 0xFDCF, SO), (0xFDF0, 0xFDFB, LO), (0xFDFC, 0xFDFC, SC),
+  (0xFDFD, 0xFDFF, SO), (0xFE00, 0xFE0F, MN), (0xFE10, 0xFE16, PO), (0xFE17, 0xFE17, PS),
+  (0xFE18, 0xFE18, PE), (0xFE19, 0xFE19, PO), (0xFE20, 0xFE2F, MN), (0xFE30, 0xFE30, PO),
+  (0xFE31, 0xFE32, PD), (0xFE33, 0xFE34, PC), (0xFE35, 0xFE35, PS), (0xFE36, 0xFE36, PE),
+  (0xFE37, 0xFE37, PS), (0xFE38, 0xFE38, PE), (0xFE39, 0xFE39, PS), (0xFE3A, 0xFE3A, PE),
+  (0xFE3B, 0xFE3B, PS), (0xFE3C, 0xFE3C, PE), (0xFE3D, 0xFE3D, PS), (0xFE3E, 0xFE3E, PE),
+  (0xFE3F, 0xFE3F, PS), (0xFE40, 0xFE40, PE), (0xFE41, 0xFE41, PS), (0xFE42, 0xFE42, PE),
+  (0xFE43, 0xFE43, PS), (0xFE44, 0xFE44, PE), (0xFE45, 0xFE46, PO), (0xFE47, 0xFE47, PS),
+  (0xFE48, 0xFE48, PE), (0xFE49, 0xFE4C, PO), (0xFE4D, 0xFE4F, PC), (0xFE50, 0xFE52, PO),
+  (0xFE54, 0xFE57, PO), (0xFE58, 0xFE58, PD), (0xFE59, 0xFE59, PS), (0xFE5A, 0xFE5A, PE),
+  (0xFE5B, 0xFE5B, PS), (0xFE5C, 0xFE5C, PE), (0xFE5D, 0xFE5D, PS), (0xFE5E, 0xFE5E, PE),
+  (0xFE5F, 0xFE61, PO), (0xFE62, 0xFE62, SM), (0xFE63, 0xFE63, PD), (0xFE64, 0xFE66, SM),
+  (0xFE68, 0xFE68, PO), (0xFE69, 0xFE69, SC), (0xFE6A, 0xFE6B, PO), (0xFE70, 0xFE74, LO),
+  (0xFE76, 0xFEFC, LO), (0xFEFF, 0xFEFF, CF), (0xFF01, 0xFF03, PO), (0xFF04, 0xFF04, SC),
+  (0xFF05, 0xFF07, PO), (0xFF08, 0xFF08, PS), (0xFF09, 0xFF09, PE), (0xFF0A, 0xFF0A, PO),
+  (0xFF0B, 0xFF0B, SM), (0xFF0C, 0xFF0C, PO), (0xFF0D, 0xFF0D, PD), (0xFF0E, 0xFF0F, PO),
+  (0xFF10, 0xFF19, ND), (0xFF1A, 0xFF1B, PO), (0xFF1C, 0xFF1E, SM), (0xFF1F, 0xFF20, PO),
+  (0xFF21, 0xFF3A, LU), (0xFF3B, 0xFF3B, PS), (0xFF3C, 0xFF3C, PO), (0xFF3D, 0xFF3D, PE),
+  (0xFF3E, 0xFF3E, SK), (0xFF3F, 0xFF3F, PC), (0xFF40, 0xFF40, SK), (0xFF41, 0xFF5A, LL),
+  (0xFF5B, 0xFF5B, PS), (0xFF5C, 0xFF5C, SM), (0xFF5D, 0xFF5D, PE), (0xFF5E, 0xFF5E, SM),
+  (0xFF5F, 0xFF5F, PS), (0xFF60, 0xFF60, PE), (0xFF61, 0xFF61, PO), (0xFF62, 0xFF62, PS),
+  (0xFF63, 0xFF63, PE), (0xFF64, 0xFF65, PO), (0xFF66, 0xFF6F, LO), (0xFF70, 0xFF70, LM),
+  (0xFF71, 0xFF9D, LO), (0xFF9E, 0xFF9F, LM), (0xFFA0, 0xFFBE, LO), (0xFFC2, 0xFFC7, LO),
+  (0xFFCA, 0xFFCF, LO), (0xFFD2, 0xFFD7, LO), (0xFFDA, 0xFFDC, LO), (0xFFE0, 0xFFE1, SC),
+  (0xFFE2, 0xFFE2, SM), (0xFFE3, 0xFFE3, SK), (0xFFE4, 0xFFE4, SO), (0xFFE5, 0xFFE6, SC),
+  (0xFFE8, 0xFFE8, SO), (0xFFE9, 0xFFEC, SM), (0xFFED, 0xFFEE, SO), (0xFFF9, 0xFFFB, CF),
+  (0xFFFC, 0xFFFD, SO), (0x10000, 0x1000B, LO), (0x1000D, 0x10026, LO), (0x10028, 0x1003A, LO),
+  (0x1003C, 0x1003D, LO), (0x1003F, 0x1004D, LO), (0x10050, 0x1005D, LO), (0x10080, 0x100FA, LO),
+  (0x10100, 0x10102, PO), (0x10107, 0x10133, NO), (0x10137, 0x1013F, SO), (0x10140, 0x10174, NL),
+  (0x10175, 0x10178, NO), (0x10179, 0x10189, SO), (0x1018A, 0x1018B, NO), (0x1018C, 0x1018E, SO),
+  (0x10190, 0x1019C, SO), (0x101A0, 0x101A0, SO), (0x101D0, 0x101FC, SO), (0x101FD, 0x101FD, MN),
+  (0x10280, 0x1029C, LO), (0x102A0, 0x102D0, LO), (0x102E0, 0x102E0, MN), (0x102E1, 0x102FB, NO),
+  (0x10300, 0x1031F, LO), (0x10320, 0x10323, NO), (0x1032D, 0x10340, LO), (0x10341, 0x10341, NL),
+  (0x10342, 0x10349, LO), (0x1034A, 0x1034A, NL), (0x10350, 0x10375, LO), (0x10376, 0x1037A, MN),
+  (0x10380, 0x1039D, LO), (0x1039F, 0x1039F, PO), (0x103A0, 0x103C3, LO), (0x103C8, 0x103CF, LO),
+  (0x103D0, 0x103D0, PO), (0x103D1, 0x103D5, NL), (0x10400, 0x10427, LU), (0x10428, 0x1044F, LL),
+  (0x10450, 0x1049D, LO), (0x104A0, 0x104A9, ND), (0x104B0, 0x104D3, LU), (0x104D8, 0x104FB, LL),
+  (0x10500, 0x10527, LO), (0x10530, 0x10563, LO), (0x1056F, 0x1056F, PO), (0x10570, 0x1057A, LU),
+  (0x1057C, 0x1058A, LU), (0x1058C, 0x10592, LU), (0x10594, 0x10595, LU), (0x10597, 0x105A1, LL),
+  (0x105A3, 0x105B1, LL), (0x105B3, 0x105B9, LL), (0x105BB, 0x105BC, LL), (0x10600, 0x10736, LO),
+  (0x10740, 0x10755, LO), (0x10760, 0x10767, LO), (0x10780, 0x10785, LM), (0x10787, 0x107B0, LM),
+  (0x107B2, 0x107BA, LM), (0x10800, 0x10805, LO), (0x10808, 0x10808, LO), (0x1080A, 0x10835, LO),
+  (0x10837, 0x10838, LO), (0x1083C, 0x1083C, LO), (0x1083F, 0x10855, LO), (0x10857, 0x10857, PO),
+  (0x10858, 0x1085F, NO), (0x10860, 0x10876, LO), (0x10877, 0x10878, SO), (0x10879, 0x1087F, NO),
+  (0x10880, 0x1089E, LO), (0x108A7, 0x108AF, NO), (0x108E0, 0x108F2, LO), (0x108F4, 0x108F5, LO),
+  (0x108FB, 0x108FF, NO), (0x10900, 0x10915, LO), (0x10916, 0x1091B, NO), (0x1091F, 0x1091F, PO),
+  (0x10920, 0x10939, LO), (0x1093F, 0x1093F, PO), (0x10980, 0x109B7, LO), (0x109BC, 0x109BD, NO),
+  (0x109BE, 0x109BF, LO), (0x109C0, 0x109CF, NO), (0x109D2, 0x109FF, NO), (0x10A00, 0x10A00, LO),
+  (0x10A01, 0x10A03, MN), (0x10A05, 0x10A06, MN), (0x10A0C, 0x10A0F, MN), (0x10A10, 0x10A13, LO),
+  (0x10A15, 0x10A17, LO), (0x10A19, 0x10A35, LO), (0x10A38, 0x10A3A, MN), (0x10A3F, 0x10A3F, MN),
+  (0x10A40, 0x10A48, NO), (0x10A50, 0x10A58, PO), (0x10A60, 0x10A7C, LO), (0x10A7D, 0x10A7E, NO),
+  (0x10A7F, 0x10A7F, PO), (0x10A80, 0x10A9C, LO), (0x10A9D, 0x10A9F, NO), (0x10AC0, 0x10AC7, LO),
+  (0x10AC8, 0x10AC8, SO), (0x10AC9, 0x10AE4, LO), (0x10AE5, 0x10AE6, MN), (0x10AEB, 0x10AEF, NO),
+  (0x10AF0, 0x10AF6, PO), (0x10B00, 0x10B35, LO), (0x10B39, 0x10B3F, PO), (0x10B40, 0x10B55, LO),
+  (0x10B58, 0x10B5F, NO), (0x10B60, 0x10B72, LO), (0x10B78, 0x10B7F, NO), (0x10B80, 0x10B91, LO),
+  (0x10B99, 0x10B9C, PO), (0x10BA9, 0x10BAF, NO), (0x10C00, 0x10C48, LO), (0x10C80, 0x10CB2, LU),
+  (0x10CC0, 0x10CF2, LL), (0x10CFA, 0x10CFF, NO), (0x10D00, 0x10D23, LO), (0x10D24, 0x10D27, MN),
+  (0x10D30, 0x10D39, ND), (0x10E60, 0x10E7E, NO), (0x10E80, 0x10EA9, LO), (0x10EAB, 0x10EAC, MN),
+  (0x10EAD, 0x10EAD, PD), (0x10EB0, 0x10EB1, LO), (0x10EFD, 0x10EFF, MN), (0x10F00, 0x10F1C, LO),
+  (0x10F1D, 0x10F26, NO), (0x10F27, 0x10F27, LO), (0x10F30, 0x10F45, LO), (0x10F46, 0x10F50, MN),
+  (0x10F51, 0x10F54, NO), (0x10F55, 0x10F59, PO), (0x10F70, 0x10F81, LO), (0x10F82, 0x10F85, MN),
+  (0x10F86, 0x10F89, PO), (0x10FB0, 0x10FC4, LO), (0x10FC5, 0x10FCB, NO), (0x10FE0, 0x10FF6, LO),
+  (0x11000, 0x11000, MC), (0x11001, 0x11001, MN), (0x11002, 0x11002, MC), (0x11003, 0x11037, LO),
+  (0x11038, 0x11046, MN), (0x11047, 0x1104D, PO), (0x11052, 0x11065, NO), (0x11066, 0x1106F, ND),
+  (0x11070, 0x11070, MN), (0x11071, 0x11072, LO), (0x11073, 0x11074, MN), (0x11075, 0x11075, LO),
+  (0x1107F, 0x11081, MN), (0x11082, 0x11082, MC), (0x11083, 0x110AF, LO), (0x110B0, 0x110B2, MC),
+  (0x110B3, 0x110B6, MN), (0x110B7, 0x110B8, MC), (0x110B9, 0x110BA, MN), (0x110BB, 0x110BC, PO),
+  (0x110BD, 0x110BD, CF), (0x110BE, 0x110C1, PO), (0x110C2, 0x110C2, MN), (0x110CD, 0x110CD, CF),
+  (0x110D0, 0x110E8, LO), (0x110F0, 0x110F9, ND), (0x11100, 0x11102, MN), (0x11103, 0x11126, LO),
+  (0x11127, 0x1112B, MN), (0x1112C, 0x1112C, MC), (0x1112D, 0x11134, MN), (0x11136, 0x1113F, ND),
+  (0x11140, 0x11143, PO), (0x11144, 0x11144, LO), (0x11145, 0x11146, MC), (0x11147, 0x11147, LO),
+  (0x11150, 0x11172, LO), (0x11173, 0x11173, MN), (0x11174, 0x11175, PO), (0x11176, 0x11176, LO),
+  (0x11180, 0x11181, MN), (0x11182, 0x11182, MC), (0x11183, 0x111B2, LO), (0x111B3, 0x111B5, MC),
+  (0x111B6, 0x111BE, MN), (0x111BF, 0x111C0, MC), (0x111C1, 0x111C4, LO), (0x111C5, 0x111C8, PO),
+  (0x111C9, 0x111CC, MN), (0x111CD, 0x111CD, PO), (0x111CE, 0x111CE, MC), (0x111CF, 0x111CF, MN),
+  (0x111D0, 0x111D9, ND), (0x111DA, 0x111DA, LO), (0x111DB, 0x111DB, PO), (0x111DC, 0x111DC, LO),
+  (0x111DD, 0x111DF, PO), (0x111E1, 0x111F4, NO), (0x11200, 0x11211, LO), (0x11213, 0x1122B, LO),
+  (0x1122C, 0x1122E, MC), (0x1122F, 0x11231, MN), (0x11232, 0x11233, MC), (0x11234, 0x11234, MN),
+  (0x11235, 0x11235, MC), (0x11236, 0x11237, MN), (0x11238, 0x1123D, PO), (0x1123E, 0x1123E, MN),
+  (0x1123F, 0x11240, LO), (0x11241, 0x11241, MN), (0x11280, 0x11286, LO), (0x11288, 0x11288, LO),
+  (0x1128A, 0x1128D, LO), (0x1128F, 0x1129D, LO), (0x1129F, 0x112A8, LO), (0x112A9, 0x112A9, PO),
+  (0x112B0, 0x112DE, LO), (0x112DF, 0x112DF, MN), (0x112E0, 0x112E2, MC), (0x112E3, 0x112EA, MN),
+  (0x112F0, 0x112F9, ND), (0x11300, 0x11301, MN), (0x11302, 0x11303, MC), (0x11305, 0x1130C, LO),
+  (0x1130F, 0x11310, LO), (0x11313, 0x11328, LO), (0x1132A, 0x11330, LO), (0x11332, 0x11333, LO),
+  (0x11335, 0x11339, LO), (0x1133B, 0x1133C, MN), (0x1133D, 0x1133D, LO), (0x1133E, 0x1133F, MC),
+  (0x11340, 0x11340, MN), (0x11341, 0x11344, MC), (0x11347, 0x11348, MC), (0x1134B, 0x1134D, MC),
+  (0x11350, 0x11350, LO), (0x11357, 0x11357, MC), (0x1135D, 0x11361, LO), (0x11362, 0x11363, MC),
+  (0x11366, 0x1136C, MN), (0x11370, 0x11374, MN), (0x11400, 0x11434, LO), (0x11435, 0x11437, MC),
+  (0x11438, 0x1143F, MN), (0x11440, 0x11441, MC), (0x11442, 0x11444, MN), (0x11445, 0x11445, MC),
+  (0x11446, 0x11446, MN), (0x11447, 0x1144A, LO), (0x1144B, 0x1144F, PO), (0x11450, 0x11459, ND),
+  (0x1145A, 0x1145B, PO), (0x1145D, 0x1145D, PO), (0x1145E, 0x1145E, MN), (0x1145F, 0x11461, LO),
+  (0x11480, 0x114AF, LO), (0x114B0, 0x114B2, MC), (0x114B3, 0x114B8, MN), (0x114B9, 0x114B9, MC),
+  (0x114BA, 0x114BA, MN), (0x114BB, 0x114BE, MC), (0x114BF, 0x114C0, MN), (0x114C1, 0x114C1, MC),
+  (0x114C2, 0x114C3, MN), (0x114C4, 0x114C5, LO), (0x114C6, 0x114C6, PO), (0x114C7, 0x114C7, LO),
+  (0x114D0, 0x114D9, ND), (0x11580, 0x115AE, LO), (0x115AF, 0x115B1, MC), (0x115B2, 0x115B5, MN),
+  (0x115B8, 0x115BB, MC), (0x115BC, 0x115BD, MN), (0x115BE, 0x115BE, MC), (0x115BF, 0x115C0, MN),
+  (0x115C1, 0x115D7, PO), (0x115D8, 0x115DB, LO), (0x115DC, 0x115DD, MN), (0x11600, 0x1162F, LO),
+  (0x11630, 0x11632, MC), (0x11633, 0x1163A, MN), (0x1163B, 0x1163C, MC), (0x1163D, 0x1163D, MN),
+  (0x1163E, 0x1163E, MC), (0x1163F, 0x11640, MN), (0x11641, 0x11643, PO), (0x11644, 0x11644, LO),
+  (0x11650, 0x11659, ND), (0x11660, 0x1166C, PO), (0x11680, 0x116AA, LO), (0x116AB, 0x116AB, MN),
+  (0x116AC, 0x116AC, MC), (0x116AD, 0x116AD, MN), (0x116AE, 0x116AF, MC), (0x116B0, 0x116B5, MN),
+  (0x116B6, 0x116B6, MC), (0x116B7, 0x116B7, MN), (0x116B8, 0x116B8, LO), (0x116B9, 0x116B9, PO),
+  (0x116C0, 0x116C9, ND), (0x11700, 0x1171A, LO), (0x1171D, 0x1171F, MN), (0x11720, 0x11721, MC),
+  (0x11722, 0x11725, MN), (0x11726, 0x11726, MC), (0x11727, 0x1172B, MN), (0x11730, 0x11739, ND),
+  (0x1173A, 0x1173B, NO), (0x1173C, 0x1173E, PO), (0x1173F, 0x1173F, SO), (0x11740, 0x11746, LO),
+  (0x11800, 0x1182B, LO), (0x1182C, 0x1182E, MC), (0x1182F, 0x11837, MN), (0x11838, 0x11838, MC),
+  (0x11839, 0x1183A, MN), (0x1183B, 0x1183B, PO), (0x118A0, 0x118BF, LU), (0x118C0, 0x118DF, LL),
+  (0x118E0, 0x118E9, ND), (0x118EA, 0x118F2, NO), (0x118FF, 0x11906, LO), (0x11909, 0x11909, LO),
+  (0x1190C, 0x11913, LO), (0x11915, 0x11916, LO), (0x11918, 0x1192F, LO), (0x11930, 0x11935, MC),
+  (0x11937, 0x11938, MC), (0x1193B, 0x1193C, MN), (0x1193D, 0x1193D, MC), (0x1193E, 0x1193E, MN),
+  (0x1193F, 0x1193F, LO), (0x11940, 0x11940, MC), (0x11941, 0x11941, LO), (0x11942, 0x11942, MC),
+  (0x11943, 0x11943, MN), (0x11944, 0x11946, PO), (0x11950, 0x11959, ND), (0x119A0, 0x119A7, LO),
+  (0x119AA, 0x119D0, LO), (0x119D1, 0x119D3, MC), (0x119D4, 0x119D7, MN), (0x119DA, 0x119DB, MN),
+  (0x119DC, 0x119DF, MC), (0x119E0, 0x119E0, MN), (0x119E1, 0x119E1, LO), (0x119E2, 0x119E2, PO),
+  (0x119E3, 0x119E3, LO), (0x119E4, 0x119E4, MC), (0x11A00, 0x11A00, LO), (0x11A01, 0x11A0A, MN),
+  (0x11A0B, 0x11A32, LO), (0x11A33, 0x11A38, MN), (0x11A39, 0x11A39, MC), (0x11A3A, 0x11A3A, LO),
+  (0x11A3B, 0x11A3E, MN), (0x11A3F, 0x11A46, PO), (0x11A47, 0x11A47, MN), (0x11A50, 0x11A50, LO),
+  (0x11A51, 0x11A56, MN), (0x11A57, 0x11A58, MC), (0x11A59, 0x11A5B, MN), (0x11A5C, 0x11A89, LO),
+  (0x11A8A, 0x11A96, MN), (0x11A97, 0x11A97, MC), (0x11A98, 0x11A99, MN), (0x11A9A, 0x11A9C, PO),
+  (0x11A9D, 0x11A9D, LO), (0x11A9E, 0x11AA2, PO), (0x11AB0, 0x11AF8, LO), (0x11B00, 0x11B09, PO),
+  (0x11C00, 0x11C08, LO), (0x11C0A, 0x11C2E, LO), (0x11C2F, 0x11C2F, MC), (0x11C30, 0x11C36, MN),
+  (0x11C38, 0x11C3D, MN), (0x11C3E, 0x11C3E, MC), (0x11C3F, 0x11C3F, MN), (0x11C40, 0x11C40, LO),
+  (0x11C41, 0x11C45, PO), (0x11C50, 0x11C59, ND), (0x11C5A, 0x11C6C, NO), (0x11C70, 0x11C71, PO),
+  (0x11C72, 0x11C8F, LO), (0x11C92, 0x11CA7, MN), (0x11CA9, 0x11CA9, MC), (0x11CAA, 0x11CB0, MN),
+  (0x11CB1, 0x11CB1, MC), (0x11CB2, 0x11CB3, MN), (0x11CB4, 0x11CB4, MC), (0x11CB5, 0x11CB6, MN),
+  (0x11D00, 0x11D06, LO), (0x11D08, 0x11D09, LO), (0x11D0B, 0x11D30, LO), (0x11D31, 0x11D36, MN),
+  (0x11D3A, 0x11D3A, MN), (0x11D3C, 0x11D3D, MN), (0x11D3F, 0x11D45, MN), (0x11D46, 0x11D46, LO),
+  (0x11D47, 0x11D47, MN), (0x11D50, 0x11D59, ND), (0x11D60, 0x11D65, LO), (0x11D67, 0x11D68, LO),
+  (0x11D6A, 0x11D89, LO), (0x11D8A, 0x11D8E, MC), (0x11D90, 0x11D91, MN), (0x11D93, 0x11D94, MC),
+  (0x11D95, 0x11D95, MN), (0x11D96, 0x11D96, MC), (0x11D97, 0x11D97, MN), (0x11D98, 0x11D98, LO),
+  (0x11DA0, 0x11DA9, ND), (0x11EE0, 0x11EF2, LO), (0x11EF3, 0x11EF4, MN), (0x11EF5, 0x11EF6, MC),
+  (0x11EF7, 0x11EF8, PO), (0x11F00, 0x11F01, MN), (0x11F02, 0x11F02, LO), (0x11F03, 0x11F03, MC),
+  (0x11F04, 0x11F10, LO), (0x11F12, 0x11F33, LO), (0x11F34, 0x11F35, MC), (0x11F36, 0x11F3A, MN),
+  (0x11F3E, 0x11F3F, MC), (0x11F40, 0x11F40, MN), (0x11F41, 0x11F41, MC), (0x11F42, 0x11F42, MN),
+  (0x11F43, 0x11F4F, PO), (0x11F50, 0x11F59, ND), (0x11FB0, 0x11FB0, LO), (0x11FC0, 0x11FD4, NO),
+  (0x11FD5, 0x11FDC, SO), (0x11FDD, 0x11FE0, SC), (0x11FE1, 0x11FF1, SO), (0x11FFF, 0x11FFF, PO),
+  (0x12000, 0x12399, LO), (0x12400, 0x1246E, NL), (0x12470, 0x12474, PO), (0x12480, 0x12543, LO),
+  (0x12F90, 0x12FF0, LO), (0x12FF1, 0x12FF2, PO), (0x13000, 0x1342F, LO), (0x13430, 0x1343F, CF),
+  (0x13440, 0x13440, MN), (0x13441, 0x13446, LO), (0x13447, 0x13455, MN), (0x14400, 0x14646, LO),
+  (0x16800, 0x16A38, LO), (0x16A40, 0x16A5E, LO), (0x16A60, 0x16A69, ND), (0x16A6E, 0x16A6F, PO),
+  (0x16A70, 0x16ABE, LO), (0x16AC0, 0x16AC9, ND), (0x16AD0, 0x16AED, LO), (0x16AF0, 0x16AF4, MN),
+  (0x16AF5, 0x16AF5, PO), (0x16B00, 0x16B2F, LO), (0x16B30, 0x16B36, MN), (0x16B37, 0x16B3B, PO),
+  (0x16B3C, 0x16B3F, SO), (0x16B40, 0x16B43, LM), (0x16B44, 0x16B44, PO), (0x16B45, 0x16B45, SO),
+  (0x16B50, 0x16B59, ND), (0x16B5B, 0x16B61, NO), (0x16B63, 0x16B77, LO), (0x16B7D, 0x16B8F, LO),
+  (0x16E40, 0x16E5F, LU), (0x16E60, 0x16E7F, LL), (0x16E80, 0x16E96, NO), (0x16E97, 0x16E9A, PO),
+  (0x16F00, 0x16F4A, LO), (0x16F4F, 0x16F4F, MN), (0x16F50, 0x16F50, LO), (0x16F51, 0x16F87, MC),
+  (0x16F8F, 0x16F92, MN), (0x16F93, 0x16F9F, LM), (0x16FE0, 0x16FE1, LM), (0x16FE2, 0x16FE2, PO),
+  (0x16FE3, 0x16FE3, LM), (0x16FE4, 0x16FE4, MN), (0x16FF0, 0x16FF1, MC), (0x17000, 0x187F7, LO),
+  (0x18800, 0x18CD5, LO), (0x18D00, 0x18D08, LO), (0x1AFF0, 0x1AFF3, LM), (0x1AFF5, 0x1AFFB, LM),
+  (0x1AFFD, 0x1AFFE, LM), (0x1B000, 0x1B122, LO), (0x1B132, 0x1B132, LO), (0x1B150, 0x1B152, LO),
+  (0x1B155, 0x1B155, LO), (0x1B164, 0x1B167, LO), (0x1B170, 0x1B2FB, LO), (0x1BC00, 0x1BC6A, LO),
+  (0x1BC70, 0x1BC7C, LO), (0x1BC80, 0x1BC88, LO), (0x1BC90, 0x1BC99, LO), (0x1BC9C, 0x1BC9C, SO),
+  (0x1BC9D, 0x1BC9E, MN), (0x1BC9F, 0x1BC9F, PO), (0x1BCA0, 0x1BCA3, CF), (0x1CF00, 0x1CF2D, MN),
+  (0x1CF30, 0x1CF46, MN), (0x1CF50, 0x1CFC3, SO), (0x1D000, 0x1D0F5, SO), (0x1D100, 0x1D126, SO),
+  (0x1D129, 0x1D164, SO), (0x1D165, 0x1D166, MC), (0x1D167, 0x1D169, MN), (0x1D16A, 0x1D16C, SO),
+  (0x1D16D, 0x1D172, MC), (0x1D173, 0x1D17A, CF), (0x1D17B, 0x1D182, MN), (0x1D183, 0x1D184, SO),
+  (0x1D185, 0x1D18B, MN), (0x1D18C, 0x1D1A9, SO), (0x1D1AA, 0x1D1AD, MN), (0x1D1AE, 0x1D1EA, SO),
+  (0x1D200, 0x1D241, SO), (0x1D242, 0x1D244, MN), (0x1D245, 0x1D245, SO), (0x1D2C0, 0x1D2D3, NO),
+  (0x1D2E0, 0x1D2F3, NO), (0x1D300, 0x1D356, SO), (0x1D360, 0x1D378, NO), (0x1D400, 0x1D419, LU),
+  (0x1D41A, 0x1D433, LL), (0x1D434, 0x1D44D, LU), (0x1D44E, 0x1D454, LL), (0x1D456, 0x1D467, LL),
+  (0x1D468, 0x1D481, LU), (0x1D482, 0x1D49B, LL), (0x1D49C, 0x1D49C, LU), (0x1D49E, 0x1D49F, LU),
+  (0x1D4A2, 0x1D4A2, LU), (0x1D4A5, 0x1D4A6, LU), (0x1D4A9, 0x1D4AC, LU), (0x1D4AE, 0x1D4B5, LU),
+  (0x1D4B6, 0x1D4B9, LL), (0x1D4BB, 0x1D4BB, LL), (0x1D4BD, 0x1D4C3, LL), (0x1D4C5, 0x1D4CF, LL),
+  (0x1D4D0, 0x1D4E9, LU), (0x1D4EA, 0x1D503, LL), (0x1D504, 0x1D505, LU), (0x1D507, 0x1D50A, LU),
+  (0x1D50D, 0x1D514, LU), (0x1D516, 0x1D51C, LU), (0x1D51E, 0x1D537, LL), (0x1D538, 0x1D539, LU),
+  (0x1D53B, 0x1D53E, LU), (0x1D540, 0x1D544, LU), (0x1D546, 0x1D546, LU), (0x1D54A, 0x1D550, LU),
+  (0x1D552, 0x1D56B, LL), (0x1D56C, 0x1D585, LU), (0x1D586, 0x1D59F, LL), (0x1D5A0, 0x1D5B9, LU),
+  (0x1D5BA, 0x1D5D3, LL), (0x1D5D4, 0x1D5ED, LU), (0x1D5EE, 0x1D607, LL), (0x1D608, 0x1D621, LU),
+  (0x1D622, 0x1D63B, LL), (0x1D63C, 0x1D655, LU), (0x1D656, 0x1D66F, LL), (0x1D670, 0x1D689, LU),
+  (0x1D68A, 0x1D6A5, LL), (0x1D6A8, 0x1D6C0, LU), (0x1D6C1, 0x1D6C1, SM), (0x1D6C2, 0x1D6DA, LL),
+  (0x1D6DB, 0x1D6DB, SM), (0x1D6DC, 0x1D6E1, LL), (0x1D6E2, 0x1D6FA, LU), (0x1D6FB, 0x1D6FB, SM),
+  (0x1D6FC, 0x1D714, LL), (0x1D715, 0x1D715, SM), (0x1D716, 0x1D71B, LL), (0x1D71C, 0x1D734, LU),
+  (0x1D735, 0x1D735, SM), (0x1D736, 0x1D74E, LL), (0x1D74F, 0x1D74F, SM), (0x1D750, 0x1D755, LL),
+  (0x1D756, 0x1D76E, LU), (0x1D76F, 0x1D76F, SM), (0x1D770, 0x1D788, LL), (0x1D789, 0x1D789, SM),
+  (0x1D78A, 0x1D78F, LL), (0x1D790, 0x1D7A8, LU), (0x1D7A9, 0x1D7A9, SM), (0x1D7AA, 0x1D7C2, LL),
+  (0x1D7C3, 0x1D7C3, SM), (0x1D7C4, 0x1D7C9, LL), (0x1D7CA, 0x1D7CA, LU), (0x1D7CB, 0x1D7CB, LL),
+  (0x1D7CE, 0x1D7FF, ND), (0x1D800, 0x1D9FF, SO), (0x1DA00, 0x1DA36, MN), (0x1DA37, 0x1DA3A, SO),
+  (0x1DA3B, 0x1DA6C, MN), (0x1DA6D, 0x1DA74, SO), (0x1DA75, 0x1DA75, MN), (0x1DA76, 0x1DA83, SO),
+  (0x1DA84, 0x1DA84, MN), (0x1DA85, 0x1DA86, SO), (0x1DA87, 0x1DA8B, PO), (0x1DA9B, 0x1DA9F, MN),
+  (0x1DAA1, 0x1DAAF, MN), (0x1DF00, 0x1DF09, LL), (0x1DF0A, 0x1DF0A, LO), (0x1DF0B, 0x1DF1E, LL),
+  (0x1DF25, 0x1DF2A, LL), (0x1E000, 0x1E006, MN), (0x1E008, 0x1E018, MN), (0x1E01B, 0x1E021, MN),
+  (0x1E023, 0x1E024, MN), (0x1E026, 0x1E02A, MN), (0x1E030, 0x1E06D, LM), (0x1E08F, 0x1E08F, MN),
+  (0x1E100, 0x1E12C, LO), (0x1E130, 0x1E136, MN), (0x1E137, 0x1E13D, LM), (0x1E140, 0x1E149, ND),
+  (0x1E14E, 0x1E14E, LO), (0x1E14F, 0x1E14F, SO), (0x1E290, 0x1E2AD, LO), (0x1E2AE, 0x1E2AE, MN),
+  (0x1E2C0, 0x1E2EB, LO), (0x1E2EC, 0x1E2EF, MN), (0x1E2F0, 0x1E2F9, ND), (0x1E2FF, 0x1E2FF, SC),
+  (0x1E4D0, 0x1E4EA, LO), (0x1E4EB, 0x1E4EB, LM), (0x1E4EC, 0x1E4EF, MN), (0x1E4F0, 0x1E4F9, ND),
+  (0x1E7E0, 0x1E7E6, LO), (0x1E7E8, 0x1E7EB, LO), (0x1E7ED, 0x1E7EE, LO), (0x1E7F0, 0x1E7FE, LO),
+  (0x1E800, 0x1E8C4, LO), (0x1E8C7, 0x1E8CF, NO), (0x1E8D0, 0x1E8D6, MN), (0x1E900, 0x1E921, LU),
+  (0x1E922, 0x1E943, LL), (0x1E944, 0x1E94A, MN), (0x1E94B, 0x1E94B, LM), (0x1E950, 0x1E959, ND),
+  (0x1E95E, 0x1E95F, PO), (0x1EC71, 0x1ECAB, NO), (0x1ECAC, 0x1ECAC, SO), (0x1ECAD, 0x1ECAF, NO),
+  (0x1ECB0, 0x1ECB0, SC), (0x1ECB1, 0x1ECB4, NO), (0x1ED01, 0x1ED2D, NO), (0x1ED2E, 0x1ED2E, SO),
+  (0x1ED2F, 0x1ED3D, NO), (0x1EE00, 0x1EE03, LO), (0x1EE05, 0x1EE1F, LO), (0x1EE21, 0x1EE22, LO),
+  (0x1EE24, 0x1EE24, LO), (0x1EE27, 0x1EE27, LO), (0x1EE29, 0x1EE32, LO), (0x1EE34, 0x1EE37, LO),
+  (0x1EE39, 0x1EE39, LO), (0x1EE3B, 0x1EE3B, LO), (0x1EE42, 0x1EE42, LO), (0x1EE47, 0x1EE47, LO),
+  (0x1EE49, 0x1EE49, LO), (0x1EE4B, 0x1EE4B, LO), (0x1EE4D, 0x1EE4F, LO), (0x1EE51, 0x1EE52, LO),
+  (0x1EE54, 0x1EE54, LO), (0x1EE57, 0x1EE57, LO), (0x1EE59, 0x1EE59, LO), (0x1EE5B, 0x1EE5B, LO),
+  (0x1EE5D, 0x1EE5D, LO), (0x1EE5F, 0x1EE5F, LO), (0x1EE61, 0x1EE62, LO), (0x1EE64, 0x1EE64, LO),
+  (0x1EE67, 0x1EE6A, LO), (0x1EE6C, 0x1EE72, LO), (0x1EE74, 0x1EE77, LO), (0x1EE79, 0x1EE7C, LO),
+  (0x1EE7E, 0x1EE7E, LO), (0x1EE80, 0x1EE89, LO), (0x1EE8B, 0x1EE9B, LO), (0x1EEA1, 0x1EEA3, LO),
+  (0x1EEA5, 0x1EEA9, LO), (0x1EEAB, 0x1EEBB, LO), (0x1EEF0, 0x1EEF1, SM), (0x1F000, 0x1F02B, SO),
+  (0x1F030, 0x1F093, SO), (0x1F0A0, 0x1F0AE, SO), (0x1F0B1, 0x1F0BF, SO), (0x1F0C1, 0x1F0CF, SO),
+  (0x1F0D1, 0x1F0F5, SO), (0x1F100, 0x1F10C, NO), (0x1F10D, 0x1F1AD, SO), (0x1F1E6, 0x1F202, SO),
+  (0x1F210, 0x1F23B, SO), (0x1F240, 0x1F248, SO), (0x1F250, 0x1F251, SO), (0x1F260, 0x1F265, SO),
+  (0x1F300, 0x1F3FA, SO), (0x1F3FB, 0x1F3FF, SK), (0x1F400, 0x1F6D7, SO), (0x1F6DC, 0x1F6EC, SO),
+  (0x1F6F0, 0x1F6FC, SO), (0x1F700, 0x1F776, SO), (0x1F77B, 0x1F7D9, SO), (0x1F7E0, 0x1F7EB, SO),
+  (0x1F7F0, 0x1F7F0, SO), (0x1F800, 0x1F80B, SO), (0x1F810, 0x1F847, SO), (0x1F850, 0x1F859, SO),
+  (0x1F860, 0x1F887, SO), (0x1F890, 0x1F8AD, SO), (0x1F8B0, 0x1F8B1, SO), (0x1F900, 0x1FA53, SO),
+  (0x1FA60, 0x1FA6D, SO), (0x1FA70, 0x1FA7C, SO), (0x1FA80, 0x1FA88, SO), (0x1FA90, 0x1FABD, SO),
+  (0x1FABF, 0x1FAC5, SO), (0x1FACE, 0x1FADB, SO), (0x1FAE0, 0x1FAE8, SO), (0x1FAF0, 0x1FAF8, SO),
+  (0x1FB00, 0x1FB92, SO), (0x1FB94, 0x1FBCA, SO), (0x1FBF0, 0x1FBF9, ND), (0x20000, 0x2A6DF, LO),
+  (0x2A700, 0x2B739, LO), (0x2B740, 0x2B81D, LO), (0x2B820, 0x2CEA1, LO), (0x2CEB0, 0x2EBE0, LO),
+  (0x2EBF0, 0x2EE5D, LO), (0x2F800, 0x2FA1D, LO), (0x30000, 0x3134A, LO), (0x31350, 0x323AF, LO),
+  (0xE0001, 0xE0001, CF), (0xE0020, 0xE007F, CF), (0xE0100, 0xE01EF, MN), (0xF0000, 0xFFFFD, CO),
+  (0x100000, 0x10FFFD, CO),
+];
+
+pub(crate) const XID_START: &[(u32, u32)] = &[
+  (0x0041, 0x005A), (0x0061, 0x007A), (0x00AA, 0x00AA), (0x00B5, 0x00B5), (0x00BA, 0x00BA), (0x00C0, 0x00D6),
+  (0x00D8, 0x00F6), (0x00F8, 0x02C1), (0x02C6, 0x02D1), (0x02E0, 0x02E4), (0x02EC, 0x02EC), (0x02EE, 0x02EE),
+  (0x0370, 0x0374), (0x0376, 0x0377), (0x037B, 0x037D), (0x037F, 0x037F), (0x0386, 0x0386), (0x0388, 0x038A),
+  (0x038C, 0x038C), (0x038E, 0x03A1), (0x03A3, 0x03F5), (0x03F7, 0x0481), (0x048A, 0x052F), (0x0531, 0x0556),
+  (0x0559, 0x0559), (0x0560, 0x0588), (0x05D0, 0x05EA), (0x05EF, 0x05F2), (0x0620, 0x064A), (0x066E, 0x066F),
+  (0x0671, 0x06D3), (0x06D5, 0x06D5), (0x06E5, 0x06E6), (0x06EE, 0x06EF), (0x06FA, 0x06FC), (0x06FF, 0x06FF),
+  (0x0710, 0x0710), (0x0712, 0x072F), (0x074D, 0x07A5), (0x07B1, 0x07B1), (0x07CA, 0x07EA), (0x07F4, 0x07F5),
+  (0x07FA, 0x07FA), (0x0800, 0x0815), (0x081A, 0x081A), (0x0824, 0x0824), (0x0828, 0x0828), (0x0840, 0x0858),
+  (0x0860, 0x086A), (0x0870, 0x0887), (0x0889, 0x088E), (0x08A0, 0x08C9), (0x0904, 0x0939), (0x093D, 0x093D),
+  (0x0950, 0x0950), (0x0958, 0x0961), (0x0971, 0x0980), (0x0985, 0x098C), (0x098F, 0x0990), (0x0993, 0x09A8),
+  (0x09AA, 0x09B0), (0x09B2, 0x09B2), (0x09B6, 0x09B9), (0x09BD, 0x09BD), (0x09CE, 0x09CE), (0x09DC, 0x09DD),
+  (0x09DF, 0x09E1), (0x09F0, 0x09F1), (0x09FC, 0x09FC), (0x0A05, 0x0A0A), (0x0A0F, 0x0A10), (0x0A13, 0x0A28),
+  (0x0A2A, 0x0A30), (0x0A32, 0x0A33), (0x0A35, 0x0A36), (0x0A38, 0x0A39), (0x0A59, 0x0A5C), (0x0A5E, 0x0A5E),
+  (0x0A72, 0x0A74), (0x0A85, 0x0A8D), (0x0A8F, 0x0A91), (0x0A93, 0x0AA8), (0x0AAA, 0x0AB0), (0x0AB2, 0x0AB3),
+  (0x0AB5, 0x0AB9), (0x0ABD, 0x0ABD), (0x0AD0, 0x0AD0), (0x0AE0, 0x0AE1), (0x0AF9, 0x0AF9), (0x0B05, 0x0B0C),
+  (0x0B0F, 0x0B10), (0x0B13, 0x0B28), (0x0B2A, 0x0B30), (0x0B32, 0x0B33), (0x0B35, 0x0B39), (0x0B3D, 0x0B3D),
+  (0x0B5C, 0x0B5D), (0x0B5F, 0x0B61), (0x0B71, 0x0B71), (0x0B83, 0x0B83), (0x0B85, 0x0B8A), (0x0B8E, 0x0B90),
+  (0x0B92, 0x0B95), (0x0B99, 0x0B9A), (0x0B9C, 0x0B9C), (0x0B9E, 0x0B9F), (0x0BA3, 0x0BA4), (0x0BA8, 0x0BAA),
+  (0x0BAE, 0x0BB9), (0x0BD0, 0x0BD0), (0x0C05, 0x0C0C), (0x0C0E, 0x0C10), (0x0C12, 0x0C28), (0x0C2A, 0x0C39),
+  (0x0C3D, 0x0C3D), (0x0C58, 0x0C5A), (0x0C5D, 0x0C5D), (0x0C60, 0x0C61), (0x0C80, 0x0C80), (0x0C85, 0x0C8C),
+  (0x0C8E, 0x0C90), (0x0C92, 0x0CA8), (0x0CAA, 0x0CB3), (0x0CB5, 0x0CB9), (0x0CBD, 0x0CBD), (0x0CDD, 0x0CDE),
+  (0x0CE0, 0x0CE1), (0x0CF1, 0x0CF2), (0x0D04, 0x0D0C), (0x0D0E, 0x0D10), (0x0D12, 0x0D3A), (0x0D3D, 0x0D3D),
+  (0x0D4E, 0x0D4E), (0x0D54, 0x0D56), (0x0D5F, 0x0D61), (0x0D7A, 0x0D7F), (0x0D85, 0x0D96), (0x0D9A, 0x0DB1),
+  (0x0DB3, 0x0DBB), (0x0DBD, 0x0DBD), (0x0DC0, 0x0DC6), (0x0E01, 0x0E30), (0x0E32, 0x0E32), (0x0E40, 0x0E46),
+  (0x0E81, 0x0E82), (0x0E84, 0x0E84), (0x0E86, 0x0E8A), (0x0E8C, 0x0EA3), (0x0EA5, 0x0EA5), (0x0EA7, 0x0EB0),
+  (0x0EB2, 0x0EB2), (0x0EBD, 0x0EBD), (0x0EC0, 0x0EC4), (0x0EC6, 0x0EC6), (0x0EDC, 0x0EDF), (0x0F00, 0x0F00),
+  (0x0F40, 0x0F47), (0x0F49, 0x0F6C), (0x0F88, 0x0F8C), (0x1000, 0x102A), (0x103F, 0x103F), (0x1050, 0x1055),
+  (0x105A, 0x105D), (0x1061, 0x1061), (0x1065, 0x1066), (0x106E, 0x1070), (0x1075, 0x1081), (0x108E, 0x108E),
+  (0x10A0, 0x10C5), (0x10C7, 0x10C7), (0x10CD, 0x10CD), (0x10D0, 0x10FA), (0x10FC, 0x1248), (0x124A, 0x124D),
+  (0x1250, 0x1256), (0x1258, 0x1258), (0x125A, 0x125D), (0x1260, 0x1288), (0x128A, 0x128D), (0x1290, 0x12B0),
+  (0x12B2, 0x12B5), (0x12B8, 0x12BE), (0x12C0, 0x12C0), (0x12C2, 0x12C5), (0x12C8, 0x12D6), (0x12D8, 0x1310),
+  (0x1312, 0x1315), (0x1318, 0x135A), (0x1380, 0x138F), (0x13A0, 0x13F5), (0x13F8, 0x13FD), (0x1401, 0x166C),
+  (0x166F, 0x167F), (0x1681, 0x169A), (0x16A0, 0x16EA), (0x16EE, 0x16F8), (0x1700, 0x1711), (0x171F, 0x1731),
+  (0x1740, 0x1751), (0x1760, 0x176C), (0x176E, 0x1770), (0x1780, 0x17B3), (0x17D7, 0x17D7), (0x17DC, 0x17DC),
+  (0x1820, 0x1878), (0x1880, 0x18A8), (0x18AA, 0x18AA), (0x18B0, 0x18F5), (0x1900, 0x191E), (0x1950, 0x196D),
+  (0x1970, 0x1974), (0x1980, 0x19AB), (0x19B0, 0x19C9), (0x1A00, 0x1A16), (0x1A20, 0x1A54), (0x1AA7, 0x1AA7),
+  (0x1B05, 0x1B33), (0x1B45, 0x1B4C), (0x1B83, 0x1BA0), (0x1BAE, 0x1BAF), (0x1BBA, 0x1BE5), (0x1C00, 0x1C23),
+  (0x1C4D, 0x1C4F), (0x1C5A, 0x1C7D), (0x1C80, 0x1C88), (0x1C90, 0x1CBA), (0x1CBD, 0x1CBF), (0x1CE9, 0x1CEC),
+  (0x1CEE, 0x1CF3), (0x1CF5, 0x1CF6), (0x1CFA, 0x1CFA), (0x1D00, 0x1DBF), (0x1E00, 0x1F15), (0x1F18, 0x1F1D),
+  (0x1F20, 0x1F45), (0x1F48, 0x1F4D), (0x1F50, 0x1F57), (0x1F59, 0x1F59), (0x1F5B, 0x1F5B), (0x1F5D, 0x1F5D),
+  (0x1F5F, 0x1F7D), (0x1F80, 0x1FB4), (0x1FB6, 0x1FBC), (0x1FBE, 0x1FBE), (0x1FC2, 0x1FC4), (0x1FC6, 0x1FCC),
+  (0x1FD0, 0x1FD3), (0x1FD6, 0x1FDB), (0x1FE0, 0x1FEC), (0x1FF2, 0x1FF4), (0x1FF6, 0x1FFC), (0x2071, 0x2071),
+  (0x207F, 0x207F), (0x2090, 0x209C), (0x2102, 0x2102), (0x2107, 0x2107), (0x210A, 0x2113), (0x2115, 0x2115),
+  (0x2118, 0x211D), (0x2124, 0x2124), (0x2126, 0x2126), (0x2128, 0x2128), (0x212A, 0x2139), (0x213C, 0x213F),
+  (0x2145, 0x2149), (0x214E, 0x214E), (0x2160, 0x2188), (0x2C00, 0x2CE4), (0x2CEB, 0x2CEE), (0x2CF2, 0x2CF3),
+  (0x2D00, 0x2D25), (0x2D27, 0x2D27), (0x2D2D, 0x2D2D), (0x2D30, 0x2D67), (0x2D6F, 0x2D6F), (0x2D80, 0x2D96),
+  (0x2DA0, 0x2DA6), (0x2DA8, 0x2DAE), (0x2DB0, 0x2DB6), (0x2DB8, 0x2DBE), (0x2DC0, 0x2DC6), (0x2DC8, 0x2DCE),
+  (0x2DD0, 0x2DD6), (0x2DD8, 0x2DDE), (0x3005, 0x3007), (0x3021, 0x3029), (0x3031, 0x3035), (0x3038, 0x303C),
+  (0x3041, 0x3096), (0x309D, 0x309F), (0x30A1, 0x30FA), (0x30FC, 0x30FF), (0x3105, 0x312F), (0x3131, 0x318E),
+  (0x31A0, 0x31BF), (0x31F0, 0x31FF), (0x3400, 0x4DBF), (0x4E00, 0xA48C), (0xA4D0, 0xA4FD), (0xA500, 0xA60C),
+  (0xA610, 0xA61F), (0xA62A, 0xA62B), (0xA640, 0xA66E), (0xA67F, 0xA69D), (0xA6A0, 0xA6EF), (0xA717, 0xA71F),
+  (0xA722, 0xA788), (0xA78B, 0xA7CA), (0xA7D0, 0xA7D1), (0xA7D3, 0xA7D3), (0xA7D5, 0xA7D9), (0xA7F2, 0xA801),
+  (0xA803, 0xA805), (0xA807, 0xA80A), (0xA80C, 0xA822), (0xA840, 0xA873), (0xA882, 0xA8B3), (0xA8F2, 0xA8F7),
+  (0xA8FB, 0xA8FB), (0xA8FD, 0xA8FE), (0xA90A, 0xA925), (0xA930, 0xA946), (0xA960, 0xA97C), (0xA984, 0xA9B2),
+  (0xA9CF, 0xA9CF), (0xA9E0, 0xA9E4), (0xA9E6, 0xA9EF), (0xA9FA, 0xA9FE), (0xAA00, 0xAA28), (0xAA40, 0xAA42),
+  (0xAA44, 0xAA4B), (0xAA60, 0xAA76), (0xAA7A, 0xAA7A), (0xAA7E, 0xAAAF), (0xAAB1, 0xAAB1), (0xAAB5, 0xAAB6),
+  (0xAAB9, 0xAABD), (0xAAC0, 0xAAC0), (0xAAC2, 0xAAC2), (0xAADB, 0xAADD), (0xAAE0, 0xAAEA), (0xAAF2, 0xAAF4),
+  (0xAB01, 0xAB06), (0xAB09, 0xAB0E), (0xAB11, 0xAB16), (0xAB20, 0xAB26), (0xAB28, 0xAB2E), (0xAB30, 0xAB5A),
+  (0xAB5C, 0xAB69), (0xAB70, 0xABE2), (0xAC00, 0xD7A3), (0xD7B0, 0xD7C6), (0xD7CB, 0xD7FB), (0xF900, 0xFA6D),
+  (0xFA70, 0xFAD9), (0xFB00, 0xFB06), (0xFB13, 0xFB17), (0xFB1D, 0xFB1D), (0xFB1F, 0xFB28), (0xFB2A, 0xFB36),
+  (0xFB38, 0xFB3C), (0xFB3E, 0xFB3E), (0xFB40, 0xFB41), (0xFB43, 0xFB44), (0xFB46, 0xFBB1), (0xFBD3, 0xFC5D),
+  (0xFC64, 0xFD3D), (0xFD50, 0xFD8F), (0xFD92, 0xFDC7), (0xFDF0, 0xFDF9), (0xFE71, 0xFE71), (0xFE73, 0xFE73),
+  (0xFE77, 0xFE77), (0xFE79, 0xFE79), (0xFE7B, 0xFE7B), (0xFE7D, 0xFE7D), (0xFE7F, 0xFEFC), (0xFF21, 0xFF3A),
+  (0xFF41, 0xFF5A), (0xFF66, 0xFF9D), (0xFFA0, 0xFFBE), (0xFFC2, 0xFFC7), (0xFFCA, 0xFFCF), (0xFFD2, 0xFFD7),
+  (0xFFDA, 0xFFDC), (0x10000, 0x1000B), (0x1000D, 0x10026), (0x10028, 0x1003A), (0x1003C, 0x1003D), (0x1003F, 0x1004D),
+  (0x10050, 0x1005D), (0x10080, 0x100FA), (0x10140, 0x10174), (0x10280, 0x1029C), (0x102A0, 0x102D0), (0x10300, 0x1031F),
+  (0x1032D, 0x1034A), (0x10350, 0x10375), (0x10380, 0x1039D), (0x103A0, 0x103C3), (0x103C8, 0x103CF), (0x103D1, 0x103D5),
+  (0x10400, 0x1049D), (0x104B0, 0x104D3), (0x104D8, 0x104FB), (0x10500, 0x10527), (0x10530, 0x10563), (0x10570, 0x1057A),
+  (0x1057C, 0x1058A), (0x1058C, 0x10592), (0x10594, 0x10595), (0x10597, 0x105A1), (0x105A3, 0x105B1), (0x105B3, 0x105B9),
+  (0x105BB, 0x105BC), (0x10600, 0x10736), (0x10740, 0x10755), (0x10760, 0x10767), (0x10780, 0x10785), (0x10787, 0x107B0),
+  (0x107B2, 0x107BA), (0x10800, 0x10805), (0x10808, 0x10808), (0x1080A, 0x10835), (0x10837, 0x10838), (0x1083C, 0x1083C),
+  (0x1083F, 0x10855), (0x10860, 0x10876), (0x10880, 0x1089E), (0x108E0, 0x108F2), (0x108F4, 0x108F5), (0x10900, 0x10915),
+  (0x10920, 0x10939), (0x10980, 0x109B7), (0x109BE, 0x109BF), (0x10A00, 0x10A00), (0x10A10, 0x10A13), (0x10A15, 0x10A17),
+  (0x10A19, 0x10A35), (0x10A60, 0x10A7C), (0x10A80, 0x10A9C), (0x10AC0, 0x10AC7), (0x10AC9, 0x10AE4), (0x10B00, 0x10B35),
+  (0x10B40, 0x10B55), (0x10B60, 0x10B72), (0x10B80, 0x10B91), (0x10C00, 0x10C48), (0x10C80, 0x10CB2), (0x10CC0, 0x10CF2),
+  (0x10D00, 0x10D23), (0x10E80, 0x10EA9), (0x10EB0, 0x10EB1), (0x10F00, 0x10F1C), (0x10F27, 0x10F27), (0x10F30, 0x10F45),
+  (0x10F70, 0x10F81), (0x10FB0, 0x10FC4), (0x10FE0, 0x10FF6), (0x11003, 0x11037), (0x11071, 0x11072), (0x11075, 0x11075),
+  (0x11083, 0x110AF), (0x110D0, 0x110E8), (0x11103, 0x11126), (0x11144, 0x11144), (0x11147, 0x11147), (0x11150, 0x11172),
+  (0x11176, 0x11176), (0x11183, 0x111B2), (0x111C1, 0x111C4), (0x111DA, 0x111DA), (0x111DC, 0x111DC), (0x11200, 0x11211),
+  (0x11213, 0x1122B), (0x1123F, 0x11240), (0x11280, 0x11286), (0x11288, 0x11288), (0x1128A, 0x1128D), (0x1128F, 0x1129D),
+  (0x1129F, 0x112A8), (0x112B0, 0x112DE), (0x11305, 0x1130C), (0x1130F, 0x11310), (0x11313, 0x11328), (0x1132A, 0x11330),
+  (0x11332, 0x11333), (0x11335, 0x11339), (0x1133D, 0x1133D), (0x11350, 0x11350), (0x1135D, 0x11361), (0x11400, 0x11434),
+  (0x11447, 0x1144A), (0x1145F, 0x11461), (0x11480, 0x114AF), (0x114C4, 0x114C5), (0x114C7, 0x114C7), (0x11580, 0x115AE),
+  (0x115D8, 0x115DB), (0x11600, 0x1162F), (0x11644, 0x11644), (0x11680, 0x116AA), (0x116B8, 0x116B8), (0x11700, 0x1171A),
+  (0x11740, 0x11746), (0x11800, 0x1182B), (0x118A0, 0x118DF), (0x118FF, 0x11906), (0x11909, 0x11909), (0x1190C, 0x11913),
+  (0x11915, 0x11916), (0x11918, 0x1192F), (0x1193F, 0x1193F), (0x11941, 0x11941), (0x119A0, 0x119A7), (0x119AA, 0x119D0),
+  (0x119E1, 0x119E1), (0x119E3, 0x119E3), (0x11A00, 0x11A00), (0x11A0B, 0x11A32), (0x11A3A, 0x11A3A), (0x11A50, 0x11A50),
+  (0x11A5C, 0x11A89), (0x11A9D, 0x11A9D), (0x11AB0, 0x11AF8), (0x11C00, 0x11C08), (0x11C0A, 0x11C2E), (0x11C40, 0x11C40),
+  (0x11C72, 0x11C8F), (0x11D00, 0x11D06), (0x11D08, 0x11D09), (0x11D0B, 0x11D30), (0x11D46, 0x11D46), (0x11D60, 0x11D65),
+  (0x11D67, 0x11D68), (0x11D6A, 0x11D89), (0x11D98, 0x11D98), (0x11EE0, 0x11EF2), (0x11F02, 0x11F02), (0x11F04, 0x11F10),
+  (0x11F12, 0x11F33), (0x11FB0, 0x11FB0), (0x12000, 0x12399), (0x12400, 0x1246E), (0x12480, 0x12543), (0x12F90, 0x12FF0),
+  (0x13000, 0x1342F), (0x13441, 0x13446), (0x14400, 0x14646), (0x16800, 0x16A38), (0x16A40, 0x16A5E), (0x16A70, 0x16ABE),
+  (0x16AD0, 0x16AED), (0x16B00, 0x16B2F), (0x16B40, 0x16B43), (0x16B63, 0x16B77), (0x16B7D, 0x16B8F), (0x16E40, 0x16E7F),
+  (0x16F00, 0x16F4A), (0x16F50, 0x16F50), (0x16F93, 0x16F9F), (0x16FE0, 0x16FE1), (0x16FE3, 0x16FE3), (0x17000, 0x187F7),
+  (0x18800, 0x18CD5), (0x18D00, 0x18D08), (0x1AFF0, 0x1AFF3), (0x1AFF5, 0x1AFFB), (0x1AFFD, 0x1AFFE), (0x1B000, 0x1B122),
+  (0x1B132, 0x1B132), (0x1B150, 0x1B152), (0x1B155, 0x1B155), (0x1B164, 0x1B167), (0x1B170, 0x1B2FB), (0x1BC00, 0x1BC6A),
+  (0x1BC70, 0x1BC7C), (0x1BC80, 0x1BC88), (0x1BC90, 0x1BC99), (0x1D400, 0x1D454), (0x1D456, 0x1D49C), (0x1D49E, 0x1D49F),
+  (0x1D4A2, 0x1D4A2), (0x1D4A5, 0x1D4A6), (0x1D4A9, 0x1D4AC), (0x1D4AE, 0x1D4B9), (0x1D4BB, 0x1D4BB), (0x1D4BD, 0x1D4C3),
+  (0x1D4C5, 0x1D505), (0x1D507, 0x1D50A), (0x1D50D, 0x1D514), (0x1D516, 0x1D51C), (0x1D51E, 0x1D539), (0x1D53B, 0x1D53E),
+  (0x1D540, 0x1D544), (0x1D546, 0x1D546), (0x1D54A, 0x1D550), (0x1D552, 0x1D6A5), (0x1D6A8, 0x1D6C0), (0x1D6C2, 0x1D6DA),
+  (0x1D6DC, 0x1D6FA), (0x1D6FC, 0x1D714), (0x1D716, 0x1D734), (0x1D736, 0x1D74E), (0x1D750, 0x1D76E), (0x1D770, 0x1D788),
+  (0x1D78A, 0x1D7A8), (0x1D7AA, 0x1D7C2), (0x1D7C4, 0x1D7CB), (0x1DF00, 0x1DF1E), (0x1DF25, 0x1DF2A), (0x1E030, 0x1E06D),
+  (0x1E100, 0x1E12C), (0x1E137, 0x1E13D), (0x1E14E, 0x1E14E), (0x1E290, 0x1E2AD), (0x1E2C0, 0x1E2EB), (0x1E4D0, 0x1E4EB),
+  (0x1E7E0, 0x1E7E6), (0x1E7E8, 0x1E7EB), (0x1E7ED, 0x1E7EE), (0x1E7F0, 0x1E7FE), (0x1E800, 0x1E8C4), (0x1E900, 0x1E943),
+  (0x1E94B, 0x1E94B), (0x1EE00, 0x1EE03), (0x1EE05, 0x1EE1F), (0x1EE21, 0x1EE22), (0x1EE24, 0x1EE24), (0x1EE27, 0x1EE27),
+  (0x1EE29, 0x1EE32), (0x1EE34, 0x1EE37), (0x1EE39, 0x1EE39), (0x1EE3B, 0x1EE3B), (0x1EE42, 0x1EE42), (0x1EE47, 0x1EE47),
+  (0x1EE49, 0x1EE49), (0x1EE4B, 0x1EE4B), (0x1EE4D, 0x1EE4F), (0x1EE51, 0x1EE52), (0x1EE54, 0x1EE54), (0x1EE57, 0x1EE57),
+  (0x1EE59, 0x1EE59), (0x1EE5B, 0x1EE5B), (0x1EE5D, 0x1EE5D), (0x1EE5F, 0x1EE5F), (0x1EE61, 0x1EE62), (0x1EE64, 0x1EE64),
+  (0x1EE67, 0x1EE6A), (0x1EE6C, 0x1EE72), (0x1EE74, 0x1EE77), (0x1EE79, 0x1EE7C), (0x1EE7E, 0x1EE7E), (0x1EE80, 0x1EE89),
+  (0x1EE8B, 0x1EE9B), (0x1EEA1, 0x1EEA3), (0x1EEA5, 0x1EEA9), (0x1EEAB, 0x1EEBB), (0x20000, 0x2A6DF), (0x2A700, 0x2B739),
+  (0x2B740, 0x2B81D), (0x2B820, 0x2CEA1), (0x2CEB0, 0x2EBE0), (0x2EBF0, 0x2EE5D), (0x2F800, 0x2FA1D), (0x30000, 0x3134A),
+  (0x31350, 0x323AF),
+];
+
+pub(crate) const XID_CONTINUE: &[(u32, u32)] = &[
+  (0x0030, 0x0039), (0x0041, 0x005A), (0x005F, 0x005F), (0x0061, 0x007A), (0x00AA, 0x00AA), (0x00B5, 0x00B5),
+  (0x00B7, 0x00B7), (0x00BA, 0x00BA), (0x00C0, 0x00D6), (0x00D8, 0x00F6), (0x00F8, 0x02C1), (0x02C6, 0x02D1),
+  (0x02E0, 0x02E4), (0x02EC, 0x02EC), (0x02EE, 0x02EE), (0x0300, 0x0374), (0x0376, 0x0377), (0x037B, 0x037D),
+  (0x037F, 0x037F), (0x0386, 0x038A), (0x038C, 0x038C), (0x038E, 0x03A1), (0x03A3, 0x03F5), (0x03F7, 0x0481),
+  (0x0483, 0x0487), (0x048A, 0x052F), (0x0531, 0x0556), (0x0559, 0x0559), (0x0560, 0x0588), (0x0591, 0x05BD),
+  (0x05BF, 0x05BF), (0x05C1, 0x05C2), (0x05C4, 0x05C5), (0x05C7, 0x05C7), (0x05D0, 0x05EA), (0x05EF, 0x05F2),
+  (0x0610, 0x061A), (0x0620, 0x0669), (0x066E, 0x06D3), (0x06D5, 0x06DC), (0x06DF, 0x06E8), (0x06EA, 0x06FC),
+  (0x06FF, 0x06FF), (0x0710, 0x074A), (0x074D, 0x07B1), (0x07C0, 0x07F5), (0x07FA, 0x07FA), (0x07FD, 0x07FD),
+  (0x0800, 0x082D), (0x0840, 0x085B), (0x0860, 0x086A), (0x0870, 0x0887), (0x0889, 0x088E), (0x0898, 0x08E1),
+  (0x08E3, 0x0963), (0x0966, 0x096F), (0x0971, 0x0983), (0x0985, 0x098C), (0x098F, 0x0990), (0x0993, 0x09A8),
+  (0x09AA, 0x09B0), (0x09B2, 0x09B2), (0x09B6, 0x09B9), (0x09BC, 0x09C4), (0x09C7, 0x09C8), (0x09CB, 0x09CE),
+  (0x09D7, 0x09D7), (0x09DC, 0x09DD), (0x09DF, 0x09E3), (0x09E6, 0x09F1), (0x09FC, 0x09FC), (0x09FE, 0x09FE),
+  (0x0A01, 0x0A03), (0x0A05, 0x0A0A), (0x0A0F, 0x0A10), (0x0A13, 0x0A28), (0x0A2A, 0x0A30), (0x0A32, 0x0A33),
+  (0x0A35, 0x0A36), (0x0A38, 0x0A39), (0x0A3C, 0x0A3C), (0x0A3E, 0x0A42), (0x0A47, 0x0A48), (0x0A4B, 0x0A4D),
+  (0x0A51, 0x0A51), (0x0A59, 0x0A5C), (0x0A5E, 0x0A5E), (0x0A66, 0x0A75), (0x0A81, 0x0A83), (0x0A85, 0x0A8D),
+  (0x0A8F, 0x0A91), (0x0A93, 0x0AA8), (0x0AAA, 0x0AB0), (0x0AB2, 0x0AB3), (0x0AB5, 0x0AB9), (0x0ABC, 0x0AC5),
+  (0x0AC7, 0x0AC9), (0x0ACB, 0x0ACD), (0x0AD0, 0x0AD0), (0x0AE0, 0x0AE3), (0x0AE6, 0x0AEF), (0x0AF9, 0x0AFF),
+  (0x0B01, 0x0B03), (0x0B05, 0x0B0C), (0x0B0F, 0x0B10), (0x0B13, 0x0B28), (0x0B2A, 0x0B30), (0x0B32, 0x0B33),
+  (0x0B35, 0x0B39), (0x0B3C, 0x0B44), (0x0B47, 0x0B48), (0x0B4B, 0x0B4D), (0x0B55, 0x0B57), (0x0B5C, 0x0B5D),
+  (0x0B5F, 0x0B63), (0x0B66, 0x0B6F), (0x0B71, 0x0B71), (0x0B82, 0x0B83), (0x0B85, 0x0B8A), (0x0B8E, 0x0B90),
+  (0x0B92, 0x0B95), (0x0B99, 0x0B9A), (0x0B9C, 0x0B9C), (0x0B9E, 0x0B9F), (0x0BA3, 0x0BA4), (0x0BA8, 0x0BAA),
+  (0x0BAE, 0x0BB9), (0x0BBE, 0x0BC2), (0x0BC6, 0x0BC8), (0x0BCA, 0x0BCD), (0x0BD0, 0x0BD0), (0x0BD7, 0x0BD7),
+  (0x0BE6, 0x0BEF), (0x0C00, 0x0C0C), (0x0C0E, 0x0C10), (0x0C12, 0x0C28), (0x0C2A, 0x0C39), (0x0C3C, 0x0C44),
+  (0x0C46, 0x0C48), (0x0C4A, 0x0C4D), (0x0C55, 0x0C56), (0x0C58, 0x0C5A), (0x0C5D, 0x0C5D), (0x0C60, 0x0C63),
+  (0x0C66, 0x0C6F), (0x0C80, 0x0C83), (0x0C85, 0x0C8C), (0x0C8E, 0x0C90), (0x0C92, 0x0CA8), (0x0CAA, 0x0CB3),
+  (0x0CB5, 0x0CB9), (0x0CBC, 0x0CC4), (0x0CC6, 0x0CC8), (0x0CCA, 0x0CCD), (0x0CD5, 0x0CD6), (0x0CDD, 0x0CDE),
+  (0x0CE0, 0x0CE3), (0x0CE6, 0x0CEF), (0x0CF1, 0x0CF3), (0x0D00, 0x0D0C), (0x0D0E, 0x0D10), (0x0D12, 0x0D44),
+  (0x0D46, 0x0D48), (0x0D4A, 0x0D4E), (0x0D54, 0x0D57), (0x0D5F, 0x0D63), (0x0D66, 0x0D6F), (0x0D7A, 0x0D7F),
+  (0x0D81, 0x0D83), (0x0D85, 0x0D96), (0x0D9A, 0x0DB1), (0x0DB3, 0x0DBB), (0x0DBD, 0x0DBD), (0x0DC0, 0x0DC6),
+  (0x0DCA, 0x0DCA), (0x0DCF, 0x0DD4), (0x0DD6, 0x0DD6), (0x0DD8, 0x0DDF), (0x0DE6, 0x0DEF), (0x0DF2, 0x0DF3),
+  (0x0E01, 0x0E3A), (0x0E40, 0x0E4E), (0x0E50, 0x0E59), (0x0E81, 0x0E82), (0x0E84, 0x0E84), (0x0E86, 0x0E8A),
+  (0x0E8C, 0x0EA3), (0x0EA5, 0x0EA5), (0x0EA7, 0x0EBD), (0x0EC0, 0x0EC4), (0x0EC6, 0x0EC6), (0x0EC8, 0x0ECE),
+  (0x0ED0, 0x0ED9), (0x0EDC, 0x0EDF), (0x0F00, 0x0F00), (0x0F18, 0x0F19), (0x0F20, 0x0F29), (0x0F35, 0x0F35),
+  (0x0F37, 0x0F37), (0x0F39, 0x0F39), (0x0F3E, 0x0F47), (0x0F49, 0x0F6C), (0x0F71, 0x0F84), (0x0F86, 0x0F97),
+  (0x0F99, 0x0FBC), (0x0FC6, 0x0FC6), (0x1000, 0x1049), (0x1050, 0x109D), (0x10A0, 0x10C5), (0x10C7, 0x10C7),
+  (0x10CD, 0x10CD), (0x10D0, 0x10FA), (0x10FC, 0x1248), (0x124A, 0x124D), (0x1250, 0x1256), (0x1258, 0x1258),
+  (0x125A, 0x125D), (0x1260, 0x1288), (0x128A, 0x128D), (0x1290, 0x12B0), (0x12B2, 0x12B5), (0x12B8, 0x12BE),
+  (0x12C0, 0x12C0), (0x12C2, 0x12C5), (0x12C8, 0x12D6), (0x12D8, 0x1310), (0x1312, 0x1315), (0x1318, 0x135A),
+  (0x135D, 0x135F), (0x1369, 0x1371), (0x1380, 0x138F), (0x13A0, 0x13F5), (0x13F8, 0x13FD), (0x1401, 0x166C),
+  (0x166F, 0x167F), (0x1681, 0x169A), (0x16A0, 0x16EA), (0x16EE, 0x16F8), (0x1700, 0x1715), (0x171F, 0x1734),
+  (0x1740, 0x1753), (0x1760, 0x176C), (0x176E, 0x1770), (0x1772, 0x1773), (0x1780, 0x17D3), (0x17D7, 0x17D7),
+  (0x17DC, 0x17DD), (0x17E0, 0x17E9), (0x180B, 0x180D), (0x180F, 0x1819), (0x1820, 0x1878), (0x1880, 0x18AA),
+  (0x18B0, 0x18F5), (0x1900, 0x191E), (0x1920, 0x192B), (0x1930, 0x193B), (0x1946, 0x196D), (0x1970, 0x1974),
+  (0x1980, 0x19AB), (0x19B0, 0x19C9), (0x19D0, 0x19DA), (0x1A00, 0x1A1B), (0x1A20, 0x1A5E), (0x1A60, 0x1A7C),
+  (0x1A7F, 0x1A89), (0x1A90, 0x1A99), (0x1AA7, 0x1AA7), (0x1AB0, 0x1ABD), (0x1ABF, 0x1ACE), (0x1B00, 0x1B4C),
+  (0x1B50, 0x1B59), (0x1B6B, 0x1B73), (0x1B80, 0x1BF3), (0x1C00, 0x1C37), (0x1C40, 0x1C49), (0x1C4D, 0x1C7D),
+  (0x1C80, 0x1C88), (0x1C90, 0x1CBA), (0x1CBD, 0x1CBF), (0x1CD0, 0x1CD2), (0x1CD4, 0x1CFA), (0x1D00, 0x1F15),
+  (0x1F18, 0x1F1D), (0x1F20, 0x1F45), (0x1F48, 0x1F4D), (0x1F50, 0x1F57), (0x1F59, 0x1F59), (0x1F5B, 0x1F5B),
+  (0x1F5D, 0x1F5D), (0x1F5F, 0x1F7D), (0x1F80, 0x1FB4), (0x1FB6, 0x1FBC), (0x1FBE, 0x1FBE), (0x1FC2, 0x1FC4),
+  (0x1FC6, 0x1FCC), (0x1FD0, 0x1FD3), (0x1FD6, 0x1FDB), (0x1FE0, 0x1FEC), (0x1FF2, 0x1FF4), (0x1FF6, 0x1FFC),
+  (0x200C, 0x200D), (0x203F, 0x2040), (0x2054, 0x2054), (0x2071, 0x2071), (0x207F, 0x207F), (0x2090, 0x209C),
+  (0x20D0, 0x20DC), (0x20E1, 0x20E1), (0x20E5, 0x20F0), (0x2102, 0x2102), (0x2107, 0x2107), (0x210A, 0x2113),
+  (0x2115, 0x2115), (0x2118, 0x211D), (0x2124, 0x2124), (0x2126, 0x2126), (0x2128, 0x2128), (0x212A, 0x2139),
+  (0x213C, 0x213F), (0x2145, 0x2149), (0x214E, 0x214E), (0x2160, 0x2188), (0x2C00, 0x2CE4), (0x2CEB, 0x2CF3),
+  (0x2D00, 0x2D25), (0x2D27, 0x2D27), (0x2D2D, 0x2D2D), (0x2D30, 0x2D67), (0x2D6F, 0x2D6F), (0x2D7F, 0x2D96),
+  (0x2DA0, 0x2DA6), (0x2DA8, 0x2DAE), (0x2DB0, 0x2DB6), (0x2DB8, 0x2DBE), (0x2DC0, 0x2DC6), (0x2DC8, 0x2DCE),
+  (0x2DD0, 0x2DD6), (0x2DD8, 0x2DDE), (0x2DE0, 0x2DFF), (0x3005, 0x3007), (0x3021, 0x302F), (0x3031, 0x3035),
+  (0x3038, 0x303C), (0x3041, 0x3096), (0x3099, 0x309A), (0x309D, 0x309F), (0x30A1, 0x30FF), (0x3105, 0x312F),
+  (0x3131, 0x318E), (0x31A0, 0x31BF), (0x31F0, 0x31FF), (0x3400, 0x4DBF), (0x4E00, 0xA48C), (0xA4D0, 0xA4FD),
+  (0xA500, 0xA60C), (0xA610, 0xA62B), (0xA640, 0xA66F), (0xA674, 0xA67D), (0xA67F, 0xA6F1), (0xA717, 0xA71F),
+  (0xA722, 0xA788), (0xA78B, 0xA7CA), (0xA7D0, 0xA7D1), (0xA7D3, 0xA7D3), (0xA7D5, 0xA7D9), (0xA7F2, 0xA827),
+  (0xA82C, 0xA82C), (0xA840, 0xA873), (0xA880, 0xA8C5), (0xA8D0, 0xA8D9), (0xA8E0, 0xA8F7), (0xA8FB, 0xA8FB),
+  (0xA8FD, 0xA92D), (0xA930, 0xA953), (0xA960, 0xA97C), (0xA980, 0xA9C0), (0xA9CF, 0xA9D9), (0xA9E0, 0xA9FE),
+  (0xAA00, 0xAA36), (0xAA40, 0xAA4D), (0xAA50, 0xAA59), (0xAA60, 0xAA76), (0xAA7A, 0xAAC2), (0xAADB, 0xAADD),
+  (0xAAE0, 0xAAEF), (0xAAF2, 0xAAF6), (0xAB01, 0xAB06), (0xAB09, 0xAB0E), (0xAB11, 0xAB16), (0xAB20, 0xAB26),
+  (0xAB28, 0xAB2E), (0xAB30, 0xAB5A), (0xAB5C, 0xAB69), (0xAB70, 0xABEA), (0xABEC, 0xABED), (0xABF0, 0xABF9),
+  (0xAC00, 0xD7A3), (0xD7B0, 0xD7C6), (0xD7CB, 0xD7FB), (0xF900, 0xFA6D), (0xFA70, 0xFAD9), (0xFB00, 0xFB06),
+  (0xFB13, 0xFB17), (0xFB1D, 0xFB28), (0xFB2A, 0xFB36), (0xFB38, 0xFB3C), (0xFB3E, 0xFB3E), (0xFB40, 0xFB41),
+  (0xFB43, 0xFB44), (0xFB46, 0xFBB1), (0xFBD3, 0xFC5D), (0xFC64, 0xFD3D), (0xFD50, 0xFD8F), (0xFD92, 0xFDC7),
+  (0xFDF0, 0xFDF9), (0xFE00, 0xFE0F), (0xFE20, 0xFE2F), (0xFE33, 0xFE34), (0xFE4D, 0xFE4F), (0xFE71, 0xFE71),
+  (0xFE73, 0xFE73), (0xFE77, 0xFE77), (0xFE79, 0xFE79), (0xFE7B, 0xFE7B), (0xFE7D, 0xFE7D), (0xFE7F, 0xFEFC),
+  (0xFF10, 0xFF19), (0xFF21, 0xFF3A), (0xFF3F, 0xFF3F), (0xFF41, 0xFF5A), (0xFF65, 0xFFBE), (0xFFC2, 0xFFC7),
+  (0xFFCA, 0xFFCF), (0xFFD2, 0xFFD7), (0xFFDA, 0xFFDC), (0x10000, 0x1000B), (0x1000D, 0x10026), (0x10028, 0x1003A),
+  (0x1003C, 0x1003D), (0x1003F, 0x1004D), (0x10050, 0x1005D), (0x10080, 0x100FA), (0x10140, 0x10174), (0x101FD, 0x101FD),
+  (0x10280, 0x1029C), (0x102A0, 0x102D0), (0x102E0, 0x102E0), (0x10300, 0x1031F), (0x1032D, 0x1034A), (0x10350, 0x1037A),
+  (0x10380, 0x1039D), (0x103A0, 0x103C3), (0x103C8, 0x103CF), (0x103D1, 0x103D5), (0x10400, 0x1049D), (0x104A0, 0x104A9),
+  (0x104B0, 0x104D3), (0x104D8, 0x104FB), (0x10500, 0x10527), (0x10530, 0x10563), (0x10570, 0x1057A), (0x1057C, 0x1058A),
+  (0x1058C, 0x10592), (0x10594, 0x10595), (0x10597, 0x105A1), (0x105A3, 0x105B1), (0x105B3, 0x105B9), (0x105BB, 0x105BC),
+  (0x10600, 0x10736), (0x10740, 0x10755), (0x10760, 0x10767), (0x10780, 0x10785), (0x10787, 0x107B0), (0x107B2, 0x107BA),
+  (0x10800, 0x10805), (0x10808, 0x10808), (0x1080A, 0x10835), (0x10837, 0x10838), (0x1083C, 0x1083C), (0x1083F, 0x10855),
+  (0x10860, 0x10876), (0x10880, 0x1089E), (0x108E0, 0x108F2), (0x108F4, 0x108F5), (0x10900, 0x10915), (0x10920, 0x10939),
+  (0x10980, 0x109B7), (0x109BE, 0x109BF), (0x10A00, 0x10A03), (0x10A05, 0x10A06), (0x10A0C, 0x10A13), (0x10A15, 0x10A17),
+  (0x10A19, 0x10A35), (0x10A38, 0x10A3A), (0x10A3F, 0x10A3F), (0x10A60, 0x10A7C), (0x10A80, 0x10A9C), (0x10AC0, 0x10AC7),
+  (0x10AC9, 0x10AE6), (0x10B00, 0x10B35), (0x10B40, 0x10B55), (0x10B60, 0x10B72), (0x10B80, 0x10B91), (0x10C00, 0x10C48),
+  (0x10C80, 0x10CB2), (0x10CC0, 0x10CF2), (0x10D00, 0x10D27), (0x10D30, 0x10D39), (0x10E80, 0x10EA9), (0x10EAB, 0x10EAC),
+  (0x10EB0, 0x10EB1), (0x10EFD, 0x10F1C), (0x10F27, 0x10F27), (0x10F30, 0x10F50), (0x10F70, 0x10F85), (0x10FB0, 0x10FC4),
+  (0x10FE0, 0x10FF6), (0x11000, 0x11046), (0x11066, 0x11075), (0x1107F, 0x110BA), (0x110C2, 0x110C2), (0x110D0, 0x110E8),
+  (0x110F0, 0x110F9), (0x11100, 0x11134), (0x11136, 0x1113F), (0x11144, 0x11147), (0x11150, 0x11173), (0x11176, 0x11176),
+  (0x11180, 0x111C4), (0x111C9, 0x111CC), (0x111CE, 0x111DA), (0x111DC, 0x111DC), (0x11200, 0x11211), (0x11213, 0x11237),
+  (0x1123E, 0x11241), (0x11280, 0x11286), (0x11288, 0x11288), (0x1128A, 0x1128D), (0x1128F, 0x1129D), (0x1129F, 0x112A8),
+  (0x112B0, 0x112EA), (0x112F0, 0x112F9), (0x11300, 0x11303), (0x11305, 0x1130C), (0x1130F, 0x11310), (0x11313, 0x11328),
+  (0x1132A, 0x11330), (0x11332, 0x11333), (0x11335, 0x11339), (0x1133B, 0x11344), (0x11347, 0x11348), (0x1134B, 0x1134D),
+  (0x11350, 0x11350), (0x11357, 0x11357), (0x1135D, 0x11363), (0x11366, 0x1136C), (0x11370, 0x11374), (0x11400, 0x1144A),
+  (0x11450, 0x11459), (0x1145E, 0x11461), (0x11480, 0x114C5), (0x114C7, 0x114C7), (0x114D0, 0x114D9), (0x11580, 0x115B5),
+  (0x115B8, 0x115C0), (0x115D8, 0x115DD), (0x11600, 0x11640), (0x11644, 0x11644), (0x11650, 0x11659), (0x11680, 0x116B8),
+  (0x116C0, 0x116C9), (0x11700, 0x1171A), (0x1171D, 0x1172B), (0x11730, 0x11739), (0x11740, 0x11746), (0x11800, 0x1183A),
+  (0x118A0, 0x118E9), (0x118FF, 0x11906), (0x11909, 0x11909), (0x1190C, 0x11913), (0x11915, 0x11916), (0x11918, 0x11935),
+  (0x11937, 0x11938), (0x1193B, 0x11943), (0x11950, 0x11959), (0x119A0, 0x119A7), (0x119AA, 0x119D7), (0x119DA, 0x119E1),
+  (0x119E3, 0x119E4), (0x11A00, 0x11A3E), (0x11A47, 0x11A47), (0x11A50, 0x11A99), (0x11A9D, 0x11A9D), (0x11AB0, 0x11AF8),
+  (0x11C00, 0x11C08), (0x11C0A, 0x11C36), (0x11C38, 0x11C40), (0x11C50, 0x11C59), (0x11C72, 0x11C8F), (0x11C92, 0x11CA7),
+  (0x11CA9, 0x11CB6), (0x11D00, 0x11D06), (0x11D08, 0x11D09), (0x11D0B, 0x11D36), (0x11D3A, 0x11D3A), (0x11D3C, 0x11D3D),
+  (0x11D3F, 0x11D47), (0x11D50, 0x11D59), (0x11D60, 0x11D65), (0x11D67, 0x11D68), (0x11D6A, 0x11D8E), (0x11D90, 0x11D91),
+  (0x11D93, 0x11D98), (0x11DA0, 0x11DA9), (0x11EE0, 0x11EF6), (0x11F00, 0x11F10), (0x11F12, 0x11F3A), (0x11F3E, 0x11F42),
+  (0x11F50, 0x11F59), (0x11FB0, 0x11FB0), (0x12000, 0x12399), (0x12400, 0x1246E), (0x12480, 0x12543), (0x12F90, 0x12FF0),
+  (0x13000, 0x1342F), (0x13440, 0x13455), (0x14400, 0x14646), (0x16800, 0x16A38), (0x16A40, 0x16A5E), (0x16A60, 0x16A69),
+  (0x16A70, 0x16ABE), (0x16AC0, 0x16AC9), (0x16AD0, 0x16AED), (0x16AF0, 0x16AF4), (0x16B00, 0x16B36), (0x16B40, 0x16B43),
+  (0x16B50, 0x16B59), (0x16B63, 0x16B77), (0x16B7D, 0x16B8F), (0x16E40, 0x16E7F), (0x16F00, 0x16F4A), (0x16F4F, 0x16F87),
+  (0x16F8F, 0x16F9F), (0x16FE0, 0x16FE1), (0x16FE3, 0x16FE4), (0x16FF0, 0x16FF1), (0x17000, 0x187F7), (0x18800, 0x18CD5),
+  (0x18D00, 0x18D08), (0x1AFF0, 0x1AFF3), (0x1AFF5, 0x1AFFB), (0x1AFFD, 0x1AFFE), (0x1B000, 0x1B122), (0x1B132, 0x1B132),
+  (0x1B150, 0x1B152), (0x1B155, 0x1B155), (0x1B164, 0x1B167), (0x1B170, 0x1B2FB), (0x1BC00, 0x1BC6A), (0x1BC70, 0x1BC7C),
+  (0x1BC80, 0x1BC88), (0x1BC90, 0x1BC99), (0x1BC9D, 0x1BC9E), (0x1CF00, 0x1CF2D), (0x1CF30, 0x1CF46), (0x1D165, 0x1D169),
+  (0x1D16D, 0x1D172), (0x1D17B, 0x1D182), (0x1D185, 0x1D18B), (0x1D1AA, 0x1D1AD), (0x1D242, 0x1D244), (0x1D400, 0x1D454),
+  (0x1D456, 0x1D49C), (0x1D49E, 0x1D49F), (0x1D4A2, 0x1D4A2), (0x1D4A5, 0x1D4A6), (0x1D4A9, 0x1D4AC), (0x1D4AE, 0x1D4B9),
+  (0x1D4BB, 0x1D4BB), (0x1D4BD, 0x1D4C3), (0x1D4C5, 0x1D505), (0x1D507, 0x1D50A), (0x1D50D, 0x1D514), (0x1D516, 0x1D51C),
+  (0x1D51E, 0x1D539), (0x1D53B, 0x1D53E), (0x1D540, 0x1D544), (0x1D546, 0x1D546), (0x1D54A, 0x1D550), (0x1D552, 0x1D6A5),
+  (0x1D6A8, 0x1D6C0), (0x1D6C2, 0x1D6DA), (0x1D6DC, 0x1D6FA), (0x1D6FC, 0x1D714), (0x1D716, 0x1D734), (0x1D736, 0x1D74E),
+  (0x1D750, 0x1D76E), (0x1D770, 0x1D788), (0x1D78A, 0x1D7A8), (0x1D7AA, 0x1D7C2), (0x1D7C4, 0x1D7CB), (0x1D7CE, 0x1D7FF),
+  (0x1DA00, 0x1DA36), (0x1DA3B, 0x1DA6C), (0x1DA75, 0x1DA75), (0x1DA84, 0x1DA84), (0x1DA9B, 0x1DA9F), (0x1DAA1, 0x1DAAF),
+  (0x1DF00, 0x1DF1E), (0x1DF25, 0x1DF2A), (0x1E000, 0x1E006), (0x1E008, 0x1E018), (0x1E01B, 0x1E021), (0x1E023, 0x1E024),
+  (0x1E026, 0x1E02A), (0x1E030, 0x1E06D), (0x1E08F, 0x1E08F), (0x1E100, 0x1E12C), (0x1E130, 0x1E13D), (0x1E140, 0x1E149),
+  (0x1E14E, 0x1E14E), (0x1E290, 0x1E2AE), (0x1E2C0, 0x1E2F9), (0x1E4D0, 0x1E4F9), (0x1E7E0, 0x1E7E6), (0x1E7E8, 0x1E7EB),
+  (0x1E7ED, 0x1E7EE), (0x1E7F0, 0x1E7FE), (0x1E800, 0x1E8C4), (0x1E8D0, 0x1E8D6), (0x1E900, 0x1E94B), (0x1E950, 0x1E959),
+  (0x1EE00, 0x1EE03), (0x1EE05, 0x1EE1F), (0x1EE21, 0x1EE22), (0x1EE24, 0x1EE24), (0x1EE27, 0x1EE27), (0x1EE29, 0x1EE32),
+  (0x1EE34, 0x1EE37), (0x1EE39, 0x1EE39), (0x1EE3B, 0x1EE3B), (0x1EE42, 0x1EE42), (0x1EE47, 0x1EE47), (0x1EE49, 0x1EE49),
+  (0x1EE4B, 0x1EE4B), (0x1EE4D, 0x1EE4F), (0x1EE51, 0x1EE52), (0x1EE54, 0x1EE54), (0x1EE57, 0x1EE57), (0x1EE59, 0x1EE59),
+  (0x1EE5B, 0x1EE5B), (0x1EE5D, 0x1EE5D), (0x1EE5F, 0x1EE5F), (0x1EE61, 0x1EE62), (0x1EE64, 0x1EE64), (0x1EE67, 0x1EE6A),
+  (0x1EE6C, 0x1EE72), (0x1EE74, 0x1EE77), (0x1EE79, 0x1EE7C), (0x1EE7E, 0x1EE7E), (0x1EE80, 0x1EE89), (0x1EE8B, 0x1EE9B),
+  (0x1EEA1, 0x1EEA3), (0x1EEA5, 0x1EEA9), (0x1EEAB, 0x1EEBB), (0x1FBF0, 0x1FBF9), (0x20000, 0x2A6DF), (0x2A700, 0x2B739),
+  (0x2B740, 0x2B81D), (0x2B820, 0x2CEA1), (0x2CEB0, 0x2EBE0), (0x2EBF0, 0x2EE5D), (0x2F800, 0x2FA1D), (0x30000, 0x3134A),
+  (0x31350, 0x323AF), (0xE0100, 0xE01EF),
+];
@@ -0,0 +1,86 @@
+#!/usr/bin/env python3
+"""Generates parser/src/utils/unicode_tables.rs from the Unicode database of the running Python.
+
+Usage: python3 scripts/gen-unicode-tables.py > parser/src/utils/unicode_tables.rs
+"""
+import unicodedata
+
+CATEGORIES = {
+    "Lu": "LU", "Ll": "LL", "Lt": "LT", "Lm": "LM", "Lo": "LO",
+    "Mn": "MN", "Mc": "MC", "Me": "ME",
+    "Nd": "ND", "Nl": "NL", "No": "NO",
+    "Pc": "PC", "Pd": "PD", "Ps": "PS", "Pe": "PE", "Pi": "PI", "Pf": "PF", "Po": "PO",
+    "Sm": "SM", "Sc": "SC", "Sk": "SK", "So": "SO",
+    "Zs": "ZS", "Zl": "ZL", "Zp": "ZP",
+    "Cc": "CC", "Cf": "CF", "Co": "CO",
+}
+NAMES = {
+    "Lu": "UppercaseLetter", "Ll": "LowercaseLetter", "Lt": "TitlecaseLetter", "Lm": "ModifierLetter",
+    "Lo": "OtherLetter", "Mn": "NonspacingMark", "Mc": "SpacingMark", "Me": "EnclosingMark",
+    "Nd": "DecimalNumber", "Nl": "LetterNumber", "No": "OtherNumber", "Pc": "ConnectorPunctuation",
+    "Pd": "DashPunctuation", "Ps": "OpenPunctuation", "Pe": "ClosePunctuation", "Pi": "InitialPunctuation",
+    "Pf": "FinalPunctuation", "Po": "OtherPunctuation", "Sm": "MathSymbol", "Sc": "CurrencySymbol",
+    "Sk": "ModifierSymbol", "So": "OtherSymbol", "Zs": "SpaceSeparator", "Zl": "LineSeparator",
+    "Zp": "ParagraphSeparator", "Cc": "Control", "Cf": "Format", "Co": "PrivateUse",
+}
+
+
+def is_scalar(cp):
+    return not 0xD800 <= cp <= 0xDFFF
+
+
+def category_ranges():
+    ranges = []
+    for cp in filter(is_scalar, range(0x110000)):
+        category = unicodedata.category(chr(cp))
+        if category == "Cn":
+            continue
+        if ranges and ranges[-1][2] == category and ranges[-1][1] == cp - 1:
+            ranges[-1][1] = cp
+        else:
+            ranges.append([cp, cp, category])
+    return ranges
+
+
+def property_ranges(predicate):
+    ranges = []
+    for cp in filter(is_scalar, range(0x110000)):
+        if predicate(chr(cp)):
+            if ranges and ranges[-1][1] == cp - 1:
+                ranges[-1][1] = cp
+            else:
+                ranges.append([cp, cp])
+    return ranges
+
+
+def emit(items, per_line):
+    for index in range(0, len(items), per_line):
+        print("  " + " ".join(items[index:index + per_line]))
+
+
+def main():
+    print("// Generated by scripts/gen-unicode-tables.py from Unicode %s. Do not edit." % unicodedata.unidata_version)
+    print()
+    print("use super::GeneralCategory;")
+    print()
+    print("pub(crate) const UNICODE_VERSION: (u8, u8, u8) = (%s);" % ", ".join(unicodedata.unidata_version.split(".")))
+    print()
+    for short, alias in CATEGORIES.items():
+        print("const %s: GeneralCategory = GeneralCategory::%s;" % (alias, NAMES[short]))
+    print()
+    ranges = category_ranges()
+    print("pub(crate) const GENERAL_CATEGORY: &[(u32, u32, GeneralCategory)] = &[")
+    emit(["(0x%04X, 0x%04X, %s)," % (s, e, CATEGORIES[c]) for s, e, c in ranges], 4)
+    print("];")
+    # str.isidentifier tests XID_Start for the first character (and allows `_`) and XID_Continue for the rest.
+    xid_start = property_ranges(lambda c: c != "_" and c.isidentifier())
+    xid_continue = property_ranges(lambda c: ("a" + c).isidentifier())
+    for name, ranges in (("XID_START", xid_start), ("XID_CONTINUE", xid_continue)):
+        print()
+        print("pub(crate) const %s: &[(u32, u32)] = &[" % name)
+        emit(["(0x%04X, 0x%04X)," % (s, e) for s, e in ranges], 6)
+        print("];")
+
+
+if __name__ == "__main__":
+    main()
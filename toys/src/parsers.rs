@@ -6,7 +6,9 @@ use std::iter::FromIterator;
 use std::rc::Rc;
 
 fn ident<'a>() -> Parser<'a, char, String> {
-  space() * regex(r"[a-zA-Z_][a-zA-Z0-9_]*") - space()
+  let head = elm_of_set(XidStart.union("_"));
+  let tail = elm_of_set(XidContinue).of_many0();
+  space() * (head + tail).collect().map(String::from_iter) - space()
 }
 
 fn add<'a>() -> Parser<'a, char, &'a char> {
@@ -62,7 +64,7 @@ fn semi_colon<'a>() -> Parser<'a, char, String> {
 }
 
 fn space<'a>() -> Parser<'a, char, ()> {
-  elm_of(" \t\r\n").of_many0().discard()
+  elm_of(" \t\r\n").of_skip_many0()
 }
 
 pub fn program<'a>() -> Parser<'a, char, Rc<Expr>> {
@@ -70,7 +72,9 @@ pub fn program<'a>() -> Parser<'a, char, Rc<Expr>> {
 }
 
 fn top_level_definition<'a>() -> Parser<'a, char, Rc<Expr>> {
-  (global_variable_definition() | function_definition()).name("top level definition")
+  (global_variable_definition() | function_definition())
+    .name("top level definition")
+    .memo()
 }
 
 fn function_definition<'a>() -> Parser<'a, char, Rc<Expr>> {
@@ -562,6 +566,17 @@ mod test {
     assert_eq!(Expr::of_symbol("abc".to_string()), result);
   }
 
+  #[test]
+  fn test_primary_unicode_identifier() {
+    for source in ["_tmp1", "größe", "変数", "ΔT"] {
+      let input = source.chars().collect::<Vec<_>>();
+      let result = identifier().parse_as_result(&input).unwrap();
+      assert_eq!(Expr::of_symbol(source.to_string()), result);
+    }
+    let input = "1abc".chars().collect::<Vec<_>>();
+    assert!(ident().parse_as_result(&input).is_err());
+  }
+
   #[test]
   fn test_multitive() {
     let source = r"1/2";
@@ -635,4 +650,18 @@ mod test {
       result
     );
   }
+
+  #[test]
+  fn test_program_reparse() {
+    let source = "fn main() { println(f(1)); } fn f(x) { x + 1; } global g = 2;";
+    let mut session = ParseSession::new(source.chars().collect::<Vec<_>>());
+    assert!(session.parse(program).is_success());
+
+    let offset = source.find("x + 1").unwrap() + 4;
+    let result = session.reparse(TextEdit::new(offset..offset + 1, vec!['4', '2']), program);
+    let edited = "fn main() { println(f(1)); } fn f(x) { x + 42; } global g = 2;";
+    let input = edited.chars().collect::<Vec<_>>();
+    assert_eq!(result.success(), program().parse(&input).success());
+    assert_eq!(session.memo_hits(), 2);
+  }
 }
@@ -47,6 +47,11 @@ impl Query {
     Self { params }
   }
 
+  /// Returns the key and value pairs in the order they were written.
+  pub fn entries(&self) -> &[(String, Option<String>)] {
+    &self.params
+  }
+
   pub fn params(&self) -> HashMap<&String, Vec<&String>> {
     let mut result: HashMap<&String, Vec<&String>> = HashMap::new();
     for (key, value) in self.params.iter() {
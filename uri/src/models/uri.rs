@@ -6,7 +6,7 @@ use crate::models::query::Query;
 use crate::models::scheme::Scheme;
 use crate::models::user_info::UserInfo;
 use crate::parsers::uri_parsers;
-use oni_comb_parser_rs::prelude::{ParseError, ParserRunner};
+use oni_comb_parser_rs::prelude::{ParseError, ParseLimits, ParserRunner};
 use std::fmt::Formatter;
 
 pub type Fragment = String;
@@ -66,7 +66,12 @@ impl std::fmt::Display for Uri {
 
 impl Uri {
   pub fn parse(text: &str) -> Result<Uri, ParseError<u8>> {
-    uri_parsers::uri().parse(text.as_bytes()).to_result()
+    uri_parsers::uri().parse_all(text.as_bytes())
+  }
+
+  /// Parses a URI within the given resource limits, for untrusted input.
+  pub fn parse_with_limits(text: &str, limits: ParseLimits) -> Result<Uri, ParseError<'_, u8>> {
+    uri_parsers::uri().parse_all_with_limits(text.as_bytes(), limits)
   }
 
   pub fn new(
@@ -87,6 +92,10 @@ impl Uri {
     self.schema.as_ref()
   }
 
+  pub(crate) fn hier_part(&self) -> Option<&HierPart> {
+    self.hier_path.as_ref()
+  }
+
   pub fn authority(&self) -> Option<&Authority> {
     match self.hier_path {
       Some(ref hp) => hp.authority.as_ref(),
@@ -128,6 +137,7 @@ mod test {
   use std::env;
 
   use crate::models::uri::Uri;
+  use oni_comb_parser_rs::prelude::ParseLimits;
 
   fn init() {
     env::set_var("RUST_LOG", "debug");
@@ -171,4 +181,25 @@ mod test {
       Err(e) => println!("{:?}", e),
     }
   }
+
+  #[test]
+  fn test_parse_trailing_input() {
+    init();
+    let error = Uri::parse("http://localhost/a b").err().unwrap();
+    assert_eq!(error.input_string(), Some(" b".to_string()));
+  }
+
+  #[test]
+  fn test_parse_with_limits() {
+    init();
+    let limits = ParseLimits::new().with_max_steps(10_000);
+    assert!(Uri::parse_with_limits("http://localhost/a/b?c=d", limits).is_ok());
+
+    let text = format!("http://localhost/{}", "a/".repeat(10_000));
+    let error = Uri::parse_with_limits(&text, limits).err().unwrap();
+    assert!(error.is_limit_exceeded());
+
+    let error = Uri::parse_with_limits("http://localhost/a b", limits).err();
+    assert_eq!(error, Uri::parse("http://localhost/a b").err());
+  }
 }
@@ -7,12 +7,6 @@ pub(crate) fn pchar<'a>() -> Parser<'a, u8, &'a [u8]> {
     .name("pchar")
 }
 
-pub(crate) fn pchar_without_eq_amp<'a>() -> Parser<'a, u8, &'a [u8]> {
-  (unreserved() | pct_encoded() | sub_delims_without_eq_ampersand() | elm_ref_of(b":@").collect())
-    .collect()
-    .name("pchar")
-}
-
 //  pct-encoded   = "%" HEXDIG HEXDIG
 pub(crate) fn pct_encoded<'a>() -> Parser<'a, u8, &'a [u8]> {
   (elm_ref(b'%') + elm_hex_digit_ref() + elm_hex_digit_ref())
@@ -42,8 +36,54 @@ pub(crate) fn sub_delims<'a>() -> Parser<'a, u8, &'a [u8]> {
   elm_ref_of(b"!$&'()*+,;=").name("sub-delims").collect()
 }
 
-pub(crate) fn sub_delims_without_eq_ampersand<'a>() -> Parser<'a, u8, &'a [u8]> {
-  elm_ref_of(b"!$'()*+,;").name("sub-delims").collect()
+pub(crate) fn is_unreserved(b: u8) -> bool {
+  b.is_ascii_alphanumeric() || b"-._~".contains(&b)
+}
+
+pub(crate) fn is_sub_delims(b: u8) -> bool {
+  b"!$&'()*+,;=".contains(&b)
+}
+
+pub(crate) fn is_pchar(b: u8) -> bool {
+  is_unreserved(b) || is_sub_delims(b) || b":@".contains(&b)
+}
+
+// A code point as written in the input: a pct-encoded triplet or a single allowed byte.
+fn code_point_syntax<'a>(allowed: fn(u8) -> bool) -> Syntax<'a, u8, Vec<u8>> {
+  let hex_digit = || Syntax::elm_pred(|b: &u8| b.is_ascii_hexdigit());
+  let pct_encoded = (Syntax::literal(b"%") * hex_digit() + hex_digit()).partial_iso(
+    |(h, l)| Some(vec![b'%', h, l]),
+    |code_point: &Vec<u8>| match code_point.as_slice() {
+      [b'%', h, l] => Some((*h, *l)),
+      _ => None,
+    },
+  );
+  let single = Syntax::elm_pred(move |b: &u8| allowed(*b)).partial_iso(
+    |b| Some(vec![b]),
+    |code_point: &Vec<u8>| match code_point.as_slice() {
+      [b] => Some(*b),
+      _ => None,
+    },
+  );
+  pct_encoded | single
+}
+
+/// Returns a syntax of zero or more code points, which prints a string back exactly as it was parsed.
+pub(crate) fn code_points_syntax<'a>(allowed: fn(u8) -> bool) -> Syntax<'a, u8, String> {
+  code_point_syntax(allowed).many0().partial_iso(
+    |code_points| String::from_utf8(code_points.concat()).ok(),
+    |s: &String| {
+      let bytes = s.as_bytes();
+      let mut code_points = Vec::new();
+      let mut i = 0;
+      while i < bytes.len() {
+        let len = if bytes[i] == b'%' { 3.min(bytes.len() - i) } else { 1 };
+        code_points.push(bytes[i..i + len].to_vec());
+        i += len;
+      }
+      Some(code_points)
+    },
+  )
 }
 
 #[cfg(test)]
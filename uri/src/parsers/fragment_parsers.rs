@@ -1,28 +1,19 @@
-use crate::parsers::basic_parsers::pchar;
+use crate::parsers::basic_parsers::{code_points_syntax, is_pchar};
 use oni_comb_parser_rs::prelude::*;
 
 // fragment      = *( pchar / "/" / "?" )
-pub fn fragment<'a>() -> Parser<'a, u8, String> {
-  (pchar() | elm_ref_of(b"/?").collect())
-    .of_many0()
-    .collect()
-    .map(|e| e.to_vec())
-    .map_res(String::from_utf8)
-    .name("fragment")
+pub fn fragment_syntax<'a>() -> Syntax<'a, u8, String> {
+  code_points_syntax(|b| is_pchar(b) || b"/?".contains(&b))
 }
 
 #[cfg(test)]
 pub mod gens {
-  use crate::parsers::basic_parsers::gens::{pchar_gen, repeat_gen_of_string};
-  use prop_check_rs::gen::{Gen, Gens};
+  use prop_check_rs::gen::Gen;
+
+  use super::fragment_syntax;
 
   pub fn fragment_gen() -> Gen<String> {
-    repeat_gen_of_string(1, u8::MAX - 1, {
-      Gens::frequency([
-        (1, pchar_gen(1, 1)),
-        (1, Gens::one_of_values(['/', '?']).map(|c| c.into())),
-      ])
-    })
+    fragment_syntax().grammar().unwrap().to_gen()
   }
 }
 
@@ -53,7 +44,7 @@ mod tests {
       counter += 1;
       log::debug!("{:>03}, fragment = {}", counter, s);
       let input = s.as_bytes();
-      let result = (fragment() - end())
+      let result = (fragment_syntax().parser() - end())
         .collect()
         .map(|e| e.to_vec())
         .map_res(String::from_utf8)
@@ -64,4 +55,15 @@ mod tests {
     });
     prop::test_with_prop(prop, 5, TEST_COUNT, RNG::new())
   }
+
+  #[test]
+  fn test_fragment_roundtrip() -> Result<()> {
+    let prop = prop::for_all_gen(fragment_gen(), move |s| {
+      let syntax = fragment_syntax();
+      let fragment = syntax.parse(s.as_bytes()).success().unwrap();
+      assert_eq!(syntax.print(&fragment), Some(s.as_bytes().to_vec()));
+      true
+    });
+    prop::test_with_prop(prop, 5, TEST_COUNT, RNG::new())
+  }
 }
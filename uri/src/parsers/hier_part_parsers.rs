@@ -13,6 +13,19 @@ pub fn hier_part<'a>() -> Parser<'a, u8, Option<HierPart>> {
   (p1.attempt() | p2).opt()
 }
 
+/// The hier-part as a syntax, printed by the `Display` of [HierPart].
+///
+/// The printer normalizes the hier-part, e.g. `[0:0:0:0:0:0:0:1]` is printed as `[::1]` and the port `0080` as `80`,
+/// so only `parse(print(v)) == v` holds, not `print(parse(s)) == s`.
+pub fn hier_part_syntax<'a>() -> Syntax<'a, u8, Option<HierPart>> {
+  Syntax::new(hier_part(), |hier_part, out| {
+    if let Some(hier_part) = hier_part {
+      out.extend_from_slice(hier_part.to_string().as_bytes());
+    }
+    true
+  })
+}
+
 #[cfg(test)]
 pub mod gens {
   use crate::parsers::authority_parsers::gens::authority_gen;
@@ -90,12 +90,12 @@ pub fn path_noscheme<'a>() -> Parser<'a, u8, Path> {
 
 // segment       = *pchar
 fn segment<'a>() -> Parser<'a, u8, &'a [u8]> {
-  pchar().of_many0().collect().name("segment")
+  pchar().of_skip_many0().collect().name("segment")
 }
 
 // segment-nz    = 1*pchar
 fn seqment_nz<'a>() -> Parser<'a, u8, &'a [u8]> {
-  pchar().of_many1().collect().name("segment-nz")
+  pchar().of_skip_many1().collect().name("segment-nz")
 }
 
 // segment-nz-nc = 1*( unreserved / pct-encoded / sub-delims / "@" )
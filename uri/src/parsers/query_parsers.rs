@@ -1,22 +1,14 @@
 use crate::models::query::Query;
-use crate::parsers::basic_parsers::pchar_without_eq_amp;
+use crate::parsers::basic_parsers::{code_points_syntax, is_pchar};
 use oni_comb_parser_rs::prelude::*;
 
 //  query         = *( pchar / "/" / "?" )
-pub fn query<'a>() -> Parser<'a, u8, Query> {
-  let code_point = || {
-    (pchar_without_eq_amp() | elm_of(b"/?").collect())
-      .of_many0()
-      .collect()
-      .map(|e| e.to_vec())
-      .map_res(String::from_utf8)
-  };
-  let key_values = || (code_point() + (elm(b'=') * code_point()).opt());
-  (key_values() + (elm(b'&') * key_values()).of_many0()).map(|(a, b)| {
-    let mut m = vec![a];
-    m.extend(b);
-    Query::new(m)
-  })
+pub fn query_syntax<'a>() -> Syntax<'a, u8, Query> {
+  let code_points = || code_points_syntax(|b| (is_pchar(b) && b != b'=' && b != b'&') || b"/?".contains(&b));
+  let key_values = code_points() + (Syntax::literal(b"=") * code_points()).opt();
+  key_values
+    .many1_sep(Syntax::literal(b"&"))
+    .iso(Query::new, |query| query.entries().to_vec())
 }
 
 #[cfg(test)]
@@ -45,15 +37,7 @@ pub mod gens {
   }
 
   pub fn query_gen() -> Gen<String> {
-    Gens::list_of_n(3, {
-      pchar_without_eq_amp_gen(1, 10).flat_map(|key| {
-        Gens::list_of_n(2, pchar_without_eq_amp_gen(1, 10)).map(move |vl| {
-          let kvl = vl.into_iter().map(|v| format!("{}={}", key, v)).collect::<Vec<_>>();
-          kvl.join("&")
-        })
-      })
-    })
-    .map(|v| v.join("&"))
+    super::query_syntax().grammar().unwrap().to_gen()
   }
 }
 
@@ -85,7 +69,7 @@ mod tests {
       counter += 1;
       log::debug!("{:>03}, query:string = {}", counter, s);
       let input = s.as_bytes();
-      let result = (query() - end()).parse(input).to_result();
+      let result = (query_syntax().parser() - end()).parse(input).to_result();
       let query = result.unwrap();
       log::debug!("{:>03}, query:object = {:?}", counter, query);
       assert_eq!(query.to_string(), s);
@@ -101,7 +85,7 @@ mod tests {
       counter += 1;
       log::debug!("{:>03}, query:string = {}", counter, s);
       let input = s.as_bytes();
-      let result = (query() - end()).parse(input).to_result();
+      let result = (query_syntax().parser() - end()).parse(input).to_result();
       let query = result.unwrap();
       log::debug!("{:>03}, query:object = {:?}", counter, query);
       assert_eq!(query.to_string(), s);
@@ -109,4 +93,24 @@ mod tests {
     });
     prop::test_with_prop(prop, 5, TEST_COUNT, RNG::new())
   }
+
+  #[test]
+  fn test_query_roundtrip() -> Result<()> {
+    let prop = prop::for_all_gen(query_gen(), move |s| {
+      let syntax = query_syntax();
+      let query = syntax.parse(s.as_bytes()).success().unwrap();
+      assert_eq!(syntax.print(&query), Some(s.as_bytes().to_vec()));
+      true
+    });
+    prop::test_with_prop(prop, 5, TEST_COUNT, RNG::new())
+  }
+
+  #[test]
+  fn test_query_print() {
+    let syntax = query_syntax();
+    let query = Query::from(vec![("a", Some("1")), ("b", None), ("", Some(""))]);
+    assert_eq!(syntax.print(&query), Some(b"a=1&b&=".to_vec()));
+    assert_eq!(syntax.print(&Query::from(vec![("a=b", None)])), None);
+    assert_eq!(syntax.print(&Query::default()), None);
+  }
 }
@@ -3,31 +3,26 @@
 use crate::models::scheme::Scheme;
 use oni_comb_parser_rs::prelude::*;
 
-pub fn scheme<'a>() -> Parser<'a, u8, Scheme> {
-  ((elm_alpha_ref() + (elm_alpha_ref() | elm_digit_ref() | elm_ref_of(b"+-.")).of_many0()).collect())
-    .map(|e| e.to_vec())
-    .map_res(String::from_utf8)
-    .map(Scheme::new)
+pub fn scheme_syntax<'a>() -> Syntax<'a, u8, Scheme> {
+  let alpha = Syntax::elm_pred(|b: &u8| b.is_ascii_alphabetic());
+  let rest = Syntax::elm_pred(|b: &u8| b.is_ascii_alphanumeric() || b"+-.".contains(b));
+  (alpha + rest.many0()).partial_iso(
+    |(head, tail)| String::from_utf8([vec![head], tail].concat()).ok().map(Scheme::new),
+    |scheme| {
+      let bytes = scheme.to_string().into_bytes();
+      bytes.split_first().map(|(head, tail)| (*head, tail.to_vec()))
+    },
+  )
 }
 
 #[cfg(test)]
 pub mod gens {
-  use prop_check_rs::gen::{Gen, Gens};
+  use prop_check_rs::gen::Gen;
 
-  use crate::parsers::basic_parsers::gens::*;
+  use super::scheme_syntax;
 
   pub fn scheme_gen() -> Gen<String> {
-    repeat_gen_of_char(
-      5,
-      Gens::frequency([
-        (1, alpha_char_gen()),
-        (1, digit_gen('0', '9')),
-        (1, Gens::pure('+')),
-        (1, Gens::pure('-')),
-        (1, Gens::pure('.')),
-      ]),
-    )
-    .flat_map(|s| alpha_char_gen().map(move |c| format!("{}{}", c, s)))
+    scheme_syntax().grammar().unwrap().to_gen()
   }
 }
 
@@ -58,7 +53,7 @@ mod tests {
       counter += 1;
       log::debug!("{:>03}, scheme:string = {}", counter, s);
       let input = s.as_bytes();
-      let result = (scheme() - end()).parse(input).to_result();
+      let result = (scheme_syntax().parser() - end()).parse(input).to_result();
       let scheme = result.unwrap();
       log::debug!("{:>03}, scheme:object = {:?}", counter, scheme);
       assert_eq!(scheme.to_string(), s);
@@ -66,4 +61,15 @@ mod tests {
     });
     prop::test_with_prop(prop, 5, TEST_COUNT, RNG::new())
   }
+
+  #[test]
+  fn test_scheme_roundtrip() -> Result<()> {
+    let prop = prop::for_all_gen(scheme_gen(), move |s| {
+      let syntax = scheme_syntax();
+      let scheme = syntax.parse(s.as_bytes()).success().unwrap();
+      assert_eq!(syntax.print(&scheme), Some(s.as_bytes().to_vec()));
+      true
+    });
+    prop::test_with_prop(prop, 5, TEST_COUNT, RNG::new())
+  }
 }
@@ -1,19 +1,37 @@
 use crate::models::uri::Uri;
-use crate::parsers::fragment_parsers::fragment;
-use crate::parsers::hier_part_parsers::hier_part;
-use crate::parsers::query_parsers::query;
-use crate::parsers::scheme_parsers::scheme;
+use crate::parsers::fragment_parsers::fragment_syntax;
+use crate::parsers::hier_part_parsers::{hier_part, hier_part_syntax};
+use crate::parsers::query_parsers::query_syntax;
+use crate::parsers::scheme_parsers::scheme_syntax;
 use oni_comb_parser_rs::prelude::*;
 
 //  absolute-URI  = scheme ":" hier-part [ "?" query ]
 pub fn absolute_uri<'a>() -> Parser<'a, u8, Uri> {
-  ((scheme().opt() - elm(b':')) + hier_part() + (elm(b'?') * query()).opt()).map(|((a, b), c)| Uri::new(a, b, c, None))
+  ((scheme_syntax().parser().opt() - elm(b':')) + hier_part() + (elm(b'?') * query_syntax().parser()).opt())
+    .map(|((a, b), c)| Uri::new(a, b, c, None))
 }
 
 // URI = scheme ":" hier-part [ "?" query ] [ "#" fragment ]
 pub fn uri<'a>() -> Parser<'a, u8, Uri> {
-  ((scheme().opt() - elm(b':')) + hier_part() + (elm(b'?') * query()).opt() + (elm(b'#') * fragment()).opt())
-    .map(|(((schema, hier_path), query), fragment)| Uri::new(schema, hier_path, query, fragment))
+  uri_syntax().parser()
+}
+
+/// The URI as a syntax. The hier-part is printed in its normal form, see [hier_part_syntax], so printing a parsed URI
+/// gives back an equivalent URI rather than the same text.
+pub fn uri_syntax<'a>() -> Syntax<'a, u8, Uri> {
+  ((scheme_syntax().opt() - Syntax::literal(b":"))
+    + hier_part_syntax()
+    + (Syntax::literal(b"?") * query_syntax()).opt()
+    + (Syntax::literal(b"#") * fragment_syntax()).opt())
+  .iso(
+    |(((schema, hier_path), query), fragment)| Uri::new(schema, hier_path, query, fragment),
+    |uri| {
+      (
+        ((uri.schema().cloned(), uri.hier_part().cloned()), uri.query().cloned()),
+        uri.fragment().cloned(),
+      )
+    },
+  )
 }
 
 #[cfg(test)]
@@ -84,4 +102,33 @@ mod tests {
     });
     prop::test_with_prop(prop, 5, TEST_COUNT, RNG::new())
   }
+
+  #[test]
+  fn test_uri_syntax_round_trip() -> Result<()> {
+    let mut counter = 0;
+    let uri_gen = uri_gen();
+    let prop = prop::for_all_gen(uri_gen, move |s| {
+      counter += 1;
+      log::debug!("{:>03}, uri:string = {}", counter, s);
+      let parsed = (uri() - end()).parse(s.as_bytes()).success().unwrap();
+      let printed = uri_syntax().print(&parsed).unwrap();
+      assert_eq!((uri() - end()).parse(&printed).success(), Some(parsed));
+      true
+    });
+    prop::test_with_prop(prop, 5, TEST_COUNT, RNG::new())
+  }
+
+  #[test]
+  fn test_uri_syntax_normalizes_hier_part() {
+    for (s, expected) in [
+      ("http://[0:0:0:0:0:0:0:1]/a", "http://[::1]/a"),
+      ("http://host:0080/a", "http://host:80/a"),
+      ("http://host/a?b=1#c", "http://host/a?b=1#c"),
+    ] {
+      let parsed = (uri() - end()).parse(s.as_bytes()).success().unwrap();
+      let printed = uri_syntax().print(&parsed).unwrap();
+      assert_eq!(String::from_utf8(printed.clone()).unwrap(), expected);
+      assert_eq!((uri() - end()).parse(&printed).success(), Some(parsed));
+    }
+  }
 }
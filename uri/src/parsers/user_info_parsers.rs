@@ -5,8 +5,18 @@ use oni_comb_parser_rs::prelude::*;
 //  userinfo      = *( unreserved / pct-encoded / sub-delims / ":" )
 pub fn user_info<'a>() -> Parser<'a, u8, UserInfo> {
   let p = || (unreserved().attempt() | pct_encoded().attempt() | sub_delims());
-  (p().of_many0().collect().map(|e| e.to_vec()).map_res(String::from_utf8)
-    + (elm(b':') * p().of_many1().collect().map(|e| e.to_vec()).map_res(String::from_utf8)).opt())
+  (p()
+    .of_skip_many0()
+    .collect()
+    .map(|e| e.to_vec())
+    .map_res(String::from_utf8)
+    + (elm(b':')
+      * p()
+        .of_skip_many1()
+        .collect()
+        .map(|e| e.to_vec())
+        .map_res(String::from_utf8))
+    .opt())
   .map(|(user_name, password)| UserInfo::new(user_name, password))
   .name("user_info")
 }